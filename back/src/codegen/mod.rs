@@ -0,0 +1,302 @@
+//! The generator of the client modules of the compute endpoints, see [`run`].
+//!
+//! The layouts of the messages come from the declarations of `mat-props-proto/src/messages.rs`,
+//! so the clients are regenerated rather than edited when a message changes.
+
+mod python;
+mod typescript;
+
+use mat_props_proto::Message;
+
+use crate::proto::Computation;
+
+/// The fields of the args messages that default to 0 in the self-describing encodings.
+const DEFAULTS_TO_ZERO: [&str; 2] = ["unit_system", "output_unit_system"];
+const GENERATED_NOTICE: &str =
+    "Generated by `cargo run -- codegen` in `back` from the message declarations \
+    of `mat-props-proto/src/messages.rs`. Do not edit.";
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub(crate) enum FieldType {
+    U8,
+    F64,
+}
+
+impl FieldType {
+    const fn size(self) -> usize {
+        match self {
+            Self::U8 => 1,
+            Self::F64 => 8,
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub(crate) struct Field {
+    pub(crate) name: &'static str,
+    pub(crate) ty: FieldType,
+    pub(crate) offset: usize,
+}
+
+impl Field {
+    fn defaults_to_zero(&self) -> bool {
+        DEFAULTS_TO_ZERO.contains(&self.name)
+    }
+}
+
+/// The binary layout of a message.
+#[derive(Clone, Debug)]
+pub(crate) struct MessageLayout {
+    pub(crate) content_type: &'static str,
+    pub(crate) message_id: u16,
+    pub(crate) size: usize,
+    pub(crate) py_struct_format_string: String,
+    /// The fields without the padding in the order of the message.
+    pub(crate) fields: Vec<Field>,
+}
+
+impl MessageLayout {
+    fn of<M: Message>() -> Self {
+        let py_struct_format_string = M::py_struct_format_string();
+        let mut field_names = M::FIELDS.iter();
+        let mut fields = Vec::new();
+        let mut offset = 0;
+        for ch in py_struct_format_string.chars() {
+            let ty = match ch {
+                'B' => FieldType::U8,
+                'd' => FieldType::F64,
+                'x' => {
+                    offset += 1;
+                    continue;
+                }
+                _ => panic!("Unsupported character in py_struct_format_string: {ch}"),
+            };
+            let name = field_names
+                .next()
+                .expect("More fields in the format than names");
+            fields.push(Field { name, ty, offset });
+            offset += ty.size();
+        }
+        assert_eq!(offset, M::SIZE, "The format doesn't cover the message");
+        Self {
+            content_type: M::CONTENT_TYPE,
+            message_id: M::MESSAGE_ID,
+            size: M::SIZE,
+            py_struct_format_string,
+            fields,
+        }
+    }
+}
+
+/// The args and the response messages of a computation.
+#[derive(Clone, Debug)]
+pub(crate) struct ComputationLayout {
+    /// The name of the computation in the path of its endpoint.
+    pub(crate) name: &'static str,
+    pub(crate) args: MessageLayout,
+    pub(crate) response: MessageLayout,
+}
+
+impl ComputationLayout {
+    pub(crate) fn of<C: Computation>() -> Self {
+        Self {
+            name: C::NAME,
+            args: MessageLayout::of::<C>(),
+            response: MessageLayout::of::<C::Response>(),
+        }
+    }
+
+    pub(crate) fn path(&self) -> String {
+        format!("/compute/{}", self.name)
+    }
+}
+
+/// The language of a generated client module.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub(crate) enum Language {
+    TypeScript,
+    Python,
+}
+
+impl Language {
+    pub(crate) const ALL: [Self; 2] = [Self::TypeScript, Self::Python];
+
+    /// The path of the generated module relative to the root of the repository.
+    pub(crate) const fn path(self) -> &'static str {
+        match self {
+            Self::TypeScript => "front/src/mat-props-client.ts",
+            Self::Python => "scripts/mat_props_client.py",
+        }
+    }
+
+    pub(crate) fn generate(self, computations: &[ComputationLayout]) -> String {
+        match self {
+            Self::TypeScript => typescript::generate(computations),
+            Self::Python => python::generate(computations),
+        }
+    }
+}
+
+/// The layouts of [`crate::proto::COMPUTATIONS`].
+pub(crate) fn computations() -> Vec<ComputationLayout> {
+    crate::proto::COMPUTATIONS
+        .iter()
+        .map(|computation| (computation.layout)())
+        .collect()
+}
+
+/// `codegen [--check] [ROOT]`: writes the client modules to their paths in the repository at `ROOT`,
+/// `..` by default, or fails if `--check` is given and any of them is out of date.
+pub(crate) fn run(args: impl Iterator<Item = String>) -> std::io::Result<()> {
+    let mut check = false;
+    let mut root = std::path::PathBuf::from("..");
+    for arg in args {
+        match arg.as_str() {
+            "--check" => check = true,
+            _ => root = arg.into(),
+        }
+    }
+    let computations = computations();
+    let mut out_of_date = Vec::new();
+    for language in Language::ALL {
+        let path = root.join(language.path());
+        let module = language.generate(&computations);
+        if check {
+            if std::fs::read_to_string(&path).ok().as_deref() != Some(module.as_str()) {
+                out_of_date.push(path.display().to_string());
+            }
+        } else {
+            if let Some(dir) = path.parent() {
+                std::fs::create_dir_all(dir)?;
+            }
+            std::fs::write(&path, module)?;
+            println!("Generated {}", path.display());
+        }
+    }
+    if out_of_date.is_empty() {
+        Ok(())
+    } else {
+        Err(std::io::Error::other(format!(
+            "Out of date, run `cargo run -- codegen`: {}",
+            out_of_date.join(", ")
+        )))
+    }
+}
+
+/// `elastic_modules_for_honeycomb` -> `ElasticModulesForHoneycomb`.
+fn pascal_case(snake_case: &str) -> String {
+    snake_case
+        .split('_')
+        .map(|word| {
+            let mut chars = word.chars();
+            chars
+                .next()
+                .map(|first| first.to_ascii_uppercase().to_string() + chars.as_str())
+                .unwrap_or_default()
+        })
+        .collect()
+}
+
+/// `elastic_modules_for_honeycomb` -> `elasticModulesForHoneycomb`.
+fn camel_case(snake_case: &str) -> String {
+    let pascal_case = pascal_case(snake_case);
+    let mut chars = pascal_case.chars();
+    chars
+        .next()
+        .map(|first| first.to_ascii_lowercase().to_string() + chars.as_str())
+        .unwrap_or_default()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::proto::ThermalConductivityForUnidirectionalCompositeArgs;
+
+    #[test]
+    fn generated_clients_are_up_to_date() {
+        let computations = computations();
+        let root = std::path::Path::new(env!("CARGO_MANIFEST_DIR")).join("..");
+        for language in Language::ALL {
+            let committed = std::fs::read_to_string(root.join(language.path())).unwrap();
+            assert!(
+                committed == language.generate(&computations),
+                "{} is out of date, run `cargo run -- codegen`",
+                language.path()
+            );
+        }
+    }
+
+    /// Runs the generated Python client against the backend on a free port.
+    #[test]
+    fn generated_python_client_talks_to_the_backend() {
+        let scripts = std::path::Path::new(env!("CARGO_MANIFEST_DIR")).join("../scripts");
+        let script = r#"
+import asyncio
+import sys
+
+sys.path.insert(0, sys.argv[2])
+import mat_props_client as client
+
+args = client.ThermalConductivityForUnidirectionalCompositeArgs(
+    number_of_model=1, fibre_content=0.2, k_for_fiber=100.0, k_for_matrix=1.0
+)
+resp = asyncio.run(client.thermal_conductivity_for_unidirectional_composite(sys.argv[1], args))
+print(repr(resp.k1))
+args.number_of_model = 9
+try:
+    asyncio.run(client.thermal_conductivity_for_unidirectional_composite(sys.argv[1], args))
+except client.ProblemError as e:
+    print(e.status)
+"#;
+        let output = actix_web::rt::System::new().block_on(async {
+            let server = actix_web::HttpServer::new(|| {
+                actix_web::App::new().service(crate::compute_by_name)
+            })
+            .workers(1)
+            .bind(("127.0.0.1", 0))
+            .unwrap();
+            let base_url = format!("http://{}", server.addrs()[0]);
+            let server = server.run();
+            let handle = server.handle();
+            actix_web::rt::spawn(server);
+            let python = std::env::var("PYTHON").unwrap_or_else(|_| "python3".to_owned());
+            let output = actix_web::rt::task::spawn_blocking(move || {
+                std::process::Command::new(python)
+                    .arg("-c")
+                    .arg(script)
+                    .arg(base_url)
+                    .arg(scripts)
+                    .output()
+                    .unwrap()
+            })
+            .await
+            .unwrap();
+            handle.stop(true).await;
+            output
+        });
+        assert!(
+            output.status.success(),
+            "{}",
+            String::from_utf8_lossy(&output.stderr)
+        );
+        let stdout = String::from_utf8(output.stdout).unwrap();
+        let [k1, status] = stdout.lines().collect::<Vec<_>>()[..] else {
+            panic!("{stdout}");
+        };
+        let [expected_k1, _, _] =
+            mat_props::thermal_conductivity_for_unidirectional_composite(1, 0.2, 100.0, 1.0)
+                .unwrap();
+        assert_eq!(k1.parse::<f64>().unwrap(), expected_k1);
+        // an unknown model, not an unsupported content type
+        assert_eq!(status, "400");
+    }
+
+    #[test]
+    fn layout_has_the_padding() {
+        let layout = ComputationLayout::of::<ThermalConductivityForUnidirectionalCompositeArgs>();
+        assert_eq!(layout.args.fields[3].name, "output_unit_system");
+        assert_eq!(layout.args.fields[4].offset, 8);
+        assert_eq!(layout.response.fields.len(), 3);
+        assert_eq!(camel_case("k_for_fiber"), "kForFiber");
+    }
+}
@@ -0,0 +1,134 @@
+use std::fmt::Write;
+
+use super::{pascal_case, ComputationLayout, FieldType, GENERATED_NOTICE};
+
+const PRELUDE: &str = r#"import asyncio
+import urllib.error
+import urllib.request
+from dataclasses import dataclass
+from struct import pack, unpack
+from sys import byteorder
+
+NATIVE_ENDIANNESS = 0 if byteorder == "little" else 1
+# the explicit byte order disables the native alignment, the formats have the padding
+_BYTE_ORDER = ("<", ">")
+
+
+class ProblemError(Exception):
+    """The error response of the backend with its application/problem+json body."""
+
+    def __init__(self, status: int, problem: str):
+        super().__init__(f"{status}: {problem}")
+        self.status = status
+        self.problem = problem
+
+
+def _post(url: str, body: bytes, content_type: str) -> bytes:
+    req = urllib.request.Request(
+        url, data=body, headers={"Content-Type": content_type}, method="POST"
+    )
+    try:
+        with urllib.request.urlopen(req) as resp:
+            return resp.read()
+    except urllib.error.HTTPError as e:
+        raise ProblemError(e.code, e.read().decode()) from e
+"#;
+
+fn py_type(ty: FieldType) -> &'static str {
+    match ty {
+        FieldType::U8 => "int",
+        FieldType::F64 => "float",
+    }
+}
+
+pub(super) fn generate(computations: &[ComputationLayout]) -> String {
+    let mut s = format!("\"\"\"{GENERATED_NOTICE}\"\"\"\n\n{PRELUDE}");
+    for computation in computations {
+        let name = pascal_case(computation.name);
+        let constant = computation.name.to_ascii_uppercase();
+        let (args, response) = (&computation.args, &computation.response);
+        let args_fields = args
+            .fields
+            .iter()
+            .filter(|field| field.name != "endianness");
+
+        writeln!(s, "\n\n{constant}_PATH = \"{}\"", computation.path()).unwrap();
+        for (prefix, message) in [("ARGS", args), ("RESPONSE", response)] {
+            writeln!(
+                s,
+                "{constant}_{prefix}_CONTENT_TYPE = \"{}\"",
+                message.content_type
+            )
+            .unwrap();
+            writeln!(s, "{constant}_{prefix}_MESSAGE_ID = {}", message.message_id).unwrap();
+            writeln!(
+                s,
+                "{constant}_{prefix}_FORMAT = \"{}\"",
+                message.py_struct_format_string
+            )
+            .unwrap();
+        }
+
+        // the fields with the defaults follow the others in a dataclass
+        writeln!(s, "\n\n@dataclass\nclass {name}Args:").unwrap();
+        for field in args_fields
+            .clone()
+            .filter(|field| !field.defaults_to_zero())
+        {
+            writeln!(s, "    {}: {}", field.name, py_type(field.ty)).unwrap();
+        }
+        for field in args_fields.clone().filter(|field| field.defaults_to_zero()) {
+            writeln!(s, "    {}: {} = 0", field.name, py_type(field.ty)).unwrap();
+        }
+
+        writeln!(s, "\n\n@dataclass\nclass {name}Response:").unwrap();
+        for field in &response.fields {
+            writeln!(s, "    {}: {}", field.name, py_type(field.ty)).unwrap();
+        }
+
+        writeln!(
+            s,
+            "\n\ndef encode_{}_args(args: {name}Args, endianness: int = NATIVE_ENDIANNESS) -> bytes:",
+            computation.name
+        )
+        .unwrap();
+        writeln!(s, "    return pack(").unwrap();
+        writeln!(
+            s,
+            "        _BYTE_ORDER[endianness] + {constant}_ARGS_FORMAT,"
+        )
+        .unwrap();
+        writeln!(s, "        endianness,").unwrap();
+        for field in args_fields {
+            writeln!(s, "        args.{},", field.name).unwrap();
+        }
+        writeln!(s, "    )").unwrap();
+
+        writeln!(
+            s,
+            "\n\ndef decode_{}_response(data: bytes, endianness: int = NATIVE_ENDIANNESS) -> {name}Response:",
+            computation.name
+        )
+        .unwrap();
+        writeln!(
+            s,
+            "    return {name}Response(*unpack(_BYTE_ORDER[endianness] + {constant}_RESPONSE_FORMAT, data))"
+        )
+        .unwrap();
+
+        writeln!(
+            s,
+            "\n\nasync def {}(base_url: str, args: {name}Args) -> {name}Response:",
+            computation.name
+        )
+        .unwrap();
+        writeln!(s, "    data = await asyncio.to_thread(").unwrap();
+        writeln!(s, "        _post,").unwrap();
+        writeln!(s, "        base_url + {constant}_PATH,").unwrap();
+        writeln!(s, "        encode_{}_args(args),", computation.name).unwrap();
+        writeln!(s, "        {constant}_ARGS_CONTENT_TYPE,").unwrap();
+        writeln!(s, "    )").unwrap();
+        writeln!(s, "    return decode_{}_response(data)", computation.name).unwrap();
+    }
+    s
+}
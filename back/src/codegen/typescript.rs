@@ -0,0 +1,162 @@
+use std::fmt::Write;
+
+use super::{
+    camel_case, pascal_case, ComputationLayout, FieldType, MessageLayout, GENERATED_NOTICE,
+};
+
+const PRELUDE: &str = r#"export type Endianness = 0 | 1;
+
+// https://en.wikipedia.org/wiki/Endianness
+export const NATIVE_ENDIANNESS = new Uint8Array(new Uint16Array([0x0100]).buffer)[0] as Endianness;
+
+/** The error response of the backend with its application/problem+json body. */
+export class ProblemError extends Error {
+    status: number;
+    problem: string;
+
+    constructor(status: number, problem: string) {
+        super(`${status}: ${problem}`);
+        this.status = status;
+        this.problem = problem;
+    }
+}
+
+async function post(url: string, body: ArrayBuffer): Promise<ArrayBuffer> {
+    const resp = await fetch(url, { method: "POST", body });
+    if (!resp.ok) {
+        throw new ProblemError(resp.status, await resp.text());
+    }
+    return resp.arrayBuffer();
+}
+"#;
+
+fn setter(ty: FieldType) -> &'static str {
+    match ty {
+        FieldType::U8 => "setUint8",
+        FieldType::F64 => "setFloat64",
+    }
+}
+
+fn getter(ty: FieldType) -> &'static str {
+    match ty {
+        FieldType::U8 => "getUint8",
+        FieldType::F64 => "getFloat64",
+    }
+}
+
+/// The multi-byte fields take the endianness.
+fn endianness_arg(ty: FieldType) -> &'static str {
+    match ty {
+        FieldType::U8 => "",
+        FieldType::F64 => ", littleEndian",
+    }
+}
+
+fn write_interface(s: &mut String, name: &str, message: &MessageLayout, skip_endianness: bool) {
+    writeln!(s, "export interface {name} {{").unwrap();
+    for field in &message.fields {
+        if skip_endianness && field.name == "endianness" {
+            continue;
+        }
+        let optional = if field.defaults_to_zero() { "?" } else { "" };
+        writeln!(s, "    {}{optional}: number;", camel_case(field.name)).unwrap();
+    }
+    writeln!(s, "}}\n").unwrap();
+}
+
+pub(super) fn generate(computations: &[ComputationLayout]) -> String {
+    let mut s = format!("// {GENERATED_NOTICE}\n\n{PRELUDE}");
+    for computation in computations {
+        let name = pascal_case(computation.name);
+        let (args, response) = (&computation.args, &computation.response);
+        s.push('\n');
+        write_interface(&mut s, &format!("{name}Args"), args, true);
+        write_interface(&mut s, &format!("{name}Response"), response, false);
+
+        writeln!(
+            s,
+            "export const {}_MESSAGES = {{",
+            computation.name.to_ascii_uppercase()
+        )
+        .unwrap();
+        writeln!(s, "    path: \"{}\",", computation.path()).unwrap();
+        for (prefix, message) in [("args", args), ("response", response)] {
+            writeln!(s, "    {prefix}ContentType: \"{}\",", message.content_type).unwrap();
+            writeln!(s, "    {prefix}MessageId: {},", message.message_id).unwrap();
+            writeln!(s, "    {prefix}Size: {},", message.size).unwrap();
+        }
+        writeln!(s, "}} as const;\n").unwrap();
+
+        writeln!(
+            s,
+            "export function encode{name}Args(args: {name}Args, endianness: Endianness = NATIVE_ENDIANNESS): ArrayBuffer {{"
+        )
+        .unwrap();
+        writeln!(s, "    // the padding stays zeroed").unwrap();
+        writeln!(s, "    const buffer = new ArrayBuffer({});", args.size).unwrap();
+        writeln!(s, "    const view = new DataView(buffer);").unwrap();
+        writeln!(s, "    const littleEndian = endianness === 0;").unwrap();
+        for field in &args.fields {
+            let value = if field.name == "endianness" {
+                "endianness".to_owned()
+            } else if field.defaults_to_zero() {
+                format!("args.{} ?? 0", camel_case(field.name))
+            } else {
+                format!("args.{}", camel_case(field.name))
+            };
+            writeln!(
+                s,
+                "    view.{}({}, {value}{});",
+                setter(field.ty),
+                field.offset,
+                endianness_arg(field.ty)
+            )
+            .unwrap();
+        }
+        writeln!(s, "    return buffer;\n}}\n").unwrap();
+
+        writeln!(
+            s,
+            "export function decode{name}Response(buffer: ArrayBuffer, endianness: Endianness = NATIVE_ENDIANNESS): {name}Response {{"
+        )
+        .unwrap();
+        writeln!(s, "    if (buffer.byteLength !== {}) {{", response.size).unwrap();
+        writeln!(
+            s,
+            "        throw new Error(`Expected {} bytes but got ${{buffer.byteLength}} bytes`);",
+            response.size
+        )
+        .unwrap();
+        writeln!(s, "    }}").unwrap();
+        writeln!(s, "    const view = new DataView(buffer);").unwrap();
+        writeln!(s, "    const littleEndian = endianness === 0;").unwrap();
+        writeln!(s, "    return {{").unwrap();
+        for field in &response.fields {
+            writeln!(
+                s,
+                "        {}: view.{}({}{}),",
+                camel_case(field.name),
+                getter(field.ty),
+                field.offset,
+                endianness_arg(field.ty)
+            )
+            .unwrap();
+        }
+        writeln!(s, "    }};\n}}\n").unwrap();
+
+        writeln!(
+            s,
+            "export async function {}(baseUrl: string, args: {name}Args): Promise<{name}Response> {{",
+            camel_case(computation.name)
+        )
+        .unwrap();
+        writeln!(
+            s,
+            "    const body = await post(`${{baseUrl}}{}`, encode{name}Args(args));",
+            computation.path()
+        )
+        .unwrap();
+        writeln!(s, "    return decode{name}Response(body);\n}}").unwrap();
+    }
+    s
+}
@@ -1,51 +1,61 @@
-use actix_web::{get, post, App, HttpServer, Responder};
+use actix_web::{get, post, web, App, HttpRequest, HttpServer, Responder};
 use dotenv::dotenv;
+use mat_props::MaterialLibrary;
+use mat_props_proto::Endianness;
 use utoipa::OpenApi;
 use utoipa_swagger_ui::SwaggerUi;
 
-mod endianness;
+mod codegen;
 mod proto;
-use endianness::Endianness;
 use proto::{
-    ElasticModulesForHoneycombArgsMessage, ElasticModulesForHoneycombResponseMessage,
-    ElasticModulesForHoneycombResponseParcel, ElasticModulesForUnidirectionalCompositeArgsMessage,
-    ElasticModulesForUnidirectionalCompositeResponseMessage,
-    ElasticModulesForUnidirectionalCompositeResponseParcel,
-    ThermalConductivityForUnidirectionalCompositeArgsMessage,
-    ThermalConductivityForUnidirectionalCompositeResponseMessage,
-    ThermalConductivityForUnidirectionalCompositeResponseParcel,
-    ThermalExpansionForHoneycombArgsMessage, ThermalExpansionForHoneycombResponseMessage,
-    ThermalExpansionForHoneycombResponseParcel,
-    ThermalExpansionForUnidirectionalCompositeArgsMessage,
-    ThermalExpansionForUnidirectionalCompositeResponseMessage,
-    ThermalExpansionForUnidirectionalCompositeResponseParcel,
+    ApiError, AxisArgs, ErrorCode, ErrorMessage, GridArgs, MaterialKindMessage, MaterialMessage,
+    SweepArgsMessage, SweepResponseMessage,
 };
 
 #[derive(OpenApi)]
 #[openapi(
     paths(
         index,
-        elastic_modules_for_unidirectional_composite,
-        elastic_modules_for_honeycomb,
-        thermal_conductivity_for_unidirectional_composite,
-        thermal_expansion_for_unidirectional_composite,
-        thermal_expansion_for_honeycomb,
+        compute_by_name,
+        compute,
+        sweep,
+        materials,
+        material,
+        batch_by_name,
     ),
     components(schemas(
-        ElasticModulesForUnidirectionalCompositeArgsMessage,
-        ElasticModulesForUnidirectionalCompositeResponseMessage,
-        ElasticModulesForHoneycombArgsMessage,
-        ElasticModulesForHoneycombResponseMessage,
-        ThermalConductivityForUnidirectionalCompositeArgsMessage,
-        ThermalConductivityForUnidirectionalCompositeResponseMessage,
-        ThermalExpansionForUnidirectionalCompositeArgsMessage,
-        ThermalExpansionForUnidirectionalCompositeResponseMessage,
-        ThermalExpansionForHoneycombArgsMessage,
-        ThermalExpansionForHoneycombResponseMessage,
-    ))
+        SweepArgsMessage,
+        AxisArgs,
+        GridArgs,
+        SweepResponseMessage,
+        MaterialMessage,
+        MaterialKindMessage,
+        ErrorMessage,
+        ErrorCode,
+    )),
+    modifiers(&ComputationPaths),
 )]
 struct ApiDoc;
 
+/// Replaces the templates `/compute/{name}` and `/batch/{name}` with the paths of the computations
+/// and adds their messages to the schemas.
+struct ComputationPaths;
+
+impl utoipa::Modify for ComputationPaths {
+    fn modify(&self, openapi: &mut utoipa::openapi::OpenApi) {
+        if let Some(template) = openapi.paths.paths.remove("/compute/{name}") {
+            for computation in &proto::COMPUTATIONS {
+                (computation.document)(openapi, &template);
+            }
+        }
+        if let Some(template) = openapi.paths.paths.remove("/batch/{name}") {
+            for computation in &proto::COMPUTATIONS {
+                (computation.document_batch)(openapi, &template);
+            }
+        }
+    }
+}
+
 #[utoipa::path(
     get,
     request_body = (),
@@ -58,335 +68,323 @@ async fn index() -> impl Responder {
     "Hello world!"
 }
 
+/// The template of the paths `/compute/{name}` of the computations, see [`ComputationPaths`].
 #[utoipa::path(
     post,
-    request_body(
-        content = ElasticModulesForUnidirectionalCompositeArgsMessage,
-        description = format!(
-            "Python struct format string: {:?}. See <https://docs.python.org/3/library/struct.html#format-strings>.\n\n\
-            See schema for the order of the fields (but not their sizes).",
-            ElasticModulesForUnidirectionalCompositeArgsMessage::py_struct_format_string()
-        ),
-        content_type = ElasticModulesForUnidirectionalCompositeArgsMessage::content_type(),
-        example = ElasticModulesForUnidirectionalCompositeArgsMessage::example_as_serde_big_array,
-    ),
+    params(("name" = String, Path, description = "The name of the computation")),
+    request_body(content = Vec<u8>, description = "The args message of the computation."),
     responses (
+        (status = 200, description = "The response message of the computation.", body = Vec<u8>),
         (
-            status = 200,
-            description = format!(
-                "Computes elastic_modules_for_unidirectional_composite. \
-                Returns the binary representation of [E1, E2, E3, nu12, nu13, nu23, G12, G13, G23] with the requested endianness.\n\n\
-                Python struct format string: {:?}. See <https://docs.python.org/3/library/struct.html#format-strings>.",
-                ElasticModulesForUnidirectionalCompositeResponseMessage::py_struct_format_string()
+            status = 400,
+            description = "Wrong payload length, invalid endianness, invalid or mismatched header, unknown model or unit system, \
+                or a non-finite input. \
+                The field of the error names the failing field of the args message.",
+            content(
+                ("application/problem+json" = ErrorMessage),
+                ("application/x.error-message" = Vec<u8>),
+            ),
+        ),
+        (
+            status = 413,
+            description = "The payload is longer than the args message and its header.",
+            body = ErrorMessage,
+            content_type = "application/problem+json",
+        ),
+        (
+            status = 415,
+            description = "The Content-Type doesn't match the args message.",
+            body = ErrorMessage,
+            content_type = "application/problem+json",
+        ),
+        (
+            status = 422,
+            description = "An infinite output, i.e. the inputs are out of the range of the model.",
+            content(
+                ("application/problem+json" = ErrorMessage),
+                ("application/x.error-message" = Vec<u8>),
+            ),
+        ),
+        (
+            status = 500,
+            description = "The computation failed.",
+            content(
+                ("application/problem+json" = ErrorMessage),
+                ("application/x.error-message" = Vec<u8>),
             ),
-            body = ElasticModulesForUnidirectionalCompositeResponseMessage,
-            content_type = ElasticModulesForUnidirectionalCompositeResponseMessage::content_type(),
         ),
     )
 )]
-#[post("/compute/elastic_modules_for_unidirectional_composite")]
-async fn elastic_modules_for_unidirectional_composite(
-    args: ElasticModulesForUnidirectionalCompositeArgsMessage,
-) -> impl Responder {
-    let ElasticModulesForUnidirectionalCompositeArgsMessage {
-        endianness,
-        number_of_model,
-        fibre_content,
-        e_for_fiber,
-        nu_for_fiber,
-        e_for_matrix,
-        nu_for_matrix,
-    } = args;
-    // the extractor validated the endianness, so it's safe to use `from_u8_unchecked`
-    let endianness = unsafe { Endianness::from_u8_unchecked(endianness) };
-    let res: [f64; 9] = match mat_props::elastic_modules_for_unidirectional_composite(
-        number_of_model,
-        fibre_content,
-        e_for_fiber,
-        nu_for_fiber,
-        e_for_matrix,
-        nu_for_matrix,
-    ) {
-        Ok(r) => r,
-        Err(_e) => return actix_web::HttpResponse::InternalServerError().finish(),
-    };
-    let [e1, e2, e3, nu12, nu13, nu23, g12, g13, g23] = res;
-    let message = ElasticModulesForUnidirectionalCompositeResponseMessage {
-        e1,
-        e2,
-        e3,
-        nu12,
-        nu13,
-        nu23,
-        g12,
-        g13,
-        g23,
-    };
-    let parcel = ElasticModulesForUnidirectionalCompositeResponseParcel::new(endianness, message);
-    actix_web::HttpResponse::Ok()
-        .append_header(("Access-Control-Allow-Origin", "*"))
-        .body(parcel)
+#[post("/compute/{name}")]
+async fn compute_by_name(
+    req: HttpRequest,
+    name: web::Path<String>,
+    payload: web::Payload,
+) -> actix_web::Result<actix_web::HttpResponse> {
+    let computation = proto::computation_by_name(&name)?;
+    (computation.handle)(req, payload.into_inner()).await
 }
 
 #[utoipa::path(
     post,
     request_body(
-        content = ElasticModulesForHoneycombArgsMessage,
+        content = Vec<u8>,
         description = format!(
-            "Python struct format string: {:?}. See <https://docs.python.org/3/library/struct.html#format-strings>.\n\n\
-            See schema for the order of the fields (but not their sizes).",
-            ElasticModulesForHoneycombArgsMessage::py_struct_format_string()
+            "The header of the protocol version 2 (Python struct format string: {:?}) followed by the binary args message \
+            of any computation, which is picked by the message id of the header. See the paths /compute/{{name}} \
+            for the args messages and their ids.",
+            proto::HEADER_PY_STRUCT_FORMAT_STRING,
         ),
-        content_type = ElasticModulesForHoneycombArgsMessage::content_type(),
-        example = ElasticModulesForHoneycombArgsMessage::example_as_serde_big_array,
+        content_type = "application/octet-stream",
     ),
     responses (
         (
             status = 200,
-            description = format!(
-                "Computes elastic_modules_for_honeycomb. \
-                Returns the binary representation of [E1, E2, E3, nu12, nu13, nu23, G12, G13, G23] with the requested endianness.\n\n\
-                Python struct format string: {:?}. See <https://docs.python.org/3/library/struct.html#format-strings>.",
-                ElasticModulesForHoneycombResponseMessage::py_struct_format_string()
+            description = "The header followed by the binary response message of the computation.",
+            body = Vec<u8>,
+            content_type = "application/octet-stream",
+        ),
+        (
+            status = 400,
+            description = "A missing or invalid header, an unknown message id, or any error of the args message.",
+            content(
+                ("application/problem+json" = ErrorMessage),
+                ("application/x.error-message" = Vec<u8>),
+            ),
+        ),
+        (
+            status = 413,
+            description = "The payload is longer than the args message and its header.",
+            body = ErrorMessage,
+            content_type = "application/problem+json",
+        ),
+        (
+            status = 422,
+            description = "An infinite output, i.e. the inputs are out of the range of the model.",
+            content(
+                ("application/problem+json" = ErrorMessage),
+                ("application/x.error-message" = Vec<u8>),
+            ),
+        ),
+        (
+            status = 500,
+            description = "The computation failed.",
+            content(
+                ("application/problem+json" = ErrorMessage),
+                ("application/x.error-message" = Vec<u8>),
             ),
-            body = ElasticModulesForHoneycombResponseMessage,
-            content_type = ElasticModulesForHoneycombResponseMessage::content_type(),
         ),
     )
 )]
-#[post("/compute/elastic_modules_for_honeycomb")]
-async fn elastic_modules_for_honeycomb(
-    args: ElasticModulesForHoneycombArgsMessage,
-) -> impl Responder {
-    let ElasticModulesForHoneycombArgsMessage {
-        endianness,
-        number_of_model,
-        l_cell_side_size,
-        h_cell_side_size,
-        wall_thickness,
-        angle,
-        e_for_honeycomb,
-        nu_for_honeycomb,
-    } = args;
-    // the extractor validated the endianness, so it's safe to use `from_u8_unchecked`
-    let endianness = unsafe { Endianness::from_u8_unchecked(endianness) };
-    let res: [f64; 9] = match mat_props::elastic_modules_for_honeycomb(
-        number_of_model,
-        l_cell_side_size,
-        h_cell_side_size,
-        wall_thickness,
-        angle,
-        e_for_honeycomb,
-        nu_for_honeycomb,
-    ) {
-        Ok(r) => r,
-        Err(_e) => return actix_web::HttpResponse::InternalServerError().finish(),
-    };
-    let [e1, e2, e3, nu12, nu13, nu23, g12, g13, g23] = res;
-    let message = ElasticModulesForHoneycombResponseMessage {
-        e1,
-        e2,
-        e3,
-        nu12,
-        nu13,
-        nu23,
-        g12,
-        g13,
-        g23,
-    };
-    let parcel = ElasticModulesForHoneycombResponseParcel::new(endianness, message);
-    actix_web::HttpResponse::Ok()
-        .append_header(("Access-Control-Allow-Origin", "*"))
-        .body(parcel)
+#[post("/compute")]
+async fn compute(
+    req: HttpRequest,
+    payload: web::Payload,
+) -> actix_web::Result<actix_web::HttpResponse> {
+    proto::dispatch(req, payload.into_inner()).await
 }
 
 #[utoipa::path(
     post,
+    params(("name" = String, Path, description = "The name of the computation")),
     request_body(
-        content = ThermalConductivityForUnidirectionalCompositeArgsMessage,
-        description = format!(
-            "Python struct format string: {:?}. See <https://docs.python.org/3/library/struct.html#format-strings>.\n\n\
-            See schema for the order of the fields (but not their sizes).",
-            ThermalConductivityForUnidirectionalCompositeArgsMessage::py_struct_format_string()
-        ),
-        content_type = ThermalConductivityForUnidirectionalCompositeArgsMessage::content_type(),
-        example = ThermalConductivityForUnidirectionalCompositeArgsMessage::example_as_serde_big_array,
+        content = Vec<u8>,
+        description = "The batch header followed by the packed args messages of the computation.",
+        content_type = proto::BATCH_ARGS_CONTENT_TYPE,
     ),
     responses (
         (
             status = 200,
-            description = format!(
-                "Computes thermal_conductivity_for_unidirectional_composite. \
-                Returns the binary representation of [K1,K2,K3] with the requested endianness.\n\n\
-                Python struct format string: {:?}. See <https://docs.python.org/3/library/struct.html#format-strings>.",
-                ThermalConductivityForUnidirectionalCompositeResponseMessage::py_struct_format_string()
+            description = "The packed response messages of the computation.",
+            body = Vec<u8>,
+            content_type = proto::BATCH_RESPONSE_CONTENT_TYPE,
+        ),
+        (
+            status = 400,
+            description = "Payload size doesn't match the count, invalid endianness, unknown model or unit system, or a non-finite input. \
+                The field of the error names the failing record and its field, e.g. records[3].number_of_model.",
+            content(
+                ("application/problem+json" = ErrorMessage),
+                ("application/x.error-message" = Vec<u8>),
+            ),
+        ),
+        (
+            status = 413,
+            description = "Too many records.",
+            body = ErrorMessage,
+            content_type = "application/problem+json",
+        ),
+        (
+            status = 415,
+            description = "The Content-Type doesn't match the batch args message.",
+            body = ErrorMessage,
+            content_type = "application/problem+json",
+        ),
+        (
+            status = 422,
+            description = "An infinite output, i.e. the inputs are out of the range of the model.",
+            content(
+                ("application/problem+json" = ErrorMessage),
+                ("application/x.error-message" = Vec<u8>),
+            ),
+        ),
+        (
+            status = 500,
+            description = "The computation failed.",
+            content(
+                ("application/problem+json" = ErrorMessage),
+                ("application/x.error-message" = Vec<u8>),
             ),
-            body = ThermalConductivityForUnidirectionalCompositeResponseMessage,
-            content_type = ThermalConductivityForUnidirectionalCompositeResponseMessage::content_type(),
         ),
     )
 )]
-#[post("/compute/thermal_conductivity_for_unidirectional_composite")]
-async fn thermal_conductivity_for_unidirectional_composite(
-    args: ThermalConductivityForUnidirectionalCompositeArgsMessage,
-) -> impl Responder {
-    let ThermalConductivityForUnidirectionalCompositeArgsMessage {
-        endianness,
-        number_of_model,
-        fibre_content,
-        k_for_fiber,
-        k_for_matrix,
-    } = args;
-    // the extractor validated the endianness, so it's safe to use `from_u8_unchecked`
-    let endianness = unsafe { Endianness::from_u8_unchecked(endianness) };
-    let res: [f64; 3] = match mat_props::thermal_conductivity_for_unidirectional_composite(
-        number_of_model,
-        fibre_content,
-        k_for_fiber,
-        k_for_matrix,
-    ) {
-        Ok(r) => r,
-        Err(_e) => return actix_web::HttpResponse::InternalServerError().finish(),
-    };
-    let [k1, k2, k3] = res;
-    let message = ThermalConductivityForUnidirectionalCompositeResponseMessage { k1, k2, k3 };
-    let parcel =
-        ThermalConductivityForUnidirectionalCompositeResponseParcel::new(endianness, message);
-    actix_web::HttpResponse::Ok()
-        .append_header(("Access-Control-Allow-Origin", "*"))
-        .body(parcel)
+#[post("/batch/{name}")]
+async fn batch_by_name(
+    req: HttpRequest,
+    name: web::Path<String>,
+    payload: web::Payload,
+) -> actix_web::Result<actix_web::HttpResponse> {
+    let computation = proto::computation_by_name(&name)?;
+    (computation.handle_batch)(req, payload.into_inner()).await
 }
 
 #[utoipa::path(
     post,
-    request_body(
-        content = ThermalExpansionForUnidirectionalCompositeArgsMessage,
-        description = format!(
-            "Python struct format string: {:?}. See <https://docs.python.org/3/library/struct.html#format-strings>.\n\n\
-            See schema for the order of the fields (but not their sizes).",
-            ThermalExpansionForUnidirectionalCompositeArgsMessage::py_struct_format_string()
+    params(
+        (
+            "function" = String,
+            Path,
+            description = "The name of the function, e.g. elastic_modules_for_unidirectional_composite.",
         ),
-        content_type = ThermalExpansionForUnidirectionalCompositeArgsMessage::content_type(),
-        example = ThermalExpansionForUnidirectionalCompositeArgsMessage::example_as_serde_big_array,
+    ),
+    request_body(
+        content = SweepArgsMessage,
+        description = "The inputs of the function and the grids of the swept inputs.",
+        content_type = "application/json",
     ),
     responses (
         (
             status = 200,
             description = format!(
-                "Computes thermal_expansion_for_unidirectional_composite. \
-                Returns the binary representation of [alpha1,alpha2,alpha3] with the requested endianness.\n\n\
-                Python struct format string: {:?}. See <https://docs.python.org/3/library/struct.html#format-strings>.",
-                ThermalExpansionForUnidirectionalCompositeResponseMessage::py_struct_format_string()
+                "Computes the function over the grid of the swept inputs. The last axis varies the fastest.\n\n\
+                If the request accepts application/json, returns the values of the swept inputs and the outputs for each point of the grid. \
+                Otherwise, returns the concatenated binary representations of the response messages of the function \
+                for each point of the grid with the requested endianness ({:?}).",
+                proto::SWEEP_RESPONSE_CONTENT_TYPE
+            ),
+            content(
+                ("application/json" = SweepResponseMessage),
+                ("application/x.sweep-response-message" = Vec<u8>),
+            ),
+        ),
+        (
+            status = 400,
+            description = "Invalid endianness, unknown model or unknown input. \
+                The field of the error names the failing field of the args.",
+            content(
+                ("application/problem+json" = ErrorMessage),
+                ("application/x.error-message" = Vec<u8>),
+            ),
+        ),
+        (
+            status = 404,
+            description = "Unknown function",
+            body = ErrorMessage,
+            content_type = "application/problem+json",
+        ),
+        (
+            status = 413,
+            description = format!(
+                "The grid has more than {} points.",
+                proto::MAX_SWEEP_POINTS
+            ),
+            content(
+                ("application/problem+json" = ErrorMessage),
+                ("application/x.error-message" = Vec<u8>),
+            ),
+        ),
+        (
+            status = 500,
+            description = "The computation failed.",
+            content(
+                ("application/problem+json" = ErrorMessage),
+                ("application/x.error-message" = Vec<u8>),
             ),
-            body = ThermalExpansionForUnidirectionalCompositeResponseMessage,
-            content_type = ThermalExpansionForUnidirectionalCompositeResponseMessage::content_type(),
         ),
     )
 )]
-#[post("/compute/thermal_expansion_for_unidirectional_composite")]
-async fn thermal_expansion_for_unidirectional_composite(
-    args: ThermalExpansionForUnidirectionalCompositeArgsMessage,
-) -> impl Responder {
-    let ThermalExpansionForUnidirectionalCompositeArgsMessage {
-        endianness,
-        number_of_model,
-        fibre_content,
-        e_for_fiber,
-        nu_for_fiber,
-        alpha_for_fiber,
-        e_for_matrix,
-        nu_for_matrix,
-        alpha_for_matrix,
-    } = args;
-    // the extractor validated the endianness, so it's safe to use `from_u8_unchecked`
-    let endianness = unsafe { Endianness::from_u8_unchecked(endianness) };
-    let res: [f64; 3] = match mat_props::thermal_expansion_for_unidirectional_composite(
-        number_of_model,
-        fibre_content,
-        e_for_fiber,
-        nu_for_fiber,
-        alpha_for_fiber,
-        e_for_matrix,
-        nu_for_matrix,
-        alpha_for_matrix,
-    ) {
-        Ok(r) => r,
-        Err(_e) => return actix_web::HttpResponse::InternalServerError().finish(),
+#[post("/sweep/{function}")]
+async fn sweep(
+    req: HttpRequest,
+    function: web::Path<String>,
+    args: web::Json<SweepArgsMessage>,
+) -> actix_web::Result<actix_web::HttpResponse> {
+    let args = args.into_inner();
+    let Some(endianness) = Endianness::try_from_u8(args.endianness) else {
+        return Err(ApiError::invalid_endianness().into());
     };
-    let [alpha1, alpha2, alpha3] = res;
-    let message = ThermalExpansionForUnidirectionalCompositeResponseMessage {
-        alpha1,
-        alpha2,
-        alpha3,
-    };
-    let parcel = ThermalExpansionForUnidirectionalCompositeResponseParcel::new(endianness, message);
+    let table = proto::compute_sweep(&function, args)
+        .map_err(|e| e.negotiate(&req, proto::SWEEP_RESPONSE_CONTENT_TYPE, endianness))?;
+    let mut resp = actix_web::HttpResponse::Ok();
+    resp.append_header(("Access-Control-Allow-Origin", "*"));
+    if proto::accepts_json(&req) {
+        Ok(resp.json(SweepResponseMessage::from(table)))
+    } else {
+        Ok(resp
+            .content_type(proto::SWEEP_RESPONSE_CONTENT_TYPE)
+            .body(proto::sweep_outputs_as_bytes(&table, endianness)))
+    }
+}
+
+#[utoipa::path(
+    get,
+    responses (
+        (
+            status = 200,
+            description = "Lists the built-in materials and the materials of the library \
+                given by the MATERIALS_LIBRARY environment variable (a .toml or .json file).",
+            body = Vec<MaterialMessage>,
+        ),
+    )
+)]
+#[get("/materials")]
+async fn materials(library: web::Data<MaterialLibrary>) -> impl Responder {
+    let materials: Vec<MaterialMessage> = library
+        .materials
+        .iter()
+        .map(MaterialMessage::from)
+        .collect();
     actix_web::HttpResponse::Ok()
         .append_header(("Access-Control-Allow-Origin", "*"))
-        .body(parcel)
+        .json(materials)
 }
 
 #[utoipa::path(
-    post,
-    request_body(
-        content = ThermalExpansionForHoneycombArgsMessage,
-        description = format!(
-            "Python struct format string: {:?}. See <https://docs.python.org/3/library/struct.html#format-strings>.\n\n\
-            See schema for the order of the fields (but not their sizes).",
-            ThermalExpansionForHoneycombArgsMessage::py_struct_format_string()
-        ),
-        content_type = ThermalExpansionForHoneycombArgsMessage::content_type(),
-        example = ThermalExpansionForHoneycombArgsMessage::example_as_serde_big_array,
+    get,
+    params(
+        ("name", description = "The case-insensitive name of the material, e.g. T300"),
     ),
     responses (
+        (status = 200, description = "Looks up the material by name.", body = MaterialMessage),
         (
-            status = 200,
-            description = format!(
-                "Computes thermal_expansion_for_honeycomb. \
-                Returns the binary representation of [alpha1,alpha2,alpha3] with the requested endianness.\n\n\
-                Python struct format string: {:?}. See <https://docs.python.org/3/library/struct.html#format-strings>.",
-                ThermalExpansionForHoneycombResponseMessage::py_struct_format_string()
-            ),
-            body = ThermalExpansionForHoneycombResponseMessage,
-            content_type = ThermalExpansionForHoneycombResponseMessage::content_type(),
+            status = 404,
+            description = "Unknown material",
+            body = ErrorMessage,
+            content_type = "application/problem+json",
         ),
     )
 )]
-#[post("/compute/thermal_expansion_for_honeycomb")]
-async fn thermal_expansion_for_honeycomb(
-    args: ThermalExpansionForHoneycombArgsMessage,
-) -> impl Responder {
-    let ThermalExpansionForHoneycombArgsMessage {
-        endianness,
-        number_of_model,
-        l_cell_side_size,
-        h_cell_side_size,
-        _wall_thickness,
-        angle,
-        alpha_for_honeycomb,
-    } = args;
-    // the extractor validated the endianness, so it's safe to use `from_u8_unchecked`
-    let endianness = unsafe { Endianness::from_u8_unchecked(endianness) };
-    let res: [f64; 3] = match mat_props::thermal_expansion_for_honeycomb(
-        number_of_model,
-        l_cell_side_size,
-        h_cell_side_size,
-        _wall_thickness,
-        angle,
-        alpha_for_honeycomb,
-    ) {
-        Ok(r) => r,
-        Err(_e) => return actix_web::HttpResponse::InternalServerError().finish(),
+#[get("/materials/{name}")]
+async fn material(
+    library: web::Data<MaterialLibrary>,
+    name: web::Path<String>,
+) -> actix_web::Result<actix_web::HttpResponse> {
+    let Some(material) = library.get(&name) else {
+        return Err(ApiError::from(mat_props::Error::UnknownMaterial(name.into_inner())).into());
     };
-    let [alpha1, alpha2, alpha3] = res;
-    let message = ThermalExpansionForHoneycombResponseMessage {
-        alpha1,
-        alpha2,
-        alpha3,
-    };
-    let parcel = ThermalExpansionForHoneycombResponseParcel::new(endianness, message);
-    actix_web::HttpResponse::Ok()
+    Ok(actix_web::HttpResponse::Ok()
         .append_header(("Access-Control-Allow-Origin", "*"))
-        .body(parcel)
+        .json(MaterialMessage::from(material)))
 }
 
 #[post("/api-doc/openapi.json")]
@@ -397,6 +395,9 @@ async fn serve_openapi_json() -> impl Responder {
 
 #[actix_web::main]
 async fn main() -> std::io::Result<()> {
+    if std::env::args().nth(1).as_deref() == Some("codegen") {
+        return codegen::run(std::env::args().skip(2));
+    }
     dotenv().unwrap();
     println!(
         "Endianness: {}.",
@@ -406,14 +407,23 @@ async fn main() -> std::io::Result<()> {
             "little"
         }
     );
-    HttpServer::new(|| {
+    let mut library = MaterialLibrary::builtin();
+    if let Ok(path) = std::env::var("MATERIALS_LIBRARY") {
+        let user_library = MaterialLibrary::load(&path)
+            .map_err(|e| std::io::Error::new(std::io::ErrorKind::InvalidData, e.to_string()))?;
+        library.extend(user_library);
+    }
+    let library = web::Data::new(library);
+    HttpServer::new(move || {
         App::new()
+            .app_data(library.clone())
             .service(index)
-            .service(elastic_modules_for_unidirectional_composite)
-            .service(elastic_modules_for_honeycomb)
-            .service(thermal_conductivity_for_unidirectional_composite)
-            .service(thermal_expansion_for_unidirectional_composite)
-            .service(thermal_expansion_for_honeycomb)
+            .service(compute)
+            .service(compute_by_name)
+            .service(sweep)
+            .service(materials)
+            .service(material)
+            .service(batch_by_name)
             .service(
                 SwaggerUi::new("/swagger-ui/{_:.*}")
                     .url("/api-doc/openapi.json", ApiDoc::openapi()),
@@ -427,26 +437,31 @@ async fn main() -> std::io::Result<()> {
 
 #[cfg(test)]
 mod tests {
-    use crate::proto::ElasticModulesForUnidirectionalCompositeArgsMessage;
+    use mat_props_proto::{Endianness, Message};
+
+    use crate::proto::{Computation, ElasticModulesForUnidirectionalCompositeArgs};
 
     #[test]
     fn see_args_as_bytes() {
-        let args = ElasticModulesForUnidirectionalCompositeArgsMessage {
+        let args = ElasticModulesForUnidirectionalCompositeArgs {
             endianness: 0,
             number_of_model: 2,
+            unit_system: 0,
+            output_unit_system: 0,
             fibre_content: 0.2,
             e_for_fiber: 100.0,
             nu_for_fiber: 0.3,
             e_for_matrix: 5.0,
             nu_for_matrix: 0.2,
         };
-        let bytes = args.into_bytes();
+        let bytes = args.encode(Endianness::NATIVE);
 
         println!("Args as bytes: {:?}", bytes);
 
         assert_eq!(bytes[0..=0], [0], "Endianness");
         assert_eq!(bytes[1..=1], [2], "Number of model");
-        // bytes[2..=7] is the padding
+        assert_eq!(bytes[2..=3], [0, 0], "Unit systems");
+        // bytes[4..=7] is the padding
         assert_eq!(
             bytes[8..=15],
             {
@@ -534,10 +549,149 @@ mod tests {
         );
     }
 
+    #[test]
+    fn compute_endpoints_document_encoded_bodies() {
+        use utoipa::OpenApi;
+
+        let openapi = crate::ApiDoc::openapi();
+        assert!(!openapi.paths.paths.contains_key("/compute/{name}"));
+        for computation in &crate::proto::COMPUTATIONS {
+            let path = format!("/compute/{}", computation.name);
+            assert!(openapi.paths.paths.contains_key(&path), "{path}");
+        }
+        for (path, item) in openapi.paths.paths.iter() {
+            if !path.starts_with("/compute/") {
+                continue;
+            }
+            let operation = &item.operations[&utoipa::openapi::PathItemType::Post];
+            let body = operation.request_body.as_ref().unwrap();
+            for encoding in crate::proto::Encoding::ALL {
+                assert!(body.content.contains_key(encoding.content_type()), "{path}");
+            }
+            let utoipa::openapi::RefOr::T(response) = &operation.responses.responses["200"] else {
+                panic!("{path}");
+            };
+            for encoding in crate::proto::Encoding::ALL {
+                assert!(
+                    response.content.contains_key(encoding.content_type()),
+                    "{path}"
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn batch_endpoints_are_documented_per_computation() {
+        use utoipa::OpenApi;
+
+        let openapi = crate::ApiDoc::openapi();
+        assert!(!openapi.paths.paths.contains_key("/batch/{name}"));
+        for computation in &crate::proto::COMPUTATIONS {
+            let path = format!("/batch/{}", computation.name);
+            let operation =
+                &openapi.paths.paths[&path].operations[&utoipa::openapi::PathItemType::Post];
+            assert_eq!(
+                operation.operation_id.as_deref(),
+                Some(format!("batch_{}", computation.name).as_str())
+            );
+            assert!(operation.parameters.is_none(), "{path}");
+            let description = operation
+                .request_body
+                .as_ref()
+                .unwrap()
+                .description
+                .as_ref();
+            assert!(description.unwrap().contains(computation.name), "{path}");
+        }
+    }
+
+    #[actix_web::test]
+    async fn batch_is_served_by_name() {
+        use crate::proto::{
+            ThermalConductivityForUnidirectionalCompositeArgs as Args, BATCH_ARGS_CONTENT_TYPE,
+        };
+        use actix_web::{http::StatusCode, test};
+
+        let app = test::init_service(actix_web::App::new().service(crate::batch_by_name)).await;
+        let record = Args::example();
+        let mut payload = vec![0u8; 16];
+        payload[0] = Endianness::NATIVE as u8;
+        payload[8..].copy_from_slice(&1u64.to_ne_bytes());
+        payload.extend_from_slice(&record.encode(Endianness::NATIVE));
+
+        let req = test::TestRequest::post()
+            .uri("/batch/thermal_conductivity_for_unidirectional_composite")
+            .insert_header(("Content-Type", BATCH_ARGS_CONTENT_TYPE))
+            .set_payload(payload.clone())
+            .to_request();
+        let resp = test::call_service(&app, req).await;
+        assert_eq!(resp.status(), StatusCode::OK);
+        assert_eq!(
+            test::read_body(resp).await,
+            Args::response_example().encode(Endianness::NATIVE)
+        );
+
+        let req = test::TestRequest::post()
+            .uri("/batch/strength")
+            .insert_header(("Content-Type", BATCH_ARGS_CONTENT_TYPE))
+            .set_payload(payload)
+            .to_request();
+        let resp = test::call_service(&app, req).await;
+        assert_eq!(resp.status(), StatusCode::NOT_FOUND);
+    }
+
+    #[actix_web::test]
+    async fn undefined_outputs_are_returned_as_nan() {
+        use crate::proto::BATCH_ARGS_CONTENT_TYPE;
+        use actix_web::{http::StatusCode, test};
+
+        let app = test::init_service(
+            actix_web::App::new()
+                .service(crate::compute_by_name)
+                .service(crate::batch_by_name),
+        )
+        .await;
+        // the rule of mixtures doesn't define nu23 and g23
+        let mut args = ElasticModulesForUnidirectionalCompositeArgs::example();
+        args.number_of_model = 1;
+        let outputs = |bytes: &[u8]| -> Vec<f64> {
+            bytes
+                .chunks_exact(8)
+                .map(|chunk| f64::from_ne_bytes(chunk.try_into().unwrap()))
+                .collect()
+        };
+
+        let req = test::TestRequest::post()
+            .uri("/compute/elastic_modules_for_unidirectional_composite")
+            .insert_header((
+                "Content-Type",
+                ElasticModulesForUnidirectionalCompositeArgs::CONTENT_TYPE,
+            ))
+            .set_payload(args.encode(Endianness::NATIVE))
+            .to_request();
+        let resp = test::call_service(&app, req).await;
+        assert_eq!(resp.status(), StatusCode::OK);
+        let outputs = outputs(&test::read_body(resp).await);
+        assert!(outputs[0].is_finite());
+        assert!(outputs[5].is_nan() && outputs[8].is_nan());
+
+        let mut payload = vec![0u8; 16];
+        payload[0] = Endianness::NATIVE as u8;
+        payload[8..].copy_from_slice(&1u64.to_ne_bytes());
+        payload.extend_from_slice(&args.encode(Endianness::NATIVE));
+        let req = test::TestRequest::post()
+            .uri("/batch/elastic_modules_for_unidirectional_composite")
+            .insert_header(("Content-Type", BATCH_ARGS_CONTENT_TYPE))
+            .set_payload(payload)
+            .to_request();
+        let resp = test::call_service(&app, req).await;
+        assert_eq!(resp.status(), StatusCode::OK);
+    }
+
     #[test]
     fn check_args_message_size() {
         assert_eq!(
-            core::mem::size_of::<crate::ElasticModulesForUnidirectionalCompositeArgsMessage>(),
+            core::mem::size_of::<ElasticModulesForUnidirectionalCompositeArgs>(),
             48
         );
     }
@@ -0,0 +1,354 @@
+use std::collections::BTreeMap;
+
+use mat_props::UnitSystem;
+use mat_props_proto::{Endianness, Message};
+
+use crate::proto::{ApiError, Computation, ErrorCode};
+
+/// The size of the header of the batch args message: the endianness, the padding and the count.
+pub(crate) const BATCH_HEADER_SIZE: usize = 16;
+/// See <https://docs.python.org/3/library/struct.html#format-strings>.
+pub(crate) const BATCH_HEADER_PY_STRUCT_FORMAT_STRING: &str = "BxxxxxxxQ";
+pub(crate) const BATCH_ARGS_CONTENT_TYPE: &str = "application/x.batch-args-message";
+pub(crate) const BATCH_RESPONSE_CONTENT_TYPE: &str = "application/x.batch-response-message";
+/// The largest number of records in a single batch.
+pub(crate) const MAX_BATCH_COUNT: usize = 1 << 16;
+
+fn too_many_records() -> ApiError {
+    ApiError::new(
+        actix_web::http::StatusCode::PAYLOAD_TOO_LARGE,
+        ErrorCode::PayloadTooLarge,
+        Some("count"),
+        format!("Too many records, the limit is {MAX_BATCH_COUNT}"),
+    )
+}
+
+fn unit_system_from_u8(unit_system: u8) -> Option<UnitSystem> {
+    [
+        UnitSystem::Consistent,
+        UnitSystem::Si,
+        UnitSystem::Engineering,
+    ]
+    .into_iter()
+    .find(|&u| u as u8 == unit_system)
+}
+
+// Names the record in the error, e.g. `records[3].fibre_content`.
+fn record_error(i: usize, mut e: ApiError) -> ApiError {
+    e.message.detail = format!("Record {i}: {}", e.message.detail);
+    e.message.field = e.message.field.map(|field| format!("records[{i}].{field}"));
+    e
+}
+
+/// The header followed by `count` packed args messages. The endianness of the header
+/// (and of the response) is the first byte, the count is a `u64` at the offset of 8 bytes.
+pub(crate) struct BatchArgsMessage<M> {
+    pub(crate) endianness: Endianness,
+    pub(crate) records: Vec<M>,
+}
+
+impl<M: Computation> BatchArgsMessage<M> {
+    pub(crate) fn from_bytes(bytes: &[u8]) -> Result<Self, actix_web::Error> {
+        let Some(header) = bytes.get(..BATCH_HEADER_SIZE) else {
+            return Err(ApiError::new(
+                actix_web::http::StatusCode::BAD_REQUEST,
+                ErrorCode::InvalidLength,
+                None,
+                "Incomplete batch header",
+            )
+            .into());
+        };
+        let Some(endianness) = Endianness::try_from_u8(header[0]) else {
+            return Err(ApiError::invalid_endianness().into());
+        };
+        let count_bytes: [u8; 8] = header[8..].try_into().unwrap();
+        let count = match endianness {
+            Endianness::Little => u64::from_le_bytes(count_bytes),
+            Endianness::Big => u64::from_be_bytes(count_bytes),
+        };
+        let records_bytes = &bytes[BATCH_HEADER_SIZE..];
+        if count > MAX_BATCH_COUNT as u64 {
+            return Err(too_many_records().into());
+        }
+        if records_bytes.len() != count as usize * M::SIZE {
+            return Err(ApiError::new(
+                actix_web::http::StatusCode::BAD_REQUEST,
+                ErrorCode::InvalidLength,
+                Some("count"),
+                "Payload size doesn't match the count",
+            )
+            .into());
+        }
+        let records = records_bytes
+            .chunks_exact(M::SIZE)
+            .map(|chunk| {
+                let Some(record_endianness) = Endianness::try_from_u8(chunk[0]) else {
+                    return Err(ApiError::invalid_endianness().into());
+                };
+                Ok(M::decode(chunk, record_endianness).map_err(ApiError::from)?)
+            })
+            .collect::<Result<Vec<M>, actix_web::Error>>()?;
+        Ok(Self {
+            endianness,
+            records,
+        })
+    }
+
+    /// Computes the packed response messages with the endianness of the header.
+    ///
+    /// The records of the same model are evaluated together by [`mat_props::evaluate_batch_columns`],
+    /// so the panics are caught once per model rather than once per record. The error of a record
+    /// names the record, e.g. `records[3].fibre_content`.
+    pub(crate) fn compute(self) -> Result<Vec<u8>, ApiError> {
+        use mat_props::Computation as _;
+
+        let number_of_inputs = M::Model::INPUTS.len();
+        let number_of_outputs = M::Model::OUTPUTS.len();
+        // the inputs in SI packed record by record
+        let mut inputs = Vec::with_capacity(self.records.len() * number_of_inputs);
+        let mut output_unit_systems = Vec::with_capacity(self.records.len());
+        let mut models: BTreeMap<u8, Vec<usize>> = BTreeMap::new();
+        for (i, record) in self.records.iter().enumerate() {
+            let record_inputs = record.inputs();
+            if let Some(j) = record_inputs.iter().position(|input| !input.is_finite()) {
+                return Err(record_error(
+                    i,
+                    ApiError::non_finite_input(M::input_fields()[j]),
+                ));
+            }
+            let unit_system_error = |field| {
+                record_error(
+                    i,
+                    ApiError::from(mat_props::Error::UnknownUnitSystem).with_field(field),
+                )
+            };
+            let unit_system = unit_system_from_u8(record.unit_system())
+                .ok_or_else(|| unit_system_error("unit_system"))?;
+            let output_unit_system = unit_system_from_u8(record.output_unit_system())
+                .ok_or_else(|| unit_system_error("output_unit_system"))?;
+            inputs.extend(
+                record_inputs
+                    .into_iter()
+                    .zip(M::Model::INPUT_DIMENSIONS)
+                    .map(|(input, &dimension)| {
+                        unit_system.convert(input, dimension, UnitSystem::Si)
+                    }),
+            );
+            output_unit_systems.push(output_unit_system);
+            models.entry(record.number_of_model()).or_default().push(i);
+        }
+
+        // the outputs in SI packed record by record
+        let mut outputs = vec![0.0; self.records.len() * number_of_outputs];
+        for (number_of_model, indices) in models {
+            let input_columns: Vec<Vec<f64>> = (0..number_of_inputs)
+                .map(|j| {
+                    indices
+                        .iter()
+                        .map(|&i| inputs[i * number_of_inputs + j])
+                        .collect()
+                })
+                .collect();
+            let input_columns: Vec<&[f64]> = input_columns.iter().map(Vec::as_slice).collect();
+            let mut output_columns = vec![vec![0.0; indices.len()]; number_of_outputs];
+            let mut output_column_slices: Vec<&mut [f64]> =
+                output_columns.iter_mut().map(Vec::as_mut_slice).collect();
+            if let Err(e) = mat_props::evaluate_batch_columns::<M::Model>(
+                number_of_model,
+                &input_columns,
+                &mut output_column_slices,
+            ) {
+                // the error is of the whole model, so evaluate its records one by one to find the failing one
+                let (i, e) = indices
+                    .iter()
+                    .find_map(|&i| {
+                        mat_props::evaluate_batch::<M::Model>(
+                            number_of_model,
+                            &inputs[i * number_of_inputs..(i + 1) * number_of_inputs],
+                            &mut vec![0.0; number_of_outputs],
+                        )
+                        .err()
+                        .map(|e| (i, e))
+                    })
+                    .unwrap_or((indices[0], e));
+                return Err(record_error(i, e.into()));
+            }
+            for (n, &i) in indices.iter().enumerate() {
+                for (k, column) in output_columns.iter().enumerate() {
+                    outputs[i * number_of_outputs + k] = column[n];
+                }
+            }
+        }
+
+        let mut bytes = Vec::with_capacity(outputs.len() * core::mem::size_of::<f64>());
+        for (i, (record_outputs, &output_unit_system)) in outputs
+            .chunks_exact(number_of_outputs)
+            .zip(&output_unit_systems)
+            .enumerate()
+        {
+            for (k, (&output, &dimension)) in record_outputs
+                .iter()
+                .zip(M::Model::OUTPUT_DIMENSIONS)
+                .enumerate()
+            {
+                let output = UnitSystem::Si.convert(output, dimension, output_unit_system);
+                // NaN is the output that the model doesn't define
+                if output.is_infinite() {
+                    return Err(record_error(
+                        i,
+                        ApiError::non_finite_output(<M::Response as Message>::FIELDS[k]),
+                    ));
+                }
+                bytes.extend(match self.endianness {
+                    Endianness::Little => output.to_le_bytes(),
+                    Endianness::Big => output.to_be_bytes(),
+                });
+            }
+        }
+        Ok(bytes)
+    }
+}
+
+impl<M: Computation> actix_web::FromRequest for BatchArgsMessage<M> {
+    type Error = actix_web::Error;
+    type Future =
+        std::pin::Pin<Box<dyn std::future::Future<Output = Result<Self, actix_web::Error>>>>;
+
+    fn from_request(
+        req: &actix_web::HttpRequest,
+        payload: &mut actix_web::dev::Payload,
+    ) -> Self::Future {
+        use futures_util::StreamExt;
+
+        let content_type = crate::proto::check_content_type(req, BATCH_ARGS_CONTENT_TYPE);
+        let payload = actix_web::web::Payload::from_request(req, payload);
+        Box::pin(async move {
+            content_type?;
+            let mut p = payload.await?;
+            let limit = BATCH_HEADER_SIZE + MAX_BATCH_COUNT * M::SIZE;
+            let mut buf = Vec::new();
+            while let Some(chunk) = p.next().await {
+                let Ok(chunk) = chunk else {
+                    return Err(actix_web::error::ErrorBadRequest(
+                        "Error receiving the payload",
+                    ));
+                };
+                if buf.len() + chunk.len() > limit {
+                    return Err(too_many_records().into());
+                }
+                buf.extend_from_slice(&chunk);
+            }
+            Self::from_bytes(&buf)
+        })
+    }
+}
+
+/// Computes the batch on the thread pool for the blocking tasks, so that the large batches
+/// don't hold up the worker.
+pub(crate) async fn batch_response<M: Computation>(
+    req: &actix_web::HttpRequest,
+    args: BatchArgsMessage<M>,
+) -> Result<actix_web::HttpResponse, ApiError> {
+    let endianness = args.endianness;
+    let bytes = actix_web::web::block(move || args.compute())
+        .await
+        .unwrap_or_else(|_| {
+            Err(ApiError::new(
+                actix_web::http::StatusCode::INTERNAL_SERVER_ERROR,
+                ErrorCode::Internal,
+                None,
+                "The batch was not computed",
+            ))
+        })
+        .map_err(|e| e.negotiate(req, BATCH_RESPONSE_CONTENT_TYPE, endianness))?;
+    Ok(actix_web::HttpResponse::Ok()
+        .append_header(("Access-Control-Allow-Origin", "*"))
+        .content_type(BATCH_RESPONSE_CONTENT_TYPE)
+        .body(bytes))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::proto::{
+        ThermalConductivityForUnidirectionalCompositeArgs,
+        ThermalConductivityForUnidirectionalCompositeResponse,
+    };
+
+    #[test]
+    fn batch_of_two_records() {
+        let record = ThermalConductivityForUnidirectionalCompositeArgs::example();
+        let mut req = vec![0u8; BATCH_HEADER_SIZE];
+        req[0] = Endianness::NATIVE as u8;
+        req[8..].copy_from_slice(&2u64.to_ne_bytes());
+        req.extend_from_slice(&record.encode(Endianness::NATIVE));
+        req.extend_from_slice(&record.encode(Endianness::NATIVE));
+
+        let args =
+            BatchArgsMessage::<ThermalConductivityForUnidirectionalCompositeArgs>::from_bytes(&req)
+                .unwrap();
+        let resp = args.compute().unwrap();
+        let size = ThermalConductivityForUnidirectionalCompositeResponse::SIZE;
+        assert_eq!(resp.len(), 2 * size);
+        let example = ThermalConductivityForUnidirectionalCompositeArgs::response_example();
+        assert_eq!(resp[size..size + 8], example.k1.to_ne_bytes());
+
+        assert!(
+            BatchArgsMessage::<ThermalConductivityForUnidirectionalCompositeArgs>::from_bytes(
+                &req[..req.len() - 1]
+            )
+            .is_err()
+        );
+    }
+
+    #[test]
+    fn records_of_different_models_and_units() {
+        use crate::proto::ElasticModulesForUnidirectionalCompositeArgs;
+
+        let record = ElasticModulesForUnidirectionalCompositeArgs::example();
+        let mut records = vec![record; 4];
+        records[1].number_of_model = 1;
+        records[2].unit_system = 2;
+        records[2].output_unit_system = 1;
+        records[3].number_of_model = 1;
+        records[3].output_unit_system = 2;
+        let expected: Vec<u8> = records
+            .iter()
+            .flat_map(|record| record.try_compute().unwrap().encode(Endianness::NATIVE))
+            .collect();
+        let args = BatchArgsMessage {
+            endianness: Endianness::NATIVE,
+            records,
+        };
+        assert_eq!(args.compute().unwrap(), expected);
+    }
+
+    #[test]
+    fn error_names_the_record() {
+        let record = ThermalConductivityForUnidirectionalCompositeArgs::example();
+        let mut unknown = record;
+        unknown.number_of_model = 42;
+        let args = BatchArgsMessage {
+            endianness: Endianness::NATIVE,
+            records: vec![record, unknown],
+        };
+        let err = args.compute().unwrap_err();
+        assert_eq!(err.message.code, crate::proto::ErrorCode::UnknownModel);
+        assert_eq!(
+            err.message.field.as_deref(),
+            Some("records[1].number_of_model")
+        );
+
+        let mut unknown = record;
+        unknown.output_unit_system = 3;
+        let args = BatchArgsMessage {
+            endianness: Endianness::NATIVE,
+            records: vec![record, record, unknown],
+        };
+        let err = args.compute().unwrap_err();
+        assert_eq!(
+            err.message.field.as_deref(),
+            Some("records[2].output_unit_system")
+        );
+    }
+}
@@ -0,0 +1,438 @@
+use futures_util::{future::LocalBoxFuture, StreamExt};
+use mat_props_proto::{ComputationResponse, Endianness, Message, MAGIC};
+
+use crate::proto::{
+    batch_response, protocol_version, response_encoding, ApiError, ArgsMessage, BatchArgsMessage,
+    Encoding, ErrorCode, Header, Parcel, BATCH_HEADER_PY_STRUCT_FORMAT_STRING,
+    HEADER_PY_STRUCT_FORMAT_STRING, HEADER_SIZE,
+};
+
+/// The args message of a computation of [`mat_props_proto::messages`] with the examples
+/// of its messages.
+///
+/// A computation is served by `/compute/{name}`, `/compute` and `/batch/{name}` once it implements
+/// the trait and is listed in [`crate::proto::COMPUTATIONS`].
+pub(crate) trait Computation:
+    mat_props_proto::Computation<Response: serde::Serialize + for<'s> utoipa::ToSchema<'s>>
+    + for<'s> utoipa::ToSchema<'s>
+    + serde::Serialize
+    + serde::de::DeserializeOwned
+    + Copy
+    + Send
+    + 'static
+{
+    fn example() -> Self;
+    fn response_example() -> Self::Response;
+
+    /// The binary example, little endian like the endianness 0 of the example.
+    fn example_as_bytes() -> Vec<u8> {
+        Self::example().encode(Endianness::Little)
+    }
+
+    fn response_example_as_bytes() -> Vec<u8> {
+        Self::response_example().encode(Endianness::Little)
+    }
+
+    /// The names of the fields of the inputs in the order of [`mat_props::Computation::INPUTS`],
+    /// the last fields of the args message.
+    fn input_fields() -> &'static [&'static str] {
+        let number_of_inputs = <Self::Model as mat_props::Computation>::INPUTS.len();
+        &Self::FIELDS[Self::FIELDS.len() - number_of_inputs..]
+    }
+
+    /// Computes the response in the requested units, but rejects the non-finite inputs and the
+    /// infinite outputs and names the field that caused the error. The NaN outputs are the ones
+    /// the model doesn't define and are returned as is.
+    fn try_compute(&self) -> Result<Self::Response, ApiError> {
+        let inputs = self.inputs();
+        if let Some(i) = inputs.iter().position(|input| !input.is_finite()) {
+            return Err(ApiError::non_finite_input(Self::input_fields()[i]));
+        }
+        let unit_system = self.unit_system();
+        let outputs = mat_props::evaluate_in_units::<Self::Model>(
+            self.number_of_model(),
+            &inputs,
+            unit_system,
+            self.output_unit_system(),
+        )
+        .map_err(|e| {
+            let is_unknown_unit_system = matches!(e, mat_props::Error::UnknownUnitSystem);
+            let err = ApiError::from(e);
+            // the library doesn't tell which of the unit systems is unknown
+            if is_unknown_unit_system && unit_system <= mat_props::UnitSystem::Engineering as u8 {
+                err.with_field("output_unit_system")
+            } else {
+                err
+            }
+        })?;
+        if let Some(k) = outputs.iter().position(|output| output.is_infinite()) {
+            return Err(ApiError::non_finite_output(
+                <Self::Response as Message>::FIELDS[k],
+            ));
+        }
+        Ok(Self::Response::from_outputs(&outputs))
+    }
+}
+
+/// Computes the response to the args message and sends it in the encoding that the request asks for,
+/// or as the binary response message with the endianness of the args message.
+pub(crate) fn respond<C: Computation>(
+    req: &actix_web::HttpRequest,
+    args: ArgsMessage<C>,
+) -> Result<actix_web::HttpResponse, ApiError> {
+    let ArgsMessage { endianness, args } = args;
+    let response_content_type = <C::Response as Message>::CONTENT_TYPE;
+    let message = args
+        .try_compute()
+        .map_err(|e| e.negotiate(req, response_content_type, endianness))?;
+    if let Some(encoding) = response_encoding(req, response_content_type) {
+        return Ok(actix_web::HttpResponse::Ok()
+            .append_header(("Access-Control-Allow-Origin", "*"))
+            .content_type(encoding.content_type())
+            .body(encoding.encode(&message)));
+    }
+    let parcel = Parcel::new(protocol_version(req), endianness, message);
+    Ok(actix_web::Responder::respond_to(parcel, req))
+}
+
+type Handler = fn(
+    actix_web::HttpRequest,
+    actix_web::dev::Payload,
+) -> LocalBoxFuture<'static, Result<actix_web::HttpResponse, actix_web::Error>>;
+
+/// The type-erased [`Computation`], so that the computations can be looked up
+/// by their name or message id.
+pub(crate) struct ComputationRoute {
+    pub(crate) name: &'static str,
+    pub(crate) message_id: u16,
+    /// Extracts the args message from the payload and responds to it.
+    pub(crate) handle: Handler,
+    /// Extracts the batch args message from the payload and responds to it.
+    pub(crate) handle_batch: Handler,
+    /// Adds the path `/compute/{name}` of the computation to the OpenAPI document,
+    /// see [`document`].
+    pub(crate) document: fn(&mut utoipa::openapi::OpenApi, &utoipa::openapi::PathItem),
+    /// Adds the path `/batch/{name}` of the computation to the OpenAPI document,
+    /// see [`document_batch`].
+    pub(crate) document_batch: fn(&mut utoipa::openapi::OpenApi, &utoipa::openapi::PathItem),
+    /// The layouts of the messages for the generated clients.
+    pub(crate) layout: fn() -> crate::codegen::ComputationLayout,
+}
+
+impl ComputationRoute {
+    pub(crate) const fn of<C: Computation>() -> Self {
+        Self {
+            name: C::NAME,
+            message_id: C::MESSAGE_ID,
+            handle: handle::<C>,
+            handle_batch: handle_batch::<C>,
+            document: document::<C>,
+            document_batch: document_batch::<C>,
+            layout: crate::codegen::ComputationLayout::of::<C>,
+        }
+    }
+}
+
+fn handle<C: Computation>(
+    req: actix_web::HttpRequest,
+    mut payload: actix_web::dev::Payload,
+) -> LocalBoxFuture<'static, Result<actix_web::HttpResponse, actix_web::Error>> {
+    let args = <ArgsMessage<C> as actix_web::FromRequest>::from_request(&req, &mut payload);
+    Box::pin(async move { Ok(respond(&req, args.await?)?) })
+}
+
+fn handle_batch<C: Computation>(
+    req: actix_web::HttpRequest,
+    mut payload: actix_web::dev::Payload,
+) -> LocalBoxFuture<'static, Result<actix_web::HttpResponse, actix_web::Error>> {
+    let args = <BatchArgsMessage<C> as actix_web::FromRequest>::from_request(&req, &mut payload);
+    Box::pin(async move { Ok(batch_response(&req, args.await?).await?) })
+}
+
+/// The computation with the given name.
+pub(crate) fn computation_by_name(name: &str) -> Result<&'static ComputationRoute, ApiError> {
+    crate::proto::COMPUTATIONS
+        .iter()
+        .find(|computation| computation.name == name)
+        .ok_or_else(|| mat_props::Error::UnknownFunction(name.to_owned()).into())
+}
+
+/// The computation of the args message with the given id.
+pub(crate) fn computation_by_message_id(
+    message_id: u16,
+) -> Result<&'static ComputationRoute, ApiError> {
+    crate::proto::COMPUTATIONS
+        .iter()
+        .find(|computation| computation.message_id == message_id)
+        .ok_or_else(|| {
+            ApiError::new(
+                actix_web::http::StatusCode::BAD_REQUEST,
+                ErrorCode::UnknownMessageId,
+                Some("header.message_id"),
+                format!("Unknown args message {message_id}"),
+            )
+        })
+}
+
+/// Reads the header of the payload, picks the computation by the message id of the header
+/// and hands it the whole payload.
+pub(crate) async fn dispatch(
+    req: actix_web::HttpRequest,
+    mut payload: actix_web::dev::Payload,
+) -> Result<actix_web::HttpResponse, actix_web::Error> {
+    let mut head = Vec::with_capacity(HEADER_SIZE);
+    while head.len() < HEADER_SIZE {
+        match payload.next().await {
+            Some(Ok(chunk)) => head.extend_from_slice(&chunk),
+            Some(Err(_)) => {
+                return Err(actix_web::error::ErrorBadRequest(
+                    "Error receiving the payload",
+                ))
+            }
+            None => break,
+        }
+    }
+    let header = match head.get(..HEADER_SIZE) {
+        Some(bytes) if bytes.starts_with(&MAGIC) => {
+            Header::from_bytes(bytes.try_into().unwrap()).map_err(ApiError::from)?
+        }
+        _ => {
+            return Err(ApiError::new(
+                actix_web::http::StatusCode::BAD_REQUEST,
+                ErrorCode::InvalidHeader,
+                Some("header"),
+                "The args message must be preceded by the header of the protocol version 2, \
+                or sent to /compute/{name}",
+            )
+            .into())
+        }
+    };
+    let computation = computation_by_message_id(header.message_id)?;
+    let head = actix_web::web::Bytes::from(head);
+    let payload: std::pin::Pin<
+        Box<
+            dyn futures_util::Stream<
+                Item = Result<actix_web::web::Bytes, actix_web::error::PayloadError>,
+            >,
+        >,
+    > = Box::pin(futures_util::stream::once(async { Ok(head) }).chain(payload));
+    (computation.handle)(req, payload.into()).await
+}
+
+/// Adds the path `/compute/{name}` of the computation to the OpenAPI document, made of the template
+/// of the path with the request body and the 200 response of the messages of the computation.
+/// The args and the response messages are added to the schemas.
+pub(crate) fn document<C: Computation>(
+    openapi: &mut utoipa::openapi::OpenApi,
+    template: &utoipa::openapi::PathItem,
+) {
+    use utoipa::openapi::{ContentBuilder, PathItemType, Ref, RefOr};
+
+    let (args_schema_name, args_schema) = <C as utoipa::ToSchema>::schema();
+    let (response_schema_name, response_schema) = <C::Response as utoipa::ToSchema>::schema();
+    let schemas = &mut openapi
+        .components
+        .get_or_insert_with(Default::default)
+        .schemas;
+    schemas.insert(args_schema_name.to_owned(), args_schema);
+    schemas.insert(response_schema_name.to_owned(), response_schema);
+
+    let mut item = template.clone();
+    let Some(operation) = item.operations.get_mut(&PathItemType::Post) else {
+        return;
+    };
+    operation.operation_id = Some(C::NAME.to_owned());
+    operation.parameters = None;
+
+    let content = |schema_name: &str, example: Option<serde_json::Value>| {
+        ContentBuilder::new()
+            .schema(Ref::from_schema_name(schema_name))
+            .example(example)
+            .build()
+    };
+    if let Some(body) = operation.request_body.as_mut() {
+        body.description = Some(format!(
+            "Python struct format string: {:?}. See <https://docs.python.org/3/library/struct.html#format-strings>.\n\n\
+            See schema for the order of the fields (but not their sizes).\n\n\
+            The body can also be the map of the fields in JSON, MessagePack or CBOR \
+            (Content-Type: application/json, application/msgpack or application/cbor), \
+            where the endianness is ignored and the unit systems default to 0.\n\n\
+            The binary body can be preceded by the header of the protocol version 2 (Python struct format string: {:?}): \
+            the magic \"MATP\", the version 2, the endianness, the message id {} and the payload length {}.",
+            C::py_struct_format_string(),
+            HEADER_PY_STRUCT_FORMAT_STRING,
+            C::MESSAGE_ID,
+            C::SIZE,
+        ));
+        body.content.clear();
+        body.content.insert(
+            C::CONTENT_TYPE.to_owned(),
+            content(
+                args_schema_name,
+                serde_json::to_value(C::example_as_bytes()).ok(),
+            ),
+        );
+        for encoding in Encoding::ALL {
+            // the examples of the binary encodings can't be shown in the JSON of the document
+            let example = (encoding == Encoding::Json)
+                .then(|| serde_json::to_value(C::example()).ok())
+                .flatten();
+            body.content.insert(
+                encoding.content_type().to_owned(),
+                content(args_schema_name, example),
+            );
+        }
+    }
+    if let Some(RefOr::T(response)) = operation.responses.responses.get_mut("200") {
+        response.description = format!(
+            "Computes {}. \
+            Returns the binary representation of [{}] with the requested endianness, \
+            or the map of the named fields in JSON, MessagePack or CBOR if the request accepts it \
+            or its body is in it and it doesn't accept the binary response.\n\n\
+            Python struct format string: {:?}. See <https://docs.python.org/3/library/struct.html#format-strings>.\n\n\
+            The binary response is preceded by the header with the message id {} if the args message was.",
+            C::NAME,
+            <C::Model as mat_props::Computation>::OUTPUTS.join(", "),
+            <C::Response as Message>::py_struct_format_string(),
+            <C::Response as Message>::MESSAGE_ID,
+        );
+        response.content.clear();
+        response.content.insert(
+            <C::Response as Message>::CONTENT_TYPE.to_owned(),
+            content(
+                response_schema_name,
+                serde_json::to_value(C::response_example_as_bytes()).ok(),
+            ),
+        );
+        for encoding in Encoding::ALL {
+            let example = (encoding == Encoding::Json)
+                .then(|| serde_json::to_value(C::response_example()).ok())
+                .flatten();
+            response.content.insert(
+                encoding.content_type().to_owned(),
+                content(response_schema_name, example),
+            );
+        }
+    }
+    openapi
+        .paths
+        .paths
+        .insert(format!("/compute/{}", C::NAME), item);
+}
+
+/// Adds the path `/batch/{name}` of the computation to the OpenAPI document, made of the template
+/// of the path with the layouts of the records of the computation in the descriptions.
+pub(crate) fn document_batch<C: Computation>(
+    openapi: &mut utoipa::openapi::OpenApi,
+    template: &utoipa::openapi::PathItem,
+) {
+    use utoipa::openapi::{PathItemType, RefOr};
+
+    let mut item = template.clone();
+    let Some(operation) = item.operations.get_mut(&PathItemType::Post) else {
+        return;
+    };
+    operation.operation_id = Some(format!("batch_{}", C::NAME));
+    operation.parameters = None;
+    if let Some(body) = operation.request_body.as_mut() {
+        body.description = Some(format!(
+            "The header (Python struct format string: {:?}) with the endianness and the count \
+            followed by the count of packed args messages of {} (Python struct format string: {:?} each). \
+            See <https://docs.python.org/3/library/struct.html#format-strings>.",
+            BATCH_HEADER_PY_STRUCT_FORMAT_STRING,
+            C::NAME,
+            C::py_struct_format_string(),
+        ));
+    }
+    if let Some(RefOr::T(response)) = operation.responses.responses.get_mut("200") {
+        response.description = format!(
+            "Computes {} for each record. \
+            Returns the packed response messages with the endianness of the header \
+            (Python struct format string: {:?} each).",
+            C::NAME,
+            <C::Response as Message>::py_struct_format_string(),
+        );
+    }
+    openapi
+        .paths
+        .paths
+        .insert(format!("/batch/{}", C::NAME), item);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::proto::{
+        ElasticModulesForHoneycombArgs, ThermalConductivityForUnidirectionalCompositeArgs,
+        ThermalConductivityForUnidirectionalCompositeResponse,
+    };
+    use actix_web::http::StatusCode;
+
+    fn dispatch_payload(payload: Vec<u8>) -> Result<actix_web::web::Bytes, StatusCode> {
+        let (req, payload) = actix_web::test::TestRequest::post()
+            .set_payload(payload)
+            .to_http_parts();
+        actix_web::rt::System::new().block_on(async {
+            let resp = dispatch(req, payload)
+                .await
+                .map_err(|e| e.as_response_error().status_code())?;
+            Ok(actix_web::body::to_bytes(resp.into_body()).await.unwrap())
+        })
+    }
+
+    #[test]
+    fn computations_have_distinct_names_and_message_ids() {
+        let computations = &crate::proto::COMPUTATIONS;
+        for (i, computation) in computations.iter().enumerate() {
+            for other in &computations[i + 1..] {
+                assert_ne!(computation.name, other.name);
+                assert_ne!(computation.message_id, other.message_id);
+            }
+            assert_eq!(
+                computation_by_name(computation.name).unwrap().message_id,
+                computation.message_id
+            );
+        }
+        let Err(err) = computation_by_name("elastic_modules") else {
+            panic!("elastic_modules is not a computation");
+        };
+        assert_eq!(err.message.status, 404);
+    }
+
+    #[test]
+    fn dispatch_picks_the_computation_by_the_message_id() {
+        type Args = ThermalConductivityForUnidirectionalCompositeArgs;
+        type Resp = ThermalConductivityForUnidirectionalCompositeResponse;
+        let header = Header::new(Endianness::NATIVE, Args::MESSAGE_ID, Args::SIZE).to_bytes();
+        let bytes =
+            dispatch_payload([&header[..], &Args::example_as_bytes()[..]].concat()).unwrap();
+        let response_header = Header::new(Endianness::NATIVE, Resp::MESSAGE_ID, Resp::SIZE);
+        assert_eq!(bytes[..HEADER_SIZE], response_header.to_bytes());
+        assert_eq!(
+            bytes[HEADER_SIZE..],
+            Args::response_example().encode(Endianness::NATIVE)
+        );
+
+        // the header of another message doesn't match the payload
+        let header = Header::new(
+            Endianness::NATIVE,
+            ElasticModulesForHoneycombArgs::MESSAGE_ID,
+            Args::SIZE,
+        )
+        .to_bytes();
+        assert_eq!(
+            dispatch_payload([&header[..], &Args::example_as_bytes()[..]].concat()),
+            Err(StatusCode::BAD_REQUEST)
+        );
+
+        let header = Header::new(Endianness::NATIVE, 0x7fff, Args::SIZE).to_bytes();
+        assert_eq!(
+            dispatch_payload([&header[..], &Args::example_as_bytes()[..]].concat()),
+            Err(StatusCode::BAD_REQUEST)
+        );
+        assert_eq!(
+            dispatch_payload(Args::example_as_bytes().to_vec()),
+            Err(StatusCode::BAD_REQUEST)
+        );
+    }
+}
@@ -0,0 +1,145 @@
+use serde::{de::DeserializeOwned, Serialize};
+
+use crate::proto::{ApiError, ErrorCode};
+
+pub(crate) const JSON_CONTENT_TYPE: &str = "application/json";
+pub(crate) const MESSAGE_PACK_CONTENT_TYPE: &str = "application/msgpack";
+pub(crate) const CBOR_CONTENT_TYPE: &str = "application/cbor";
+/// The largest self-describing args message.
+pub(crate) const MAX_ENCODED_ARGS_SIZE: usize = 1 << 12;
+
+/// The self-describing encodings of the messages, an alternative to their `#[repr(C)]` layout.
+///
+/// The messages are encoded as maps with the names of the fields as keys.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub(crate) enum Encoding {
+    Json,
+    MessagePack,
+    Cbor,
+}
+
+impl Encoding {
+    pub(crate) const ALL: [Self; 3] = [Self::Json, Self::MessagePack, Self::Cbor];
+
+    pub(crate) const fn content_type(self) -> &'static str {
+        match self {
+            Self::Json => JSON_CONTENT_TYPE,
+            Self::MessagePack => MESSAGE_PACK_CONTENT_TYPE,
+            Self::Cbor => CBOR_CONTENT_TYPE,
+        }
+    }
+
+    fn aliases(self) -> &'static [&'static str] {
+        match self {
+            Self::Json => &[JSON_CONTENT_TYPE],
+            Self::MessagePack => &[
+                MESSAGE_PACK_CONTENT_TYPE,
+                "application/x-msgpack",
+                "application/vnd.msgpack",
+            ],
+            Self::Cbor => &[CBOR_CONTENT_TYPE],
+        }
+    }
+
+    /// The encoding of the body of the request, if any.
+    pub(crate) fn of_request(req: &actix_web::HttpRequest) -> Option<Self> {
+        let Some(Ok(essence)) = super::content_type_essence(req) else {
+            return None;
+        };
+        Self::ALL.into_iter().find(|encoding| {
+            encoding
+                .aliases()
+                .iter()
+                .any(|alias| essence.eq_ignore_ascii_case(alias))
+        })
+    }
+
+    /// The first of the encodings that the request accepts, if any.
+    pub(crate) fn accepted(req: &actix_web::HttpRequest) -> Option<Self> {
+        let accept = accept(req)?;
+        Self::ALL.into_iter().find(|encoding| {
+            encoding
+                .aliases()
+                .iter()
+                .any(|alias| accept.contains(alias))
+        })
+    }
+
+    pub(crate) fn encode<T: Serialize>(self, value: &T) -> Vec<u8> {
+        match self {
+            Self::Json => serde_json::to_vec(value).unwrap(),
+            Self::MessagePack => rmp_serde::to_vec_named(value).unwrap(),
+            Self::Cbor => {
+                let mut bytes = Vec::new();
+                ciborium::into_writer(value, &mut bytes).unwrap();
+                bytes
+            }
+        }
+    }
+
+    pub(crate) fn decode<T: DeserializeOwned>(self, bytes: &[u8]) -> Result<T, ApiError> {
+        let (code, res) = match self {
+            Self::Json => (
+                ErrorCode::InvalidJson,
+                serde_json::from_slice(bytes).map_err(|e| e.to_string()),
+            ),
+            Self::MessagePack => (
+                ErrorCode::InvalidMessagePack,
+                rmp_serde::from_slice(bytes).map_err(|e| e.to_string()),
+            ),
+            Self::Cbor => (
+                ErrorCode::InvalidCbor,
+                ciborium::from_reader(bytes).map_err(|e| e.to_string()),
+            ),
+        };
+        res.map_err(|detail| {
+            ApiError::new(actix_web::http::StatusCode::BAD_REQUEST, code, None, detail)
+        })
+    }
+}
+
+fn accept(req: &actix_web::HttpRequest) -> Option<&str> {
+    req.headers()
+        .get(actix_web::http::header::ACCEPT)
+        .and_then(|accept| accept.to_str().ok())
+}
+
+/// Whether the request accepts JSON.
+pub(crate) fn accepts_json(req: &actix_web::HttpRequest) -> bool {
+    accept(req).is_some_and(|accept| accept.contains(JSON_CONTENT_TYPE))
+}
+
+/// The encoding of the response rather than the binary response message: the encoding that
+/// the request accepts, or the encoding of its body if it doesn't accept the binary response message.
+pub(crate) fn response_encoding(
+    req: &actix_web::HttpRequest,
+    response_content_type: &str,
+) -> Option<Encoding> {
+    if let Some(encoding) = Encoding::accepted(req) {
+        return Some(encoding);
+    }
+    let accepts_binary = accept(req).is_some_and(|accept| accept.contains(response_content_type));
+    Encoding::of_request(req).filter(|_| !accepts_binary)
+}
+
+/// Reads the args message of at most [`MAX_ENCODED_ARGS_SIZE`] bytes in the encoding.
+pub(crate) async fn read_encoded<T: DeserializeOwned>(
+    encoding: Encoding,
+    mut payload: actix_web::web::Payload,
+) -> Result<T, actix_web::Error> {
+    use futures_util::StreamExt;
+
+    let mut buf = Vec::new();
+    while let Some(chunk) = payload.next().await {
+        let Ok(chunk) = chunk else {
+            return Err(actix_web::error::ErrorBadRequest(
+                "Error receiving the payload",
+            ));
+        };
+        if buf.len() + chunk.len() > MAX_ENCODED_ARGS_SIZE {
+            return Err(ApiError::payload_too_large(MAX_ENCODED_ARGS_SIZE).into());
+        }
+        buf.extend_from_slice(&chunk);
+    }
+    Ok(encoding.decode(&buf)?)
+}
@@ -0,0 +1,174 @@
+use mat_props_proto::{
+    Endianness, ErrorCode, ErrorMessage, ERROR_CONTENT_TYPE, ERROR_MESSAGE_CONTENT_TYPE,
+};
+
+/// The error of a handler. It is sent as `application/problem+json` unless the client
+/// accepts the binary representation, see [`ApiError::negotiate`].
+#[derive(Debug)]
+pub(crate) struct ApiError {
+    pub(crate) message: ErrorMessage,
+    binary: Option<Endianness>,
+}
+
+impl ApiError {
+    pub(crate) fn new(
+        status: actix_web::http::StatusCode,
+        code: ErrorCode,
+        field: Option<&str>,
+        detail: impl Into<String>,
+    ) -> Self {
+        ErrorMessage::new(status.as_u16(), code, field, detail).into()
+    }
+
+    pub(crate) fn invalid_endianness() -> Self {
+        ErrorMessage::invalid_endianness().into()
+    }
+
+    pub(crate) fn non_finite_input(field: &str) -> Self {
+        ErrorMessage::non_finite_input(field).into()
+    }
+
+    pub(crate) fn non_finite_output(field: &str) -> Self {
+        ErrorMessage::non_finite_output(field).into()
+    }
+
+    pub(crate) fn unsupported_content_type(expected: &str) -> Self {
+        Self::new(
+            actix_web::http::StatusCode::UNSUPPORTED_MEDIA_TYPE,
+            ErrorCode::UnsupportedContentType,
+            None,
+            format!("Expected the content type `{expected}`"),
+        )
+    }
+
+    pub(crate) fn invalid_length(expected: usize, actual: usize) -> Self {
+        ErrorMessage::invalid_length(expected, actual).into()
+    }
+
+    pub(crate) fn payload_too_large(limit: usize) -> Self {
+        Self::new(
+            actix_web::http::StatusCode::PAYLOAD_TOO_LARGE,
+            ErrorCode::PayloadTooLarge,
+            None,
+            format!("The payload exceeds {limit} bytes"),
+        )
+    }
+
+    pub(crate) fn with_field(mut self, field: &str) -> Self {
+        self.message.field = Some(field.to_owned());
+        self
+    }
+
+    /// Sends the error as the binary error message with the given endianness if the request
+    /// accepts either it or the binary response of the endpoint.
+    pub(crate) fn negotiate(
+        mut self,
+        req: &actix_web::HttpRequest,
+        response_content_type: &str,
+        endianness: Endianness,
+    ) -> Self {
+        let accepts_binary = req
+            .headers()
+            .get(actix_web::http::header::ACCEPT)
+            .and_then(|accept| accept.to_str().ok())
+            .is_some_and(|accept| {
+                accept.contains(ERROR_MESSAGE_CONTENT_TYPE)
+                    || accept.contains(response_content_type)
+            });
+        if accepts_binary {
+            self.binary = Some(endianness);
+        }
+        self
+    }
+
+    /// The binary error message: the header (Python struct format string: "HHHH") with the status,
+    /// the code, the length of the field and the length of the detail followed by the field
+    /// and the detail in UTF-8.
+    pub(crate) fn to_bytes(&self, endianness: Endianness) -> Vec<u8> {
+        let ErrorMessage {
+            status,
+            code,
+            ref field,
+            ref detail,
+            ..
+        } = self.message;
+        let field = field.as_deref().unwrap_or_default();
+        let header = [status, code as u16, field.len() as u16, detail.len() as u16];
+        let mut bytes = Vec::with_capacity(8 + field.len() + detail.len());
+        for value in header {
+            match endianness {
+                Endianness::Little => bytes.extend_from_slice(&value.to_le_bytes()),
+                Endianness::Big => bytes.extend_from_slice(&value.to_be_bytes()),
+            }
+        }
+        bytes.extend_from_slice(field.as_bytes());
+        bytes.extend_from_slice(detail.as_bytes());
+        bytes
+    }
+}
+
+impl std::fmt::Display for ApiError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&self.message.detail)
+    }
+}
+
+impl From<ErrorMessage> for ApiError {
+    fn from(message: ErrorMessage) -> Self {
+        Self {
+            message,
+            binary: None,
+        }
+    }
+}
+
+impl From<mat_props::Error> for ApiError {
+    fn from(e: mat_props::Error) -> Self {
+        ErrorMessage::from(e).into()
+    }
+}
+
+impl From<mat_props_proto::InvalidLength> for ApiError {
+    fn from(e: mat_props_proto::InvalidLength) -> Self {
+        Self::invalid_length(e.expected, e.actual)
+    }
+}
+
+impl actix_web::ResponseError for ApiError {
+    fn status_code(&self) -> actix_web::http::StatusCode {
+        actix_web::http::StatusCode::from_u16(self.message.status)
+            .unwrap_or(actix_web::http::StatusCode::INTERNAL_SERVER_ERROR)
+    }
+
+    fn error_response(&self) -> actix_web::HttpResponse {
+        let mut resp = actix_web::HttpResponse::build(self.status_code());
+        resp.append_header(("Access-Control-Allow-Origin", "*"));
+        match self.binary {
+            Some(endianness) => resp
+                .content_type(ERROR_MESSAGE_CONTENT_TYPE)
+                .body(self.to_bytes(endianness)),
+            None => resp
+                .content_type(ERROR_CONTENT_TYPE)
+                .body(serde_json::to_string(&self.message).unwrap()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn error_message_as_json_and_bytes() {
+        let err = ApiError::from(mat_props::Error::UnknownModel);
+        let json = serde_json::to_value(&err.message).unwrap();
+        assert_eq!(json["status"], 400);
+        assert_eq!(json["code"], "unknown_model");
+        assert_eq!(json["field"], "number_of_model");
+
+        let bytes = err.to_bytes(Endianness::Big);
+        assert_eq!(bytes[..8], [1, 144, 0, 2, 0, 15, 0, 13]);
+        assert_eq!(&bytes[8..23], b"number_of_model");
+        assert_eq!(&bytes[23..], b"Unknown model");
+    }
+}
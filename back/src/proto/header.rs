@@ -0,0 +1,146 @@
+use mat_props_proto::{Header, HeaderError, ProtocolVersion, HEADER_SIZE, MAGIC};
+
+use crate::proto::{ApiError, ErrorCode};
+
+/// The version of the args message of the request, see [`HeaderReader::version`].
+pub(crate) fn protocol_version(req: &actix_web::HttpRequest) -> ProtocolVersion {
+    actix_web::HttpMessage::extensions(req)
+        .get::<ProtocolVersion>()
+        .copied()
+        .unwrap_or(ProtocolVersion::V1)
+}
+
+impl From<HeaderError> for ApiError {
+    fn from(e: HeaderError) -> Self {
+        match e {
+            HeaderError::InvalidMagic => invalid_header(e.to_string()),
+            HeaderError::InvalidEndianness(_) => ApiError::invalid_endianness(),
+        }
+    }
+}
+
+fn invalid_header(detail: impl Into<String>) -> ApiError {
+    ApiError::new(
+        actix_web::http::StatusCode::BAD_REQUEST,
+        ErrorCode::InvalidHeader,
+        Some("header"),
+        detail,
+    )
+}
+
+/// Splits the optional header off the chunks of the payload.
+#[derive(Default)]
+pub(crate) struct HeaderReader {
+    bytes: [u8; HEADER_SIZE],
+    len: usize,
+    has_header: Option<bool>,
+}
+
+impl HeaderReader {
+    /// Takes the bytes of the header from the chunk and returns the rest.
+    pub(crate) fn feed<'c>(&mut self, chunk: &'c [u8]) -> &'c [u8] {
+        // an empty chunk can't tell whether the payload starts with the header
+        let Some(&first) = chunk.first() else {
+            return chunk;
+        };
+        let has_header = *self.has_header.get_or_insert(first == MAGIC[0]);
+        if !has_header {
+            return chunk;
+        }
+        let n = (HEADER_SIZE - self.len).min(chunk.len());
+        self.bytes[self.len..self.len + n].copy_from_slice(&chunk[..n]);
+        self.len += n;
+        &chunk[n..]
+    }
+
+    /// Whether the header was fed completely or there is no header.
+    pub(crate) fn is_complete(&self) -> bool {
+        match self.has_header {
+            Some(true) => self.len == HEADER_SIZE,
+            Some(false) => true,
+            None => false,
+        }
+    }
+
+    /// Validates the header, if any, against the message that follows it. It can be called
+    /// as soon as [`Self::is_complete`] to reject the mismatched message before reading it.
+    pub(crate) fn version(
+        &self,
+        message_id: u16,
+        message_size: usize,
+    ) -> Result<ProtocolVersion, ApiError> {
+        if self.has_header != Some(true) {
+            return Ok(ProtocolVersion::V1);
+        }
+        if !self.is_complete() {
+            return Err(ApiError::invalid_length(HEADER_SIZE, self.len));
+        }
+        let header = Header::from_bytes(&self.bytes)?;
+        if header.version != ProtocolVersion::V2 as u8 {
+            return Err(ApiError::new(
+                actix_web::http::StatusCode::BAD_REQUEST,
+                ErrorCode::UnsupportedVersion,
+                Some("header.version"),
+                format!(
+                    "Unsupported protocol version {}, the supported versions are 1 and 2",
+                    header.version
+                ),
+            ));
+        }
+        if header.message_id != message_id {
+            return Err(ApiError::new(
+                actix_web::http::StatusCode::BAD_REQUEST,
+                ErrorCode::MessageIdMismatch,
+                Some("header.message_id"),
+                format!(
+                    "Expected the message {message_id} but got the message {}",
+                    header.message_id
+                ),
+            ));
+        }
+        if header.payload_length as usize != message_size {
+            return Err(invalid_header(format!(
+                "Expected the payload length {message_size} but got {}",
+                header.payload_length
+            )));
+        }
+        Ok(ProtocolVersion::V2)
+    }
+
+    /// Checks that the endianness of the header, if any, is the endianness of the message.
+    pub(crate) fn check_message_endianness(&self, message_endianness: u8) -> Result<(), ApiError> {
+        if self.has_header == Some(true) && self.bytes[5] != message_endianness {
+            return Err(invalid_header(
+                "The endianness of the header doesn't match the endianness of the message",
+            ));
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use mat_props_proto::Endianness;
+
+    #[test]
+    fn header_is_split_across_chunks() {
+        let header = Header::new(Endianness::NATIVE, 3, 32).to_bytes();
+        let mut reader = HeaderReader::default();
+        assert!(reader.feed(&[]).is_empty());
+        assert!(!reader.is_complete());
+        assert!(reader.feed(&header[..5]).is_empty());
+        assert_eq!(reader.feed(&[&header[5..], &[7u8][..]].concat()), [7]);
+        assert_eq!(reader.version(3, 32).unwrap(), ProtocolVersion::V2);
+        assert!(reader
+            .check_message_endianness(Endianness::NATIVE as u8)
+            .is_ok());
+        assert!(reader.version(2, 32).is_err());
+        assert!(reader.version(3, 40).is_err());
+
+        let mut reader = HeaderReader::default();
+        assert!(reader.feed(&[]).is_empty());
+        assert_eq!(reader.feed(&[0, 1]), [0, 1]);
+        assert_eq!(reader.version(3, 32).unwrap(), ProtocolVersion::V1);
+    }
+}
@@ -0,0 +1,66 @@
+use mat_props::{Material, MaterialKind};
+use serde::Serialize;
+
+#[derive(Clone, Copy, Serialize, utoipa::ToSchema)]
+#[serde(rename_all = "snake_case")]
+pub(crate) enum MaterialKindMessage {
+    Fibre,
+    Matrix,
+    Core,
+}
+
+impl From<MaterialKind> for MaterialKindMessage {
+    fn from(kind: MaterialKind) -> Self {
+        match kind {
+            MaterialKind::Fibre => Self::Fibre,
+            MaterialKind::Matrix => Self::Matrix,
+            MaterialKind::Core => Self::Core,
+        }
+    }
+}
+
+/// A constituent material with its properties in SI units.
+///
+/// The properties are named after the prefixes of the fields of the args messages,
+/// e.g. `e` is used for `e_for_fiber`.
+#[derive(Clone, Serialize, utoipa::ToSchema)]
+#[schema(example = json!({
+    "name": "T300",
+    "kind": "fibre",
+    "e": 230e9,
+    "nu": 0.2,
+    "alpha": -0.41e-6,
+    "k": 10.5,
+    "rho": 1760.0,
+    "source": "Toray, T300 Technical Data Sheet No. CFA-001"
+}))]
+pub(crate) struct MaterialMessage {
+    pub(crate) name: String,
+    pub(crate) kind: MaterialKindMessage,
+    /// Young's modulus, Pa.
+    pub(crate) e: Option<f64>,
+    /// Poisson's ratio.
+    pub(crate) nu: Option<f64>,
+    /// The coefficient of thermal expansion, 1/K.
+    pub(crate) alpha: Option<f64>,
+    /// Thermal conductivity, W/(m·K).
+    pub(crate) k: Option<f64>,
+    /// Density, kg/m³.
+    pub(crate) rho: Option<f64>,
+    pub(crate) source: String,
+}
+
+impl From<&Material> for MaterialMessage {
+    fn from(material: &Material) -> Self {
+        Self {
+            name: material.name.clone(),
+            kind: material.kind.into(),
+            e: material.e,
+            nu: material.nu,
+            alpha: material.alpha,
+            k: material.k,
+            rho: material.rho,
+            source: material.source.clone(),
+        }
+    }
+}
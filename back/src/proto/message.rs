@@ -0,0 +1,136 @@
+use futures_util::{future::LocalBoxFuture, StreamExt};
+use mat_props_proto::{Endianness, Message};
+
+use crate::proto::{
+    check_content_length, check_content_type, read_encoded, ApiError, Computation, Encoding,
+    HeaderReader, ProtocolVersion,
+};
+
+/// The extractor of the args message of a computation of [`mat_props_proto::messages`]: the binary
+/// message, optionally preceded by the [`crate::proto::Header`], or the map of its fields in an [`Encoding`].
+pub(crate) struct ArgsMessage<C> {
+    /// The endianness of the binary response, the native one for the encoded args message.
+    pub(crate) endianness: Endianness,
+    pub(crate) args: C,
+}
+
+impl<C: Computation> actix_web::FromRequest for ArgsMessage<C> {
+    type Error = actix_web::Error;
+    type Future = LocalBoxFuture<'static, Result<Self, actix_web::Error>>;
+
+    fn from_request(
+        req: &actix_web::HttpRequest,
+        payload: &mut actix_web::dev::Payload,
+    ) -> Self::Future {
+        let encoding = Encoding::of_request(req);
+        let framing = if encoding.is_some() {
+            Ok(())
+        } else {
+            check_content_type(req, C::CONTENT_TYPE)
+                .and_then(|()| check_content_length(req, C::SIZE))
+        };
+        let payload = actix_web::web::Payload::from_request(req, payload);
+        let req = req.clone();
+        Box::pin(async move {
+            framing?;
+            let mut p = payload.await?;
+            if let Some(encoding) = encoding {
+                return Ok(Self {
+                    // the decoded numbers are already native
+                    endianness: Endianness::NATIVE,
+                    args: read_encoded(encoding, p).await?,
+                });
+            }
+            let mut header = HeaderReader::default();
+            let mut buf = Vec::with_capacity(C::SIZE);
+            while let Some(chunk) = p.next().await {
+                let Ok(chunk) = chunk else {
+                    return Err(actix_web::error::ErrorBadRequest(
+                        "Error receiving the payload",
+                    ));
+                };
+                let chunk = header.feed(&chunk);
+                // rejects the header of another message before reading the message
+                if header.is_complete() {
+                    header.version(C::MESSAGE_ID, C::SIZE)?;
+                }
+                if buf.len() + chunk.len() > C::SIZE {
+                    return Err(ApiError::payload_too_large(C::SIZE).into());
+                }
+                buf.extend_from_slice(chunk);
+            }
+            let version = header.version(C::MESSAGE_ID, C::SIZE)?;
+            // the chunked payload has no Content-Length, so its length is checked only here
+            if buf.len() != C::SIZE {
+                return Err(ApiError::invalid_length(C::SIZE, buf.len()).into());
+            }
+            let Some(endianness) = Endianness::try_from_u8(buf[0]) else {
+                return Err(ApiError::invalid_endianness().into());
+            };
+            header.check_message_endianness(buf[0])?;
+            let args = C::decode(&buf, endianness).map_err(ApiError::from)?;
+            actix_web::HttpMessage::extensions_mut(&req).insert(version);
+            Ok(Self { endianness, args })
+        })
+    }
+}
+
+/// The binary response message, preceded by the [`crate::proto::Header`] since [`ProtocolVersion::V2`].
+pub(crate) struct Parcel<M> {
+    pub(crate) version: ProtocolVersion,
+    pub(crate) endianness: Endianness,
+    pub(crate) message: M,
+}
+
+impl<M: Message> Parcel<M> {
+    pub(crate) fn new(version: ProtocolVersion, endianness: Endianness, message: M) -> Self {
+        Self {
+            version,
+            endianness,
+            message,
+        }
+    }
+
+    pub(crate) fn to_bytes(&self) -> Vec<u8> {
+        match self.version {
+            ProtocolVersion::V1 => self.message.encode(self.endianness),
+            ProtocolVersion::V2 => self.message.encode_with_header(self.endianness),
+        }
+    }
+}
+
+impl<M: Message> actix_web::Responder for Parcel<M> {
+    type Body = actix_web::body::BoxBody;
+
+    fn respond_to(self, _: &actix_web::HttpRequest) -> actix_web::HttpResponse {
+        actix_web::HttpResponse::Ok()
+            .append_header(("Access-Control-Allow-Origin", "*"))
+            .body(self.to_bytes())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::proto::{
+        Header, ThermalConductivityForUnidirectionalCompositeArgs as Args,
+        ThermalConductivityForUnidirectionalCompositeResponse as Resp, HEADER_SIZE,
+    };
+
+    #[test]
+    fn parcel_has_the_header_since_v2() {
+        let example = Args::response_example();
+        let to_bytes = |version| Parcel::new(version, Endianness::NATIVE, example).to_bytes();
+        assert_eq!(
+            to_bytes(ProtocolVersion::V1),
+            example.encode(Endianness::NATIVE)
+        );
+        let bytes = to_bytes(ProtocolVersion::V2);
+        let header = Header::from_bytes(bytes[..HEADER_SIZE].try_into().unwrap()).unwrap();
+        assert_eq!(
+            header,
+            Header::new(Endianness::NATIVE, Resp::MESSAGE_ID, Resp::SIZE)
+        );
+        assert_eq!(bytes[HEADER_SIZE..], example.encode(Endianness::NATIVE));
+    }
+}
@@ -1,500 +1,440 @@
+mod batch;
+mod computation;
 mod elastic_modules_for_honeycomb;
 mod elastic_modules_for_unidirectional_composite;
+mod encoding;
+mod error;
+mod header;
+mod materials;
+mod message;
+mod sweep;
 mod thermal_conductivity_for_unidirectional_composite;
 mod thermal_expansion_for_honeycomb;
 mod thermal_expansion_for_unidirectional_composite;
 
-pub(crate) use elastic_modules_for_honeycomb::{
-    ElasticModulesForHoneycombArgsMessage, ElasticModulesForHoneycombResponseMessage,
-    ElasticModulesForHoneycombResponseParcel,
+pub(crate) use batch::{
+    batch_response, BatchArgsMessage, BATCH_ARGS_CONTENT_TYPE,
+    BATCH_HEADER_PY_STRUCT_FORMAT_STRING, BATCH_RESPONSE_CONTENT_TYPE,
 };
-pub(crate) use elastic_modules_for_unidirectional_composite::{
-    ElasticModulesForUnidirectionalCompositeArgsMessage,
-    ElasticModulesForUnidirectionalCompositeResponseMessage,
-    ElasticModulesForUnidirectionalCompositeResponseParcel,
+pub(crate) use computation::{computation_by_name, dispatch, Computation, ComputationRoute};
+pub(crate) use encoding::{accepts_json, read_encoded, response_encoding, Encoding};
+pub(crate) use error::ApiError;
+pub(crate) use header::{protocol_version, HeaderReader};
+pub(crate) use mat_props_proto::messages::{
+    ElasticModulesForHoneycombArgs, ElasticModulesForUnidirectionalCompositeArgs,
+    ThermalConductivityForUnidirectionalCompositeArgs, ThermalExpansionForHoneycombArgs,
+    ThermalExpansionForUnidirectionalCompositeArgs,
 };
-pub(crate) use thermal_conductivity_for_unidirectional_composite::{
-    ThermalConductivityForUnidirectionalCompositeArgsMessage,
-    ThermalConductivityForUnidirectionalCompositeResponseMessage,
-    ThermalConductivityForUnidirectionalCompositeResponseParcel,
+pub(crate) use mat_props_proto::{
+    ErrorCode, ErrorMessage, Header, ProtocolVersion, HEADER_PY_STRUCT_FORMAT_STRING, HEADER_SIZE,
 };
-pub(crate) use thermal_expansion_for_honeycomb::{
-    ThermalExpansionForHoneycombArgsMessage, ThermalExpansionForHoneycombResponseMessage,
-    ThermalExpansionForHoneycombResponseParcel,
+pub(crate) use materials::{MaterialKindMessage, MaterialMessage};
+pub(crate) use message::{ArgsMessage, Parcel};
+pub(crate) use sweep::{
+    compute_sweep, sweep_outputs_as_bytes, AxisArgs, GridArgs, SweepArgsMessage,
+    SweepResponseMessage, MAX_SWEEP_POINTS, SWEEP_RESPONSE_CONTENT_TYPE,
 };
-pub(crate) use thermal_expansion_for_unidirectional_composite::{
-    ThermalExpansionForUnidirectionalCompositeArgsMessage,
-    ThermalExpansionForUnidirectionalCompositeResponseMessage,
-    ThermalExpansionForUnidirectionalCompositeResponseParcel,
+// outside of the tests, the response messages are reached through `Computation::Response`
+#[cfg(test)]
+pub(crate) use mat_props_proto::messages::{
+    ElasticModulesForHoneycombResponse, ElasticModulesForUnidirectionalCompositeResponse,
+    ThermalConductivityForUnidirectionalCompositeResponse, ThermalExpansionForHoneycombResponse,
+    ThermalExpansionForUnidirectionalCompositeResponse,
 };
 
-macro_rules! decl_req_message {
-    (@swap_bytes ($self:ident.$field:ident : u8)) => {};
-    (@swap_bytes ($self:ident.$field:ident : f64)) => {
-        $self.$field = f64::from_bits($self.$field.to_bits().swap_bytes());
-    };
-    (@py_struct_format_string_char (u8)) => { 'B' };
-    (@py_struct_format_string_char (f64)) => { 'd' };
-    (@to_py_struct_format_string_raw_loop($buf:ident, $cur_size:ident, $($ty:tt),+)) => {
-        $(
-            let size: usize = std::mem::size_of::<$ty>();
-            let alignment: usize = std::mem::align_of::<$ty>();
-            let padding = (alignment - ($cur_size % alignment)) % alignment;
-            for _ in 0..padding {
-                $buf.push('x');
-            }
-            let ch = crate::proto::decl_req_message!(@py_struct_format_string_char($ty));
-            $buf.push(ch);
-            // using += instead of the fully qualified syntax rendered the line an expression rather than a statement
-            #[allow(unused_assignments)]
-            <usize as core::ops::AddAssign>::add_assign(&mut $cur_size, padding + size);
-        )+
-    };
-    (
-        #[content_type = $content_type:tt]
-        message $name:ident {
-        $(
-            $(#[$attr:meta])*
-            $vis:vis $field:ident : $ty:ident
-        ),+
-    }) => {
-        #[derive(Clone, Copy, utoipa::ToSchema)]
-        #[repr(C)]
-        pub(crate) struct $name {
-            /// 0: little endian, 1: big endian.
-            ///
-            /// The endianness is the first field to enable optimization where
-            /// the bytes of multi-byte fileds are swapped to match the native endianness
-            /// of the server as they are received.
-            #[schema(minimum = 0, maximum = 1)]
-            pub(crate) endianness: u8,
-            $(
-                $(#[$attr])*
-                $vis $field: $ty
-            ),+
-        }
-
-        impl $name {
-            pub(crate) const SIZE: usize = std::mem::size_of::<Self>();
-            #[cfg(test)]
-            pub(crate) const FIELD_COUNT: usize = {
-                // 1 accounts for "endianness" field
-                let mut count = 1;
-                $(
-                    stringify!($field);
-                    count += 1;
-                )+
-                count
-            };
-            #[cfg(test)]
-            pub(crate) const FIELDS: [&'static str; Self::FIELD_COUNT] = [
-                "endianness",
-                $(
-                    stringify!($field),
-                )+
-            ];
-
-            #[inline]
-            pub(crate) const fn into_bytes(self) -> [u8; Self::SIZE] {
-                debug_assert!(core::mem::size_of::<Self>() == core::mem::size_of::<[u8; Self::SIZE]>());
-                unsafe { std::mem::transmute::<Self, [u8; Self::SIZE]>(self) }
-            }
-
-            #[inline]
-            pub(crate) fn endianness(&self) -> Option<crate::endianness::Endianness> {
-                crate::endianness::Endianness::try_from_u8(self.endianness)
-            }
-
-            pub(crate) fn reorder_bytes(&mut self) {
-                $(
-                    crate::proto::decl_req_message!(@swap_bytes(self.$field : $ty));
-                )+
-            }
-
-            pub(crate) fn py_struct_format_string() -> String {
-                let mut buf = String::new();
-                let mut cur_size = 0;
-
-                // for alignment
-                buf.push('B');
-                cur_size += 1;
-
-                crate::proto::decl_req_message!(@to_py_struct_format_string_raw_loop(buf, cur_size, $($ty),+));
-                buf
-            }
-
-            pub(crate) fn content_type() -> &'static str {
-                $content_type
-            }
-
-            pub(crate) const fn example_as_bytes() -> [u8; Self::SIZE] {
-                Self::example().into_bytes()
-            }
-
-            // raison d'être:
-            // https://stackoverflow.com/questions/48782047/how-do-i-use-serde-to-deserialize-arrays-greater-than-32-elements-such-as-u8
-            pub(crate) const fn example_as_serde_big_array() -> serde_big_array::Array<u8, { Self::SIZE }> {
-                serde_big_array::Array(Self::example_as_bytes())
-            }
+/// The computations served by `/compute/{name}`, `/compute` and `/batch/{name}`.
+pub(crate) static COMPUTATIONS: [ComputationRoute; 5] = [
+    ComputationRoute::of::<ElasticModulesForUnidirectionalCompositeArgs>(),
+    ComputationRoute::of::<ElasticModulesForHoneycombArgs>(),
+    ComputationRoute::of::<ThermalConductivityForUnidirectionalCompositeArgs>(),
+    ComputationRoute::of::<ThermalExpansionForUnidirectionalCompositeArgs>(),
+    ComputationRoute::of::<ThermalExpansionForHoneycombArgs>(),
+];
+
+/// The `Content-Type` of the request without the parameters, e.g. `; charset=utf-8`.
+fn content_type_essence(req: &actix_web::HttpRequest) -> Option<Result<&str, ()>> {
+    let content_type = req.headers().get(actix_web::http::header::CONTENT_TYPE)?;
+    Some(
+        content_type
+            .to_str()
+            .map(|content_type| content_type.split(';').next().unwrap_or_default().trim())
+            .map_err(|_| ()),
+    )
+}
 
-            #[cfg(test)]
-            pub(crate) fn js_build_args_buffer() -> String {
-                use std::fmt::Write;
-                let mut s = String::new();
-                write!(&mut s, "const args_buffer = new ArrayBuffer({});\n", Self::SIZE).unwrap();
-                write!(&mut s, "const args = new DataView(args_buffer);\n").unwrap();
-                let py_struct_format_string = Self::py_struct_format_string();
-                let mut offset = 0;
-                let mut field_idx = 0;
-                for ch in py_struct_format_string.chars() {
-                    match ch {
-                        'B' => {
-                            write!(&mut s, "args.setUint8({}, {});\n", offset, Self::FIELDS[field_idx]).unwrap();
-                            offset += 1;
-                            field_idx += 1;
-                        },
-                        'x' => {
-                            write!(
-                                &mut s,
-                                "args.setUint8({}, 0); // padding\n",
-                                offset,
-                            ).unwrap();
-                            offset += 1;
-                        },
-                        'd' => {
-                            write!(&mut s, "args.setFloat64({}, {}, native_endianness);\n", offset, Self::FIELDS[field_idx]).unwrap();
-                            offset += 8;
-                            field_idx += 1;
-                        },
-                        _ => panic!("Unsupported character in py_struct_format_string: {}", ch),
-                    };
-                }
-                s
-            }
-        }
+/// Checks the `Content-Type` of the request against the content type of the message.
+///
+/// A missing `Content-Type` is accepted because the browsers send none with an `ArrayBuffer`
+/// and naming the content type would require a CORS preflight request.
+pub(crate) fn check_content_type(
+    req: &actix_web::HttpRequest,
+    expected: &str,
+) -> Result<(), ApiError> {
+    match content_type_essence(req) {
+        None => Ok(()),
+        Some(Ok(essence)) if essence.eq_ignore_ascii_case(expected) => Ok(()),
+        Some(_) => Err(ApiError::unsupported_content_type(expected)),
+    }
+}
 
-        impl actix_web::FromRequest for $name {
-            type Error = actix_web::Error;
-            type Future = std::pin::Pin<
-                Box<
-                    dyn std::future::Future<
-                        Output = Result<
-                        $name,
-                            actix_web::Error,
-                        >,
-                    >,
-                >,
-            >;
-
-            fn from_request(
-                req: &actix_web::HttpRequest,
-                payload: &mut actix_web::dev::Payload,
-            ) -> Self::Future {
-                use futures_util::StreamExt;
-
-                let payload = actix_web::web::Payload::from_request(req, payload);
-                Box::pin(async move {
-                    let payload = payload.await;
-                    match payload {
-                        Ok(mut p) => {
-                            let mut buf = heapless::Vec::<
-                                u8,
-                                { $name::SIZE },
-                                $name,
-                            >::new();
-                            while let Some(chunk) = p.next().await {
-                                let Ok(chunk) = chunk else {
-                                    return Err(actix_web::error::ErrorBadRequest(
-                                        "Error receiving the payload",
-                                    ));
-                                };
-                                buf.extend_from_slice(&chunk).map_err(|()| {
-                                    actix_web::error::ErrorInternalServerError("Args buffer overflow")
-                                })?;
-                            }
-                            let mut args: $name =
-                                unsafe { buf.transmute_buffer() };
-                            let Some(endianness) = args.endianness() else {
-                                return Err(actix_web::error::ErrorBadRequest("Invalid endianness"));
-                            };
-                            if endianness != crate::Endianness::NATIVE {
-                                args.reorder_bytes();
-                            };
-                            Ok(args)
-                        }
-                        Err(e) => return Err(e),
-                    }
-                })
-            }
-        }
+/// Checks the `Content-Length` of the request, if any, before reading the payload of `size` bytes
+/// with or without the [`Header`].
+pub(crate) fn check_content_length(
+    req: &actix_web::HttpRequest,
+    size: usize,
+) -> Result<(), ApiError> {
+    let Some(content_length) = req.headers().get(actix_web::http::header::CONTENT_LENGTH) else {
+        return Ok(());
     };
+    let Some(content_length) = content_length
+        .to_str()
+        .ok()
+        .and_then(|content_length| content_length.parse::<usize>().ok())
+    else {
+        return Err(ApiError::new(
+            actix_web::http::StatusCode::BAD_REQUEST,
+            ErrorCode::InvalidLength,
+            None,
+            "Invalid Content-Length",
+        ));
+    };
+    if content_length > size + HEADER_SIZE {
+        Err(ApiError::payload_too_large(size + HEADER_SIZE))
+    } else if content_length != size && content_length != size + HEADER_SIZE {
+        Err(ApiError::invalid_length(size, content_length))
+    } else {
+        Ok(())
+    }
 }
 
-macro_rules! decl_resp_message {
-    (@py_struct_format_string_char (u8)) => { 'B' };
-    (@py_struct_format_string_char (f64)) => { 'd' };
-    (@to_py_struct_format_string_raw_loop($buf:ident, $cur_size:ident, $($ty:tt),+)) => {
-        $(
-            let size: usize = std::mem::size_of::<$ty>();
-            let alignment: usize = std::mem::align_of::<$ty>();
-            let padding = (alignment - ($cur_size % alignment)) % alignment;
-            for _ in 0..padding {
-                $buf.push('x');
-            }
-            let ch = crate::proto::decl_resp_message!(@py_struct_format_string_char($ty));
-            $buf.push(ch);
-            // using += instead of the fully qualified syntax rendered the line an expression rather than a statement
-            #[allow(unused_assignments)]
-            <usize as core::ops::AddAssign>::add_assign(&mut $cur_size, padding + size);
-        )+
-    };
-    (
-        #[content_type = $content_type:tt]
-        #[parcel = $parcel:ident]
-        message $name:ident {
-            $(
-                $(#[$attr:meta])*
-                $vis:vis $field:ident : $ty:ident
-            ),+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use actix_web::http::StatusCode;
+    use mat_props_proto::{Endianness, Message, MAGIC};
+    use proptest::prelude::*;
+
+    /// Runs the extractor of the message on the payload, with the headers if they are given.
+    fn extract<M: actix_web::FromRequest<Error = actix_web::Error>>(
+        content_type: Option<&str>,
+        content_length: Option<usize>,
+        payload: Vec<u8>,
+    ) -> Result<M, StatusCode> {
+        let mut req = actix_web::test::TestRequest::post().set_payload(payload);
+        if let Some(content_type) = content_type {
+            req = req.insert_header((actix_web::http::header::CONTENT_TYPE, content_type));
         }
-    ) => {
-        #[derive(Clone, Copy, utoipa::ToSchema, bytemuck::Pod, bytemuck::Zeroable)]
-        #[repr(C)]
-        #[schema(example = $name::example_as_serde_big_array)]
-        pub(crate) struct $name {
-            $(
-                $(#[$attr])*
-                $vis $field: $ty
-            ),+
+        if let Some(content_length) = content_length {
+            req = req.insert_header((actix_web::http::header::CONTENT_LENGTH, content_length));
         }
+        let (req, mut payload) = req.to_http_parts();
+        actix_web::rt::System::new()
+            .block_on(M::from_request(&req, &mut payload))
+            .map_err(|e| e.as_response_error().status_code())
+    }
 
-        impl $name {
-            pub(crate) const SIZE: usize = core::mem::size_of::<Self>();
-            #[cfg(test)]
-            pub(crate) const FIELD_COUNT: usize = {
-                let mut count = 0;
-                $(
-                    stringify!($field);
-                    count += 1;
-                )+
-                count
-            };
-            #[cfg(test)]
-            pub(crate) const FIELDS: [&'static str; Self::FIELD_COUNT] = [
-                $(
-                    stringify!($field),
-                )+
-            ];
-
-            pub(crate) const fn content_type() -> &'static str {
-                $content_type
-            }
-
-            pub(crate) fn py_struct_format_string() -> String {
-                let mut buf = String::new();
-                let mut cur_size = 0;
-
-                crate::proto::decl_resp_message!(@to_py_struct_format_string_raw_loop(buf, cur_size, $($ty),+));
-                buf
-            }
-
-            pub(crate) const fn example_as_array() -> [u8; Self::SIZE] {
-                unsafe { core::mem::transmute(Self::example()) }
-            }
-
-            pub(crate) fn example_as_serde_big_array() -> serde_big_array::Array<u8, { Self::SIZE }> {
-                serde_big_array::Array(Self::example_as_array())
-            }
-
-            #[cfg(test)]
-            pub(crate) fn js_send_req() -> String {
-                use std::fmt::Write;
-
-                let mut s = String::new();
-                s.push_str("const req = new XMLHttpRequest();\n");
-                s.push_str("req.open('POST', url);\n");
-                s.push_str("req.responseType = 'arraybuffer';\n");
-                s.push_str("req.onload = (_event) => {\n");
-                s.push_str("\t//const status_code = req.status;\n");
-                s.push_str("\t//const console.log(`status_code = ${status_code}`);\n");
-                s.push_str("\tconst array_buffer = req.response;\n");
-                s.push_str("\tif (array_buffer) {\n");
-                // FIXME: assumes that all the returned fields are f64
-                s.push_str("\t\tconst resp_view = new Float64Array(array_buffer);\n");
-                for (i, field) in Self::FIELDS.iter().enumerate() {
-                    write!(
+    /// The JavaScript that fills the `ArrayBuffer` of the args message field by field.
+    fn js_build_args_buffer<M: Message>() -> String {
+        use std::fmt::Write;
+        let mut s = String::new();
+        writeln!(&mut s, "const args_buffer = new ArrayBuffer({});", M::SIZE).unwrap();
+        writeln!(&mut s, "const args = new DataView(args_buffer);").unwrap();
+        let mut offset = 0;
+        let mut field_idx = 0;
+        for ch in M::py_struct_format_string().chars() {
+            match ch {
+                'B' => {
+                    writeln!(
                         &mut s,
-                        "\t\tconst {field} = resp_view[{i}];\n"
-                    ).unwrap();
+                        "args.setUint8({}, {});",
+                        offset,
+                        M::FIELDS[field_idx]
+                    )
+                    .unwrap();
+                    offset += 1;
+                    field_idx += 1;
                 }
-                s.push_str("\t};\n");
-                s.push_str("};\n");
-                s
-            }
+                'x' => {
+                    writeln!(&mut s, "args.setUint8({}, 0); // padding", offset).unwrap();
+                    offset += 1;
+                }
+                'd' => {
+                    writeln!(
+                        &mut s,
+                        "args.setFloat64({}, {}, native_endianness);",
+                        offset,
+                        M::FIELDS[field_idx]
+                    )
+                    .unwrap();
+                    offset += 8;
+                    field_idx += 1;
+                }
+                _ => panic!("Unsupported character in py_struct_format_string: {}", ch),
+            };
         }
+        s
+    }
 
-        pub(crate) struct $parcel {
-            pub(crate) endianness: crate::Endianness,
-            pub(crate) already_sent: bool,
-            pub(crate) message: $name,
+    /// The JavaScript that sends the request and reads the fields of the response message.
+    fn js_send_req<M: Message>() -> String {
+        use std::fmt::Write;
+
+        let mut s = String::new();
+        s.push_str("const req = new XMLHttpRequest();\n");
+        s.push_str("req.open('POST', url);\n");
+        s.push_str("req.responseType = 'arraybuffer';\n");
+        s.push_str("req.onload = (_event) => {\n");
+        s.push_str("\t//const status_code = req.status;\n");
+        s.push_str("\t//const console.log(`status_code = ${status_code}`);\n");
+        s.push_str("\tconst array_buffer = req.response;\n");
+        s.push_str("\tif (array_buffer) {\n");
+        // FIXME: assumes that all the returned fields are f64
+        s.push_str("\t\tconst resp_view = new Float64Array(array_buffer);\n");
+        for (i, field) in M::FIELDS.iter().enumerate() {
+            writeln!(&mut s, "\t\tconst {field} = resp_view[{i}];").unwrap();
         }
+        s.push_str("\t};\n");
+        s.push_str("};\n");
+        s
+    }
 
-        impl $parcel {
-            pub(crate) fn new(
-                endianness: crate::Endianness,
-                message: $name,
-            ) -> Self {
-                Self {
-                    endianness,
-                    already_sent: false,
-                    message,
+    macro_rules! fuzz_args_message {
+        ($mod_name:ident, $name:ident) => {
+            mod $mod_name {
+                use super::*;
+
+                fn payload() -> impl Strategy<Value = Vec<u8>> {
+                    prop_oneof![
+                        proptest::collection::vec(any::<u8>(), 0..2 * $name::SIZE),
+                        proptest::collection::vec(any::<u8>(), $name::SIZE),
+                    ]
                 }
-            }
-        }
 
-        impl actix_web::body::MessageBody for $parcel {
-            type Error = actix_web::Error;
+                proptest! {
+                    #[test]
+                    fn arbitrary_payload(payload in payload(), declares_length: bool) {
+                        let content_length = declares_length.then_some(payload.len());
+                        // the payload that starts like the header is rejected with its header
+                        let has_header = payload.first() == Some(&MAGIC[0]);
+                        let len = payload.len();
+                        let res = extract::<ArgsMessage<$name>>(Some($name::CONTENT_TYPE), content_length, payload.clone());
+                        if len == $name::SIZE && payload[0] <= 1 {
+                            prop_assert!(res.is_ok());
+                        } else if declares_length && len > $name::SIZE + HEADER_SIZE
+                            || !declares_length && !has_header && len > $name::SIZE
+                        {
+                            prop_assert_eq!(res.map(|_| ()), Err(StatusCode::PAYLOAD_TOO_LARGE));
+                        } else {
+                            let status = res.map(|_| ()).unwrap_err();
+                            prop_assert!(
+                                status == StatusCode::BAD_REQUEST || status == StatusCode::PAYLOAD_TOO_LARGE,
+                                "{}", status
+                            );
+                        }
+                    }
+
+                    #[test]
+                    fn content_length_is_checked_before_reading(content_length in 0..4 * $name::SIZE) {
+                        let payload = $name::example_as_bytes();
+                        let expected = if content_length > $name::SIZE + HEADER_SIZE {
+                            Err(StatusCode::PAYLOAD_TOO_LARGE)
+                        } else if content_length != $name::SIZE && content_length != $name::SIZE + HEADER_SIZE {
+                            Err(StatusCode::BAD_REQUEST)
+                        } else {
+                            Ok(())
+                        };
+                        let res = extract::<ArgsMessage<$name>>(None, Some(content_length), payload);
+                        prop_assert_eq!(res.map(|_| ()), expected);
+                    }
 
-            fn size(&self) -> actix_web::body::BodySize {
-                actix_web::body::BodySize::Sized(core::mem::size_of::<
-                    $name,
-                >() as u64)
-            }
+                    #[test]
+                    fn content_type_must_match(content_type in "[a-z0-9.+/-]{0,80}") {
+                        prop_assume!(content_type != $name::CONTENT_TYPE);
+                        let payload = $name::example_as_bytes();
+                        let res = extract::<ArgsMessage<$name>>(Some(&content_type), None, payload);
+                        prop_assert_eq!(res.map(|_| ()), Err(StatusCode::UNSUPPORTED_MEDIA_TYPE));
+                    }
+                }
 
-            fn poll_next(
-                self: std::pin::Pin<&mut Self>,
-                _: &mut std::task::Context<'_>,
-            ) -> std::task::Poll<Option<Result<actix_web::web::Bytes, actix_web::Error>>> {
-                let Self {
-                    ref endianness,
-                    ref mut already_sent,
-                    ref message,
-                } = std::pin::Pin::into_inner(self);
-
-                if *already_sent {
-                    return std::task::Poll::Ready(None);
+                #[test]
+                fn example_is_extracted() {
+                    let payload = $name::example_as_bytes();
+                    let content_type = format!("{}; charset=binary", $name::CONTENT_TYPE);
+                    let ArgsMessage { endianness, args } =
+                        extract::<ArgsMessage<$name>>(Some(&content_type), Some($name::SIZE), payload).unwrap();
+                    assert_eq!(endianness, Endianness::Little);
+                    assert_eq!(args, $name::example());
+                    assert!(extract::<ArgsMessage<$name>>(None, None, $name::example_as_bytes()).is_ok());
                 }
 
-                let iter = bytemuck::bytes_of(message);
-                let bytes = if *endianness == crate::Endianness::NATIVE {
-                    actix_web::web::Bytes::from_iter(iter.iter().copied())
-                } else {
-                    let iter = iter
-                        .chunks_exact(core::mem::size_of::<f64>())
-                        .flat_map(|chunk| {
-                            let chunk = <&[u8; core::mem::size_of::<f64>()]>::try_from(chunk).unwrap();
-                            chunk.iter().copied().rev()
-                        });
-                    actix_web::web::Bytes::from_iter(iter)
-                };
-                *already_sent = true;
-                std::task::Poll::Ready(Some(Ok(bytes)))
+                #[test]
+                fn example_with_header_is_extracted() {
+                    for endianness in [Endianness::Little, Endianness::Big] {
+                        let payload = $name::example().encode_with_header(endianness);
+                        let (req, mut payload) = actix_web::test::TestRequest::post()
+                            .set_payload(payload)
+                            .to_http_parts();
+                        let args = actix_web::rt::System::new()
+                            .block_on(<ArgsMessage<$name> as actix_web::FromRequest>::from_request(&req, &mut payload))
+                            .unwrap();
+                        assert_eq!(protocol_version(&req), ProtocolVersion::V2);
+                        assert_eq!(args.endianness, endianness);
+                        assert_eq!(args.args.encode(Endianness::Little), $name::example_as_bytes());
+                    }
+                }
+
+                #[test]
+                fn encoded_example_is_extracted() {
+                    for encoding in Encoding::ALL {
+                        let payload = encoding.encode(&$name::example());
+                        let args = extract::<ArgsMessage<$name>>(Some(encoding.content_type()), None, payload).unwrap();
+                        assert_eq!(args.endianness, Endianness::NATIVE, "{encoding:?}");
+                        assert_eq!(args.args, $name::example(), "{encoding:?}");
+                    }
+                }
             }
-        }
+        };
     }
-}
 
-macro_rules! decl_req_resp_message_pair {
-    (
-        test $test_name:ident;
-        fn $fn_name:path;
-
-        #[content_type = $req_content_type:tt]
-        message(req) $req_name:ident {
-            $(
-                $(#[$req_attr:meta])*
-                $req_vis:vis $req_field:ident : $req_ty:ident
-            ),+ $(,)?
-        }
+    macro_rules! assert_response_round_trip {
+        ($name:ident) => {
+            for encoding in Encoding::ALL {
+                let bytes = encoding.encode(&$name::response_example());
+                let decoded: <$name as mat_props_proto::Computation>::Response =
+                    encoding.decode(&bytes).unwrap();
+                assert_eq!(decoded, $name::response_example(), "{encoding:?}");
+            }
+        };
+    }
 
-        #[content_type = $resp_content_type:tt]
-        #[parcel = $resp_parcel:ident]
-        message(resp) $resp_name:ident {
-            $(
-                $(#[$resp_attr:meta])*
-                $resp_vis:vis $resp_field:ident : $resp_ty:ident
-            ),+ $(,)?
-        }
+    #[test]
+    fn encoded_response_messages_round_trip() {
+        assert_response_round_trip!(ElasticModulesForUnidirectionalCompositeArgs);
+        assert_response_round_trip!(ElasticModulesForHoneycombArgs);
+        assert_response_round_trip!(ThermalConductivityForUnidirectionalCompositeArgs);
+        assert_response_round_trip!(ThermalExpansionForUnidirectionalCompositeArgs);
+        assert_response_round_trip!(ThermalExpansionForHoneycombArgs);
+    }
 
-        impl $req_name_dup:ident {
-            pub(crate) const fn example() -> Self $req_example_block:block
-        }
+    /// The message with the header of the given version and message id.
+    fn with_header(version: u8, message_id: u16, message: &[u8]) -> Vec<u8> {
+        let mut header = Header::new(Endianness::Little, message_id, message.len());
+        header.version = version;
+        [&header.to_bytes()[..], message].concat()
+    }
 
-        impl $resp_name_dup:ident {
-            pub(crate) const fn example() -> Self $resp_example_block:block
+    #[test]
+    fn mismatched_header_is_rejected() {
+        type Args = ElasticModulesForUnidirectionalCompositeArgs;
+        let honeycomb = ElasticModulesForHoneycombArgs::example_as_bytes();
+        let err = extract::<ArgsMessage<Args>>(
+            None,
+            None,
+            with_header(2, ElasticModulesForHoneycombArgs::MESSAGE_ID, &honeycomb),
+        );
+        assert_eq!(err.map(|_| ()), Err(StatusCode::BAD_REQUEST));
+        let mut reader = HeaderReader::default();
+        reader.feed(&with_header(2, 2, &honeycomb));
+        let err = reader.version(Args::MESSAGE_ID, Args::SIZE).unwrap_err();
+        assert_eq!(err.message.code, ErrorCode::MessageIdMismatch);
+        assert_eq!(err.message.field.as_deref(), Some("header.message_id"));
+
+        let example = Args::example_as_bytes();
+        for (version, message_id) in [(3, Args::MESSAGE_ID), (2, 0x8001)] {
+            assert_eq!(
+                extract::<ArgsMessage<Args>>(
+                    None,
+                    None,
+                    with_header(version, message_id, &example)
+                )
+                .map(|_| ()),
+                Err(StatusCode::BAD_REQUEST)
+            );
         }
-    ) => {
-        crate::proto::decl_req_message!(
-            #[content_type = $req_content_type]
-            message $req_name {
-                $(
-                    $(#[$req_attr])*
-                    $req_vis $req_field : $req_ty
-                ),+
-            }
+        assert!(extract::<ArgsMessage<Args>>(
+            None,
+            None,
+            with_header(2, Args::MESSAGE_ID, &example)
+        )
+        .is_ok());
+        let mut other_endianness = with_header(2, Args::MESSAGE_ID, &example);
+        other_endianness[HEADER_SIZE] ^= 1;
+        assert_eq!(
+            extract::<ArgsMessage<Args>>(None, None, other_endianness).map(|_| ()),
+            Err(StatusCode::BAD_REQUEST)
         );
+    }
 
-        crate::proto::decl_resp_message!(
-            #[content_type = $resp_content_type]
-            #[parcel = $resp_parcel]
-            message $resp_name {
-                $(
-                    $(#[$resp_attr])*
-                    $resp_vis $resp_field : $resp_ty
-                ),+
-            }
+    #[test]
+    fn msgpack_args_message_is_a_map_of_the_fields() {
+        let bytes = Encoding::MessagePack
+            .encode(&ThermalConductivityForUnidirectionalCompositeArgs::example());
+        let value: serde_json::Value = rmp_serde::from_slice(&bytes).unwrap();
+        assert_eq!(value["fibre_content"], 0.2);
+        assert_eq!(
+            extract::<ArgsMessage<ThermalConductivityForUnidirectionalCompositeArgs>>(
+                Some("application/x-msgpack"),
+                None,
+                bytes[..bytes.len() - 1].to_vec(),
+            )
+            .map(|_| ()),
+            Err(StatusCode::BAD_REQUEST)
         );
+    }
 
-        impl $req_name_dup {
-            pub(crate) const fn example() -> Self $req_example_block
-        }
-
-        impl $resp_name_dup {
-            pub(crate) const fn example() -> Self $resp_example_block
-        }
-
-        #[cfg(test)]
-        #[test]
-        fn $test_name() {
-            let $req_name {
-                endianness: _,
-                $(
-                    $req_field
-                ),+
-            } = $req_name::example();
-            let res = $fn_name(
-                $(
-                    $req_field
-                ),+
-            ).unwrap();
-            let $resp_name {
-                $(
-                    $resp_field
-                ),+
-            } = $resp_name::example();
-            let mut i = 0;
-            $(
-                assert_eq!(res[i], $resp_field);
-                #[allow(unused_assignments)]
-                <usize as core::ops::AddAssign>::add_assign(&mut i, 1);
-            )+
-        }
-    };
-}
+    #[test]
+    fn json_args_message_with_default_unit_systems() {
+        let json = r#"{"number_of_model": 2, "fibre_content": 0.2, "k_for_fiber": 100.0, "k_for_matrix": 1.0}"#;
+        let ArgsMessage { args, .. } = extract::<
+            ArgsMessage<ThermalConductivityForUnidirectionalCompositeArgs>,
+        >(
+            Some("application/json; charset=utf-8"), None, json.into()
+        )
+        .unwrap();
+        assert_eq!(args.unit_system, 0);
+        assert_eq!(
+            args.try_compute().unwrap().k1,
+            ThermalConductivityForUnidirectionalCompositeArgs::response_example().k1
+        );
 
-pub(crate) use decl_req_message;
-pub(crate) use decl_req_resp_message_pair;
-pub(crate) use decl_resp_message;
+        let misspelled = json.replace("fibre_content", "fiber_content");
+        assert_eq!(
+            extract::<ArgsMessage<ThermalConductivityForUnidirectionalCompositeArgs>>(
+                Some(Encoding::Json.content_type()),
+                None,
+                misspelled.into(),
+            )
+            .map(|_| ()),
+            Err(StatusCode::BAD_REQUEST)
+        );
+    }
 
-#[cfg(test)]
-mod tests {
-    use super::*;
+    fuzz_args_message!(
+        fuzz_elastic_modules_for_unidirectional_composite,
+        ElasticModulesForUnidirectionalCompositeArgs
+    );
+    fuzz_args_message!(
+        fuzz_elastic_modules_for_honeycomb,
+        ElasticModulesForHoneycombArgs
+    );
+    fuzz_args_message!(
+        fuzz_thermal_conductivity_for_unidirectional_composite,
+        ThermalConductivityForUnidirectionalCompositeArgs
+    );
+    fuzz_args_message!(
+        fuzz_thermal_expansion_for_unidirectional_composite,
+        ThermalExpansionForUnidirectionalCompositeArgs
+    );
+    fuzz_args_message!(
+        fuzz_thermal_expansion_for_honeycomb,
+        ThermalExpansionForHoneycombArgs
+    );
 
     #[test]
     fn see_js_build_args_buffer_for_elastic_modules_for_unidirectional_composite_args_message() {
         println!(
             "{}",
-            ElasticModulesForUnidirectionalCompositeArgsMessage::js_build_args_buffer()
+            js_build_args_buffer::<ElasticModulesForUnidirectionalCompositeArgs>()
         );
     }
 
@@ -502,7 +442,7 @@ mod tests {
     fn see_js_send_req_for_elastic_modules_for_unidirectional_composite_response_message() {
         println!(
             "{}",
-            ElasticModulesForUnidirectionalCompositeResponseMessage::js_send_req()
+            js_send_req::<ElasticModulesForUnidirectionalCompositeResponse>()
         );
     }
 
@@ -510,23 +450,20 @@ mod tests {
     fn see_js_build_args_buffer_for_elastic_modules_for_honeycomb_args_message() {
         println!(
             "{}",
-            ElasticModulesForHoneycombArgsMessage::js_build_args_buffer()
+            js_build_args_buffer::<ElasticModulesForHoneycombArgs>()
         );
     }
 
     #[test]
     fn see_js_send_req_for_elastic_modules_for_honeycomb_response_message() {
-        println!(
-            "{}",
-            ElasticModulesForHoneycombResponseMessage::js_send_req()
-        );
+        println!("{}", js_send_req::<ElasticModulesForHoneycombResponse>());
     }
 
     #[test]
     fn see_js_build_args_buffer_for_thermal_expansion_for_unidirectional_composite_args_message() {
         println!(
             "{}",
-            ThermalExpansionForUnidirectionalCompositeArgsMessage::js_build_args_buffer()
+            js_build_args_buffer::<ThermalExpansionForUnidirectionalCompositeArgs>()
         );
     }
 
@@ -534,7 +471,7 @@ mod tests {
     fn see_js_send_req_for_thermal_expansion_for_unidirectional_composite_response_message() {
         println!(
             "{}",
-            ThermalExpansionForUnidirectionalCompositeResponseMessage::js_send_req()
+            js_send_req::<ThermalExpansionForUnidirectionalCompositeResponse>()
         );
     }
 
@@ -542,16 +479,13 @@ mod tests {
     fn see_js_build_args_buffer_for_thermal_expansion_for_honeycomb_args_message() {
         println!(
             "{}",
-            ThermalExpansionForHoneycombArgsMessage::js_build_args_buffer()
+            js_build_args_buffer::<ThermalExpansionForHoneycombArgs>()
         );
     }
 
     #[test]
     fn see_js_send_req_for_thermal_expansion_for_honeycomb_args_message() {
-        println!(
-            "{}",
-            ThermalExpansionForHoneycombResponseMessage::js_send_req()
-        );
+        println!("{}", js_send_req::<ThermalExpansionForHoneycombResponse>());
     }
 
     #[test]
@@ -559,7 +493,7 @@ mod tests {
     {
         println!(
             "{}",
-            ThermalConductivityForUnidirectionalCompositeArgsMessage::js_build_args_buffer()
+            js_build_args_buffer::<ThermalConductivityForUnidirectionalCompositeArgs>()
         );
     }
 
@@ -567,7 +501,7 @@ mod tests {
     fn see_js_send_req_for_thermal_conductivity_for_unidirectional_composite_response_message() {
         println!(
             "{}",
-            ThermalConductivityForUnidirectionalCompositeResponseMessage::js_send_req()
+            js_send_req::<ThermalConductivityForUnidirectionalCompositeResponse>()
         );
     }
 }
@@ -0,0 +1,240 @@
+use mat_props::{
+    Computation, ElasticModulesForHoneycomb, ElasticModulesForUnidirectionalComposite, SweepTable,
+    ThermalConductivityForUnidirectionalComposite, ThermalExpansionForHoneycomb,
+    ThermalExpansionForUnidirectionalComposite,
+};
+use mat_props_proto::Endianness;
+use serde::{Deserialize, Serialize};
+
+use crate::proto::{ApiError, ErrorCode};
+
+pub(crate) const SWEEP_RESPONSE_CONTENT_TYPE: &str = "application/x.sweep-response-message";
+/// The largest number of points of the grid of a single sweep.
+pub(crate) const MAX_SWEEP_POINTS: usize = 1 << 16;
+
+fn too_many_points() -> ApiError {
+    ApiError::new(
+        actix_web::http::StatusCode::PAYLOAD_TOO_LARGE,
+        ErrorCode::PayloadTooLarge,
+        Some("axes"),
+        format!("Too many points of the grid, the limit is {MAX_SWEEP_POINTS}"),
+    )
+}
+
+/// The values of a swept input.
+#[derive(Clone, Deserialize, utoipa::ToSchema)]
+#[serde(tag = "kind", rename_all = "snake_case")]
+pub(crate) enum GridArgs {
+    /// The evenly spaced values from `start` to `end` inclusive.
+    Linear {
+        start: f64,
+        end: f64,
+        number_of_points: usize,
+    },
+    /// The values from `start` to `end` inclusive evenly spaced on the logarithmic scale.
+    Log {
+        start: f64,
+        end: f64,
+        number_of_points: usize,
+    },
+    /// The given values.
+    Explicit { points: Vec<f64> },
+}
+
+impl From<GridArgs> for mat_props::Grid {
+    fn from(grid: GridArgs) -> Self {
+        match grid {
+            GridArgs::Linear {
+                start,
+                end,
+                number_of_points,
+            } => Self::Linear {
+                start,
+                end,
+                number_of_points,
+            },
+            GridArgs::Log {
+                start,
+                end,
+                number_of_points,
+            } => Self::Log {
+                start,
+                end,
+                number_of_points,
+            },
+            GridArgs::Explicit { points } => Self::Explicit(points),
+        }
+    }
+}
+
+#[derive(Clone, Deserialize, utoipa::ToSchema)]
+pub(crate) struct AxisArgs {
+    /// The name of the swept input, i.e. the name of the field of the args message of the function.
+    pub(crate) input: String,
+    pub(crate) grid: GridArgs,
+}
+
+#[derive(Clone, Deserialize, utoipa::ToSchema)]
+#[schema(example = json!({
+    "endianness": 0,
+    "number_of_model": 2,
+    "inputs": [0.0, 100.0, 0.3, 5.0, 0.2],
+    "axes": [
+        { "input": "fibre_content", "grid": { "kind": "linear", "start": 0.0, "end": 0.8, "number_of_points": 5 } }
+    ]
+}))]
+pub(crate) struct SweepArgsMessage {
+    /// 0: little endian, 1: big endian. The endianness of the binary response.
+    #[schema(minimum = 0, maximum = 1)]
+    pub(crate) endianness: u8,
+    pub(crate) number_of_model: u8,
+    /// The inputs of the function in the order of the fields of its args message
+    /// after `number_of_model`. The values of the swept inputs are ignored.
+    pub(crate) inputs: Vec<f64>,
+    /// The swept inputs. The last one varies the fastest.
+    pub(crate) axes: Vec<AxisArgs>,
+}
+
+#[derive(Serialize, utoipa::ToSchema)]
+pub(crate) struct SweepResponseMessage {
+    /// The values of the swept inputs for each point of the grid in the order of the axes.
+    pub(crate) inputs: Vec<Vec<f64>>,
+    /// The outputs for each point of the grid in the order of the fields of the response message of the function.
+    pub(crate) outputs: Vec<Vec<f64>>,
+}
+
+impl From<SweepTable> for SweepResponseMessage {
+    fn from(table: SweepTable) -> Self {
+        let SweepTable { inputs, outputs } = table;
+        Self { inputs, outputs }
+    }
+}
+
+pub(crate) fn compute_sweep(
+    function: &str,
+    args: SweepArgsMessage,
+) -> Result<SweepTable, ApiError> {
+    match function {
+        "elastic_modules_for_unidirectional_composite" => {
+            sweep_computation::<ElasticModulesForUnidirectionalComposite>(args)
+        }
+        "elastic_modules_for_honeycomb" => sweep_computation::<ElasticModulesForHoneycomb>(args),
+        "thermal_conductivity_for_unidirectional_composite" => {
+            sweep_computation::<ThermalConductivityForUnidirectionalComposite>(args)
+        }
+        "thermal_expansion_for_unidirectional_composite" => {
+            sweep_computation::<ThermalExpansionForUnidirectionalComposite>(args)
+        }
+        "thermal_expansion_for_honeycomb" => {
+            sweep_computation::<ThermalExpansionForHoneycomb>(args)
+        }
+        _ => Err(mat_props::Error::UnknownFunction(function.to_owned()).into()),
+    }
+}
+
+fn sweep_computation<C: Computation>(args: SweepArgsMessage) -> Result<SweepTable, ApiError> {
+    let axes = args
+        .axes
+        .into_iter()
+        .map(|axis| {
+            // the unused fields of the args messages, e.g. `_wall_thickness`, are named without
+            // the leading underscore in the library
+            let name = axis.input.trim_start_matches('_');
+            let Some(input) = C::INPUTS.iter().copied().find(|&input| input == name) else {
+                return Err(ApiError::new(
+                    actix_web::http::StatusCode::BAD_REQUEST,
+                    ErrorCode::UnknownInput,
+                    Some("axes"),
+                    format!("Unknown input `{}`", axis.input),
+                ));
+            };
+            Ok(mat_props::Axis {
+                input: input.into(),
+                grid: axis.grid.into(),
+            })
+        })
+        .collect::<Result<Vec<_>, ApiError>>()?;
+    // the library allocates the whole grid up front
+    let number_of_points = axes.iter().try_fold(1usize, |points, axis| {
+        points.checked_mul(axis.grid.number_of_points())
+    });
+    if number_of_points.is_none_or(|points| points > MAX_SWEEP_POINTS) {
+        return Err(too_many_points());
+    }
+    Ok(mat_props::sweep::<C>(
+        args.number_of_model,
+        &args.inputs,
+        &axes,
+    )?)
+}
+
+/// Lays out the outputs as the consecutive response messages of the function.
+pub(crate) fn sweep_outputs_as_bytes(table: &SweepTable, endianness: Endianness) -> Vec<u8> {
+    table
+        .outputs
+        .iter()
+        .flatten()
+        .flat_map(|output| match endianness {
+            Endianness::Little => output.to_le_bytes(),
+            Endianness::Big => output.to_be_bytes(),
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::proto::Computation as _;
+    use mat_props_proto::Message as _;
+
+    #[test]
+    fn sweep_outputs_have_the_layout_of_the_response_messages() {
+        let args: SweepArgsMessage = serde_json::from_str(
+            r#"{
+                "endianness": 0,
+                "number_of_model": 2,
+                "inputs": [0.2, 100.0, 1.0],
+                "axes": [
+                    { "input": "fibre_content", "grid": { "kind": "explicit", "points": [0.1, 0.2] } }
+                ]
+            }"#,
+        )
+        .unwrap();
+        let table =
+            compute_sweep("thermal_conductivity_for_unidirectional_composite", args).unwrap();
+        let bytes = sweep_outputs_as_bytes(&table, Endianness::Little);
+        let size = crate::proto::ThermalConductivityForUnidirectionalCompositeResponse::SIZE;
+        assert_eq!(bytes.len(), 2 * size);
+        let example =
+            crate::proto::ThermalConductivityForUnidirectionalCompositeArgs::response_example();
+        assert_eq!(bytes[size..size + 8], example.k1.to_le_bytes());
+    }
+
+    #[test]
+    fn number_of_points_is_limited() {
+        let args = |number_of_points: usize| -> SweepArgsMessage {
+            serde_json::from_value(serde_json::json!({
+                "endianness": 0,
+                "number_of_model": 2,
+                "inputs": [0.2, 100.0, 1.0],
+                "axes": [
+                    { "input": "fibre_content", "grid": { "kind": "linear", "start": 0.1, "end": 0.2, "number_of_points": number_of_points } },
+                    { "input": "k_for_fiber", "grid": { "kind": "linear", "start": 10.0, "end": 100.0, "number_of_points": number_of_points } }
+                ]
+            }))
+            .unwrap()
+        };
+        let function = "thermal_conductivity_for_unidirectional_composite";
+        assert_eq!(
+            compute_sweep(function, args(256)).unwrap().outputs.len(),
+            MAX_SWEEP_POINTS
+        );
+        for number_of_points in [257, 1_000_000_000_000, usize::MAX] {
+            let e = compute_sweep(function, args(number_of_points)).unwrap_err();
+            assert_eq!(
+                actix_web::ResponseError::status_code(&e),
+                actix_web::http::StatusCode::PAYLOAD_TOO_LARGE
+            );
+        }
+    }
+}
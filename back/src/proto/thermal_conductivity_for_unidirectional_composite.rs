@@ -1,45 +1,60 @@
-use super::decl_req_resp_message_pair;
+use mat_props_proto::messages::{
+    ThermalConductivityForUnidirectionalCompositeArgs,
+    ThermalConductivityForUnidirectionalCompositeResponse,
+};
 
-decl_req_resp_message_pair!(
-    test example_data_is_consistent_for_thermal_conductivity_for_unidirectional_composite;
-    fn mat_props::thermal_conductivity_for_unidirectional_composite;
+use crate::proto::Computation;
 
-    #[content_type = "application/x.thermal-conductivity-for-unidirectional-composite-args-message"]
-    message(req) ThermalConductivityForUnidirectionalCompositeArgsMessage {
-        #[schema(minimum = 1, maximum = 2)]
-        pub(crate) number_of_model: u8,
-        pub(crate) fibre_content: f64,
-        pub(crate) k_for_fiber: f64,
-        pub(crate) k_for_matrix: f64,
-    }
-
-    #[content_type = "application/x.thermal-conductivity-for-unidirectional-composite-response-message"]
-    #[parcel =  ThermalConductivityForUnidirectionalCompositeResponseParcel]
-    message(resp) ThermalConductivityForUnidirectionalCompositeResponseMessage {
-        pub(crate) k1: f64,
-        pub(crate) k2: f64,
-        pub(crate) k3: f64,
+impl Computation for ThermalConductivityForUnidirectionalCompositeArgs {
+    fn example() -> Self {
+        Self {
+            endianness: 0,
+            number_of_model: 2,
+            unit_system: 0,
+            output_unit_system: 0,
+            fibre_content: 0.2,
+            k_for_fiber: 100.0,
+            k_for_matrix: 1.0,
+        }
     }
 
-    impl ThermalConductivityForUnidirectionalCompositeArgsMessage {
-        pub(crate) const fn example() -> Self {
-            Self {
-                endianness: 0,
-                number_of_model: 2,
-                fibre_content: 0.2,
-                k_for_fiber: 100.0,
-                k_for_matrix: 1.0,
-            }
+    fn response_example() -> ThermalConductivityForUnidirectionalCompositeResponse {
+        ThermalConductivityForUnidirectionalCompositeResponse {
+            k1: 20.8,
+            k2: 1.3300670235932428,
+            k3: 1.3300670235932428,
         }
     }
+}
 
-    impl ThermalConductivityForUnidirectionalCompositeResponseMessage {
-        pub(crate) const fn example() -> Self {
-            Self {
-                k1: 20.8,
-                k2: 1.3300670235932428,
-                k3: 1.3300670235932428,
-            }
-        }
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn example_data_is_consistent_for_thermal_conductivity_for_unidirectional_composite() {
+        let ThermalConductivityForUnidirectionalCompositeArgs {
+            number_of_model,
+            fibre_content,
+            k_for_fiber,
+            k_for_matrix,
+            ..
+        } = ThermalConductivityForUnidirectionalCompositeArgs::example();
+        let res = mat_props::thermal_conductivity_for_unidirectional_composite(
+            number_of_model,
+            fibre_content,
+            k_for_fiber,
+            k_for_matrix,
+        )
+        .unwrap();
+        let ThermalConductivityForUnidirectionalCompositeResponse { k1, k2, k3 } =
+            ThermalConductivityForUnidirectionalCompositeArgs::response_example();
+        assert_eq!(res, [k1, k2, k3]);
+        assert_eq!(
+            ThermalConductivityForUnidirectionalCompositeArgs::example()
+                .try_compute()
+                .unwrap(),
+            ThermalConductivityForUnidirectionalCompositeArgs::response_example()
+        );
     }
-);
+}
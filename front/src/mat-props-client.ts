@@ -0,0 +1,362 @@
+// Generated by `cargo run -- codegen` in `back` from the message declarations of `mat-props-proto/src/messages.rs`. Do not edit.
+
+export type Endianness = 0 | 1;
+
+// https://en.wikipedia.org/wiki/Endianness
+export const NATIVE_ENDIANNESS = new Uint8Array(new Uint16Array([0x0100]).buffer)[0] as Endianness;
+
+/** The error response of the backend with its application/problem+json body. */
+export class ProblemError extends Error {
+    status: number;
+    problem: string;
+
+    constructor(status: number, problem: string) {
+        super(`${status}: ${problem}`);
+        this.status = status;
+        this.problem = problem;
+    }
+}
+
+async function post(url: string, body: ArrayBuffer): Promise<ArrayBuffer> {
+    const resp = await fetch(url, { method: "POST", body });
+    if (!resp.ok) {
+        throw new ProblemError(resp.status, await resp.text());
+    }
+    return resp.arrayBuffer();
+}
+
+export interface ElasticModulesForUnidirectionalCompositeArgs {
+    numberOfModel: number;
+    unitSystem?: number;
+    outputUnitSystem?: number;
+    fibreContent: number;
+    eForFiber: number;
+    nuForFiber: number;
+    eForMatrix: number;
+    nuForMatrix: number;
+}
+
+export interface ElasticModulesForUnidirectionalCompositeResponse {
+    e1: number;
+    e2: number;
+    e3: number;
+    nu12: number;
+    nu13: number;
+    nu23: number;
+    g12: number;
+    g13: number;
+    g23: number;
+}
+
+export const ELASTIC_MODULES_FOR_UNIDIRECTIONAL_COMPOSITE_MESSAGES = {
+    path: "/compute/elastic_modules_for_unidirectional_composite",
+    argsContentType: "application/x.elastic-modules-for-unidirectional-composite-args-message",
+    argsMessageId: 1,
+    argsSize: 48,
+    responseContentType: "application/x.elastic-modules-for-unidirectional-composite-response-message",
+    responseMessageId: 32769,
+    responseSize: 72,
+} as const;
+
+export function encodeElasticModulesForUnidirectionalCompositeArgs(args: ElasticModulesForUnidirectionalCompositeArgs, endianness: Endianness = NATIVE_ENDIANNESS): ArrayBuffer {
+    // the padding stays zeroed
+    const buffer = new ArrayBuffer(48);
+    const view = new DataView(buffer);
+    const littleEndian = endianness === 0;
+    view.setUint8(0, endianness);
+    view.setUint8(1, args.numberOfModel);
+    view.setUint8(2, args.unitSystem ?? 0);
+    view.setUint8(3, args.outputUnitSystem ?? 0);
+    view.setFloat64(8, args.fibreContent, littleEndian);
+    view.setFloat64(16, args.eForFiber, littleEndian);
+    view.setFloat64(24, args.nuForFiber, littleEndian);
+    view.setFloat64(32, args.eForMatrix, littleEndian);
+    view.setFloat64(40, args.nuForMatrix, littleEndian);
+    return buffer;
+}
+
+export function decodeElasticModulesForUnidirectionalCompositeResponse(buffer: ArrayBuffer, endianness: Endianness = NATIVE_ENDIANNESS): ElasticModulesForUnidirectionalCompositeResponse {
+    if (buffer.byteLength !== 72) {
+        throw new Error(`Expected 72 bytes but got ${buffer.byteLength} bytes`);
+    }
+    const view = new DataView(buffer);
+    const littleEndian = endianness === 0;
+    return {
+        e1: view.getFloat64(0, littleEndian),
+        e2: view.getFloat64(8, littleEndian),
+        e3: view.getFloat64(16, littleEndian),
+        nu12: view.getFloat64(24, littleEndian),
+        nu13: view.getFloat64(32, littleEndian),
+        nu23: view.getFloat64(40, littleEndian),
+        g12: view.getFloat64(48, littleEndian),
+        g13: view.getFloat64(56, littleEndian),
+        g23: view.getFloat64(64, littleEndian),
+    };
+}
+
+export async function elasticModulesForUnidirectionalComposite(baseUrl: string, args: ElasticModulesForUnidirectionalCompositeArgs): Promise<ElasticModulesForUnidirectionalCompositeResponse> {
+    const body = await post(`${baseUrl}/compute/elastic_modules_for_unidirectional_composite`, encodeElasticModulesForUnidirectionalCompositeArgs(args));
+    return decodeElasticModulesForUnidirectionalCompositeResponse(body);
+}
+
+export interface ElasticModulesForHoneycombArgs {
+    numberOfModel: number;
+    unitSystem?: number;
+    outputUnitSystem?: number;
+    lCellSideSize: number;
+    hCellSideSize: number;
+    wallThickness: number;
+    angle: number;
+    eForHoneycomb: number;
+    nuForHoneycomb: number;
+}
+
+export interface ElasticModulesForHoneycombResponse {
+    e1: number;
+    e2: number;
+    e3: number;
+    nu12: number;
+    nu13: number;
+    nu23: number;
+    g12: number;
+    g13: number;
+    g23: number;
+}
+
+export const ELASTIC_MODULES_FOR_HONEYCOMB_MESSAGES = {
+    path: "/compute/elastic_modules_for_honeycomb",
+    argsContentType: "application/x.elastic-modules-for-honeycomb-args-message",
+    argsMessageId: 2,
+    argsSize: 56,
+    responseContentType: "application/x.elastic-modules-for-honeycomb-response-message",
+    responseMessageId: 32770,
+    responseSize: 72,
+} as const;
+
+export function encodeElasticModulesForHoneycombArgs(args: ElasticModulesForHoneycombArgs, endianness: Endianness = NATIVE_ENDIANNESS): ArrayBuffer {
+    // the padding stays zeroed
+    const buffer = new ArrayBuffer(56);
+    const view = new DataView(buffer);
+    const littleEndian = endianness === 0;
+    view.setUint8(0, endianness);
+    view.setUint8(1, args.numberOfModel);
+    view.setUint8(2, args.unitSystem ?? 0);
+    view.setUint8(3, args.outputUnitSystem ?? 0);
+    view.setFloat64(8, args.lCellSideSize, littleEndian);
+    view.setFloat64(16, args.hCellSideSize, littleEndian);
+    view.setFloat64(24, args.wallThickness, littleEndian);
+    view.setFloat64(32, args.angle, littleEndian);
+    view.setFloat64(40, args.eForHoneycomb, littleEndian);
+    view.setFloat64(48, args.nuForHoneycomb, littleEndian);
+    return buffer;
+}
+
+export function decodeElasticModulesForHoneycombResponse(buffer: ArrayBuffer, endianness: Endianness = NATIVE_ENDIANNESS): ElasticModulesForHoneycombResponse {
+    if (buffer.byteLength !== 72) {
+        throw new Error(`Expected 72 bytes but got ${buffer.byteLength} bytes`);
+    }
+    const view = new DataView(buffer);
+    const littleEndian = endianness === 0;
+    return {
+        e1: view.getFloat64(0, littleEndian),
+        e2: view.getFloat64(8, littleEndian),
+        e3: view.getFloat64(16, littleEndian),
+        nu12: view.getFloat64(24, littleEndian),
+        nu13: view.getFloat64(32, littleEndian),
+        nu23: view.getFloat64(40, littleEndian),
+        g12: view.getFloat64(48, littleEndian),
+        g13: view.getFloat64(56, littleEndian),
+        g23: view.getFloat64(64, littleEndian),
+    };
+}
+
+export async function elasticModulesForHoneycomb(baseUrl: string, args: ElasticModulesForHoneycombArgs): Promise<ElasticModulesForHoneycombResponse> {
+    const body = await post(`${baseUrl}/compute/elastic_modules_for_honeycomb`, encodeElasticModulesForHoneycombArgs(args));
+    return decodeElasticModulesForHoneycombResponse(body);
+}
+
+export interface ThermalConductivityForUnidirectionalCompositeArgs {
+    numberOfModel: number;
+    unitSystem?: number;
+    outputUnitSystem?: number;
+    fibreContent: number;
+    kForFiber: number;
+    kForMatrix: number;
+}
+
+export interface ThermalConductivityForUnidirectionalCompositeResponse {
+    k1: number;
+    k2: number;
+    k3: number;
+}
+
+export const THERMAL_CONDUCTIVITY_FOR_UNIDIRECTIONAL_COMPOSITE_MESSAGES = {
+    path: "/compute/thermal_conductivity_for_unidirectional_composite",
+    argsContentType: "application/x.thermal-conductivity-for-unidirectional-composite-args-message",
+    argsMessageId: 3,
+    argsSize: 32,
+    responseContentType: "application/x.thermal-conductivity-for-unidirectional-composite-response-message",
+    responseMessageId: 32771,
+    responseSize: 24,
+} as const;
+
+export function encodeThermalConductivityForUnidirectionalCompositeArgs(args: ThermalConductivityForUnidirectionalCompositeArgs, endianness: Endianness = NATIVE_ENDIANNESS): ArrayBuffer {
+    // the padding stays zeroed
+    const buffer = new ArrayBuffer(32);
+    const view = new DataView(buffer);
+    const littleEndian = endianness === 0;
+    view.setUint8(0, endianness);
+    view.setUint8(1, args.numberOfModel);
+    view.setUint8(2, args.unitSystem ?? 0);
+    view.setUint8(3, args.outputUnitSystem ?? 0);
+    view.setFloat64(8, args.fibreContent, littleEndian);
+    view.setFloat64(16, args.kForFiber, littleEndian);
+    view.setFloat64(24, args.kForMatrix, littleEndian);
+    return buffer;
+}
+
+export function decodeThermalConductivityForUnidirectionalCompositeResponse(buffer: ArrayBuffer, endianness: Endianness = NATIVE_ENDIANNESS): ThermalConductivityForUnidirectionalCompositeResponse {
+    if (buffer.byteLength !== 24) {
+        throw new Error(`Expected 24 bytes but got ${buffer.byteLength} bytes`);
+    }
+    const view = new DataView(buffer);
+    const littleEndian = endianness === 0;
+    return {
+        k1: view.getFloat64(0, littleEndian),
+        k2: view.getFloat64(8, littleEndian),
+        k3: view.getFloat64(16, littleEndian),
+    };
+}
+
+export async function thermalConductivityForUnidirectionalComposite(baseUrl: string, args: ThermalConductivityForUnidirectionalCompositeArgs): Promise<ThermalConductivityForUnidirectionalCompositeResponse> {
+    const body = await post(`${baseUrl}/compute/thermal_conductivity_for_unidirectional_composite`, encodeThermalConductivityForUnidirectionalCompositeArgs(args));
+    return decodeThermalConductivityForUnidirectionalCompositeResponse(body);
+}
+
+export interface ThermalExpansionForUnidirectionalCompositeArgs {
+    numberOfModel: number;
+    unitSystem?: number;
+    outputUnitSystem?: number;
+    fibreContent: number;
+    eForFiber: number;
+    nuForFiber: number;
+    alphaForFiber: number;
+    eForMatrix: number;
+    nuForMatrix: number;
+    alphaForMatrix: number;
+}
+
+export interface ThermalExpansionForUnidirectionalCompositeResponse {
+    alpha1: number;
+    alpha2: number;
+    alpha3: number;
+}
+
+export const THERMAL_EXPANSION_FOR_UNIDIRECTIONAL_COMPOSITE_MESSAGES = {
+    path: "/compute/thermal_expansion_for_unidirectional_composite",
+    argsContentType: "application/x.thermal-expansion-for-unidirectional-composite-args-message",
+    argsMessageId: 4,
+    argsSize: 64,
+    responseContentType: "application/x.thermal-expansion-for-unidirectional-composite-response-message",
+    responseMessageId: 32772,
+    responseSize: 24,
+} as const;
+
+export function encodeThermalExpansionForUnidirectionalCompositeArgs(args: ThermalExpansionForUnidirectionalCompositeArgs, endianness: Endianness = NATIVE_ENDIANNESS): ArrayBuffer {
+    // the padding stays zeroed
+    const buffer = new ArrayBuffer(64);
+    const view = new DataView(buffer);
+    const littleEndian = endianness === 0;
+    view.setUint8(0, endianness);
+    view.setUint8(1, args.numberOfModel);
+    view.setUint8(2, args.unitSystem ?? 0);
+    view.setUint8(3, args.outputUnitSystem ?? 0);
+    view.setFloat64(8, args.fibreContent, littleEndian);
+    view.setFloat64(16, args.eForFiber, littleEndian);
+    view.setFloat64(24, args.nuForFiber, littleEndian);
+    view.setFloat64(32, args.alphaForFiber, littleEndian);
+    view.setFloat64(40, args.eForMatrix, littleEndian);
+    view.setFloat64(48, args.nuForMatrix, littleEndian);
+    view.setFloat64(56, args.alphaForMatrix, littleEndian);
+    return buffer;
+}
+
+export function decodeThermalExpansionForUnidirectionalCompositeResponse(buffer: ArrayBuffer, endianness: Endianness = NATIVE_ENDIANNESS): ThermalExpansionForUnidirectionalCompositeResponse {
+    if (buffer.byteLength !== 24) {
+        throw new Error(`Expected 24 bytes but got ${buffer.byteLength} bytes`);
+    }
+    const view = new DataView(buffer);
+    const littleEndian = endianness === 0;
+    return {
+        alpha1: view.getFloat64(0, littleEndian),
+        alpha2: view.getFloat64(8, littleEndian),
+        alpha3: view.getFloat64(16, littleEndian),
+    };
+}
+
+export async function thermalExpansionForUnidirectionalComposite(baseUrl: string, args: ThermalExpansionForUnidirectionalCompositeArgs): Promise<ThermalExpansionForUnidirectionalCompositeResponse> {
+    const body = await post(`${baseUrl}/compute/thermal_expansion_for_unidirectional_composite`, encodeThermalExpansionForUnidirectionalCompositeArgs(args));
+    return decodeThermalExpansionForUnidirectionalCompositeResponse(body);
+}
+
+export interface ThermalExpansionForHoneycombArgs {
+    numberOfModel: number;
+    unitSystem?: number;
+    outputUnitSystem?: number;
+    lCellSideSize: number;
+    hCellSideSize: number;
+    wallThickness: number;
+    angle: number;
+    alphaForHoneycomb: number;
+}
+
+export interface ThermalExpansionForHoneycombResponse {
+    alpha1: number;
+    alpha2: number;
+    alpha3: number;
+}
+
+export const THERMAL_EXPANSION_FOR_HONEYCOMB_MESSAGES = {
+    path: "/compute/thermal_expansion_for_honeycomb",
+    argsContentType: "application/x.thermal-expansion-for-honeycomb-args-message",
+    argsMessageId: 5,
+    argsSize: 48,
+    responseContentType: "application/x.thermal-expansion-for-honeycomb-response-message",
+    responseMessageId: 32773,
+    responseSize: 24,
+} as const;
+
+export function encodeThermalExpansionForHoneycombArgs(args: ThermalExpansionForHoneycombArgs, endianness: Endianness = NATIVE_ENDIANNESS): ArrayBuffer {
+    // the padding stays zeroed
+    const buffer = new ArrayBuffer(48);
+    const view = new DataView(buffer);
+    const littleEndian = endianness === 0;
+    view.setUint8(0, endianness);
+    view.setUint8(1, args.numberOfModel);
+    view.setUint8(2, args.unitSystem ?? 0);
+    view.setUint8(3, args.outputUnitSystem ?? 0);
+    view.setFloat64(8, args.lCellSideSize, littleEndian);
+    view.setFloat64(16, args.hCellSideSize, littleEndian);
+    view.setFloat64(24, args.wallThickness, littleEndian);
+    view.setFloat64(32, args.angle, littleEndian);
+    view.setFloat64(40, args.alphaForHoneycomb, littleEndian);
+    return buffer;
+}
+
+export function decodeThermalExpansionForHoneycombResponse(buffer: ArrayBuffer, endianness: Endianness = NATIVE_ENDIANNESS): ThermalExpansionForHoneycombResponse {
+    if (buffer.byteLength !== 24) {
+        throw new Error(`Expected 24 bytes but got ${buffer.byteLength} bytes`);
+    }
+    const view = new DataView(buffer);
+    const littleEndian = endianness === 0;
+    return {
+        alpha1: view.getFloat64(0, littleEndian),
+        alpha2: view.getFloat64(8, littleEndian),
+        alpha3: view.getFloat64(16, littleEndian),
+    };
+}
+
+export async function thermalExpansionForHoneycomb(baseUrl: string, args: ThermalExpansionForHoneycombArgs): Promise<ThermalExpansionForHoneycombResponse> {
+    const body = await post(`${baseUrl}/compute/thermal_expansion_for_honeycomb`, encodeThermalExpansionForHoneycombArgs(args));
+    return decodeThermalExpansionForHoneycombResponse(body);
+}
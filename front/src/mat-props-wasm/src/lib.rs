@@ -0,0 +1,447 @@
+//! The models of `mat-props` for computing in the browser, e.g. when the backend is unreachable.
+//!
+//! The functions with `unit_system` and `output_unit_system` take the same arguments in the same
+//! order as the args messages of the backend and give the same results.
+
+use mat_props::{
+    evaluate_in_units, Computation, ElasticModulesForHoneycomb,
+    ElasticModulesForUnidirectionalComposite, ThermalConductivityForUnidirectionalComposite,
+    ThermalExpansionForHoneycomb, ThermalExpansionForUnidirectionalComposite,
+};
+use wasm_bindgen::prelude::*;
+
+/// [Elastic modules](https://en.wikipedia.org/wiki/Elastic_modulus) of an orthotropic material.
+#[wasm_bindgen]
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct ElasticModules {
+    pub e1: f64,
+    pub e2: f64,
+    pub e3: f64,
+    pub nu12: f64,
+    pub nu13: f64,
+    pub nu23: f64,
+    pub g12: f64,
+    pub g13: f64,
+    pub g23: f64,
+}
+
+/// [Thermal conductivity](https://en.wikipedia.org/wiki/Thermal_conductivity_and_resistivity)
+/// in the three primary directions.
+#[wasm_bindgen]
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct ThermalConductivity {
+    pub k1: f64,
+    pub k2: f64,
+    pub k3: f64,
+}
+
+/// [Coefficients of thermal expansion](https://en.wikipedia.org/wiki/Thermal_expansion)
+/// in the three primary directions.
+#[wasm_bindgen]
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct ThermalExpansion {
+    pub alpha1: f64,
+    pub alpha2: f64,
+    pub alpha3: f64,
+}
+
+/// [Thermal diffusivity](https://en.wikipedia.org/wiki/Thermal_diffusivity)
+/// in the three primary directions.
+#[wasm_bindgen]
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct ThermalDiffusivity {
+    pub a1: f64,
+    pub a2: f64,
+    pub a3: f64,
+}
+
+/// The elastic modules divided by the density.
+#[wasm_bindgen]
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct SpecificElasticModules {
+    pub e1: f64,
+    pub e2: f64,
+    pub e3: f64,
+    pub g12: f64,
+    pub g13: f64,
+    pub g23: f64,
+}
+
+/// Density and specific heat capacity, `c` is `NaN` if it can't be computed.
+#[wasm_bindgen]
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct DensityAndSpecificHeat {
+    /// The relative density, `NaN` for unidirectional composite.
+    pub rho_relative: f64,
+    pub rho: f64,
+    pub c: f64,
+}
+
+/// The strengths of unidirectional composite (all positive).
+#[wasm_bindgen]
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Strength {
+    pub xt: f64,
+    pub xc: f64,
+    pub yt: f64,
+    pub yc: f64,
+    pub s12: f64,
+}
+
+impl From<[f64; 9]> for ElasticModules {
+    fn from([e1, e2, e3, nu12, nu13, nu23, g12, g13, g23]: [f64; 9]) -> Self {
+        Self {
+            e1,
+            e2,
+            e3,
+            nu12,
+            nu13,
+            nu23,
+            g12,
+            g13,
+            g23,
+        }
+    }
+}
+
+impl From<[f64; 3]> for ThermalConductivity {
+    fn from([k1, k2, k3]: [f64; 3]) -> Self {
+        Self { k1, k2, k3 }
+    }
+}
+
+impl From<[f64; 3]> for ThermalExpansion {
+    fn from([alpha1, alpha2, alpha3]: [f64; 3]) -> Self {
+        Self {
+            alpha1,
+            alpha2,
+            alpha3,
+        }
+    }
+}
+
+impl From<[f64; 3]> for ThermalDiffusivity {
+    fn from([a1, a2, a3]: [f64; 3]) -> Self {
+        Self { a1, a2, a3 }
+    }
+}
+
+impl From<[f64; 6]> for SpecificElasticModules {
+    fn from([e1, e2, e3, g12, g13, g23]: [f64; 6]) -> Self {
+        Self {
+            e1,
+            e2,
+            e3,
+            g12,
+            g13,
+            g23,
+        }
+    }
+}
+
+impl From<[f64; 5]> for Strength {
+    fn from([xt, xc, yt, yc, s12]: [f64; 5]) -> Self {
+        Self {
+            xt,
+            xc,
+            yt,
+            yc,
+            s12,
+        }
+    }
+}
+
+// the same path as the backend takes for the args messages
+fn evaluate<C: Computation, const N: usize, R: From<[f64; N]>>(
+    number_of_model: u8,
+    unit_system: u8,
+    output_unit_system: u8,
+    inputs: &[f64],
+) -> Result<R, JsError> {
+    let outputs = evaluate_in_units::<C>(number_of_model, inputs, unit_system, output_unit_system)?;
+    let outputs: [f64; N] = outputs
+        .try_into()
+        .map_err(|_| JsError::new("unexpected number of outputs"))?;
+    Ok(outputs.into())
+}
+
+#[allow(clippy::too_many_arguments)]
+#[wasm_bindgen]
+pub fn elastic_modules_for_unidirectional_composite(
+    number_of_model: u8,
+    unit_system: u8,
+    output_unit_system: u8,
+    fibre_content: f64,
+    e_for_fiber: f64,
+    nu_for_fiber: f64,
+    e_for_matrix: f64,
+    nu_for_matrix: f64,
+) -> Result<ElasticModules, JsError> {
+    evaluate::<ElasticModulesForUnidirectionalComposite, 9, _>(
+        number_of_model,
+        unit_system,
+        output_unit_system,
+        &[
+            fibre_content,
+            e_for_fiber,
+            nu_for_fiber,
+            e_for_matrix,
+            nu_for_matrix,
+        ],
+    )
+}
+
+#[allow(clippy::too_many_arguments)]
+#[wasm_bindgen]
+pub fn elastic_modules_for_honeycomb(
+    number_of_model: u8,
+    unit_system: u8,
+    output_unit_system: u8,
+    l_cell_side_size: f64,
+    h_cell_side_size: f64,
+    wall_thickness: f64,
+    angle: f64,
+    e_for_honeycomb: f64,
+    nu_for_honeycomb: f64,
+) -> Result<ElasticModules, JsError> {
+    evaluate::<ElasticModulesForHoneycomb, 9, _>(
+        number_of_model,
+        unit_system,
+        output_unit_system,
+        &[
+            l_cell_side_size,
+            h_cell_side_size,
+            wall_thickness,
+            angle,
+            e_for_honeycomb,
+            nu_for_honeycomb,
+        ],
+    )
+}
+
+#[wasm_bindgen]
+pub fn thermal_conductivity_for_unidirectional_composite(
+    number_of_model: u8,
+    unit_system: u8,
+    output_unit_system: u8,
+    fibre_content: f64,
+    k_for_fiber: f64,
+    k_for_matrix: f64,
+) -> Result<ThermalConductivity, JsError> {
+    evaluate::<ThermalConductivityForUnidirectionalComposite, 3, _>(
+        number_of_model,
+        unit_system,
+        output_unit_system,
+        &[fibre_content, k_for_fiber, k_for_matrix],
+    )
+}
+
+#[allow(clippy::too_many_arguments)]
+#[wasm_bindgen]
+pub fn thermal_expansion_for_unidirectional_composite(
+    number_of_model: u8,
+    unit_system: u8,
+    output_unit_system: u8,
+    fibre_content: f64,
+    e_for_fiber: f64,
+    nu_for_fiber: f64,
+    alpha_for_fiber: f64,
+    e_for_matrix: f64,
+    nu_for_matrix: f64,
+    alpha_for_matrix: f64,
+) -> Result<ThermalExpansion, JsError> {
+    evaluate::<ThermalExpansionForUnidirectionalComposite, 3, _>(
+        number_of_model,
+        unit_system,
+        output_unit_system,
+        &[
+            fibre_content,
+            e_for_fiber,
+            nu_for_fiber,
+            alpha_for_fiber,
+            e_for_matrix,
+            nu_for_matrix,
+            alpha_for_matrix,
+        ],
+    )
+}
+
+#[allow(clippy::too_many_arguments)]
+#[wasm_bindgen]
+pub fn thermal_expansion_for_honeycomb(
+    number_of_model: u8,
+    unit_system: u8,
+    output_unit_system: u8,
+    l_cell_side_size: f64,
+    h_cell_side_size: f64,
+    wall_thickness: f64,
+    angle: f64,
+    alpha_for_honeycomb: f64,
+) -> Result<ThermalExpansion, JsError> {
+    evaluate::<ThermalExpansionForHoneycomb, 3, _>(
+        number_of_model,
+        unit_system,
+        output_unit_system,
+        &[
+            l_cell_side_size,
+            h_cell_side_size,
+            wall_thickness,
+            angle,
+            alpha_for_honeycomb,
+        ],
+    )
+}
+
+#[allow(clippy::too_many_arguments)]
+#[wasm_bindgen]
+pub fn thermal_diffusivity_for_unidirectional_composite(
+    number_of_model: u8,
+    fibre_content: f64,
+    k_for_fiber: f64,
+    k_for_matrix: f64,
+    rho_for_fiber: f64,
+    rho_for_matrix: f64,
+    c_for_fiber: f64,
+    c_for_matrix: f64,
+) -> Result<ThermalDiffusivity, JsError> {
+    Ok(mat_props::thermal_diffusivity_for_unidirectional_composite(
+        number_of_model,
+        fibre_content,
+        k_for_fiber,
+        k_for_matrix,
+        rho_for_fiber,
+        rho_for_matrix,
+        c_for_fiber,
+        c_for_matrix,
+    )?
+    .into())
+}
+
+#[allow(clippy::too_many_arguments)]
+#[wasm_bindgen]
+pub fn specific_elastic_modules_for_unidirectional_composite(
+    number_of_model: u8,
+    fibre_content: f64,
+    e_for_fiber: f64,
+    nu_for_fiber: f64,
+    e_for_matrix: f64,
+    nu_for_matrix: f64,
+    rho_for_fiber: f64,
+    rho_for_matrix: f64,
+) -> Result<SpecificElasticModules, JsError> {
+    Ok(
+        mat_props::specific_elastic_modules_for_unidirectional_composite(
+            number_of_model,
+            fibre_content,
+            e_for_fiber,
+            nu_for_fiber,
+            e_for_matrix,
+            nu_for_matrix,
+            rho_for_fiber,
+            rho_for_matrix,
+        )?
+        .into(),
+    )
+}
+
+#[allow(clippy::too_many_arguments)]
+#[wasm_bindgen]
+pub fn specific_elastic_modules_for_honeycomb(
+    number_of_model: u8,
+    l_cell_side_size: f64,
+    h_cell_side_size: f64,
+    wall_thickness: f64,
+    angle: f64,
+    e_for_honeycomb: f64,
+    nu_for_honeycomb: f64,
+    rho_for_honeycomb: f64,
+) -> Result<SpecificElasticModules, JsError> {
+    Ok(mat_props::specific_elastic_modules_for_honeycomb(
+        number_of_model,
+        l_cell_side_size,
+        h_cell_side_size,
+        wall_thickness,
+        angle,
+        e_for_honeycomb,
+        nu_for_honeycomb,
+        rho_for_honeycomb,
+    )?
+    .into())
+}
+
+#[wasm_bindgen]
+pub fn density_and_specific_heat_for_unidirectional_composite(
+    fibre_content: f64,
+    rho_for_fiber: f64,
+    rho_for_matrix: f64,
+    c_for_fiber: Option<f64>,
+    c_for_matrix: Option<f64>,
+) -> Result<DensityAndSpecificHeat, JsError> {
+    let [rho, c] = mat_props::density_and_specific_heat_for_unidirectional_composite(
+        fibre_content,
+        rho_for_fiber,
+        rho_for_matrix,
+        c_for_fiber,
+        c_for_matrix,
+    )?;
+    Ok(DensityAndSpecificHeat {
+        rho_relative: f64::NAN,
+        rho,
+        c,
+    })
+}
+
+#[wasm_bindgen]
+pub fn density_and_specific_heat_for_honeycomb(
+    l_cell_side_size: f64,
+    h_cell_side_size: f64,
+    wall_thickness: f64,
+    angle: f64,
+    rho_for_honeycomb: f64,
+    c_for_honeycomb: Option<f64>,
+) -> Result<DensityAndSpecificHeat, JsError> {
+    let [rho_relative, rho, c] = mat_props::density_and_specific_heat_for_honeycomb(
+        l_cell_side_size,
+        h_cell_side_size,
+        wall_thickness,
+        angle,
+        rho_for_honeycomb,
+        c_for_honeycomb,
+    )?;
+    Ok(DensityAndSpecificHeat {
+        rho_relative,
+        rho,
+        c,
+    })
+}
+
+#[allow(clippy::too_many_arguments)]
+#[wasm_bindgen]
+pub fn strength_for_unidirectional_composite(
+    number_of_model: u8,
+    fibre_content: f64,
+    e_for_fiber: f64,
+    nu_for_fiber: f64,
+    sigma_t_for_fiber: f64,
+    e_for_matrix: f64,
+    nu_for_matrix: f64,
+    sigma_t_for_matrix: f64,
+    sigma_c_for_matrix: f64,
+    tau_for_matrix: f64,
+    misalignment_angle: Option<f64>,
+) -> Result<Strength, JsError> {
+    Ok(mat_props::strength_for_unidirectional_composite(
+        number_of_model,
+        fibre_content,
+        e_for_fiber,
+        nu_for_fiber,
+        sigma_t_for_fiber,
+        e_for_matrix,
+        nu_for_matrix,
+        sigma_t_for_matrix,
+        sigma_c_for_matrix,
+        tau_for_matrix,
+        misalignment_angle,
+    )?
+    .into())
+}
@@ -0,0 +1,75 @@
+// The same reference values as the unit tests of mat-props.
+//
+// Run `npm run test:wasm` from `front` (needs wasm-pack).
+
+const test = require("node:test");
+const assert = require("node:assert");
+const wasm = require("../pkg-node/mat_props_wasm.js");
+
+test("elastic modules for unidirectional composite", () => {
+  const modules = wasm.elastic_modules_for_unidirectional_composite(2, 0, 0, 0.2, 100.0, 0.3, 5.0, 0.2);
+  assert.strictEqual(modules.e1, 24.011723329425557);
+  assert.strictEqual(modules.e2, 6.5683701067350135);
+  assert.strictEqual(modules.e3, 6.5683701067350135);
+  assert.strictEqual(modules.nu12, 0.06240625050144681);
+  assert.strictEqual(modules.nu13, 0.06240625050144681);
+  assert.strictEqual(modules.nu23, 0.18585515203940609);
+  assert.strictEqual(modules.g12, 2.9945407835581253);
+  assert.strictEqual(modules.g13, 2.9945407835581253);
+  assert.strictEqual(modules.g23, 2.769465602708258);
+});
+
+test("elastic modules for honeycomb", () => {
+  const modules = wasm.elastic_modules_for_honeycomb(1, 0, 0, 9.24, 8.4619, 0.4, Math.PI / 6.0, 7.07, 0.2);
+  assert.strictEqual(modules.e1, 0.0014972693834675922);
+  assert.strictEqual(modules.e2, 0.0013344741623586129);
+  assert.strictEqual(modules.e3, 0.3592394105863781);
+  assert.strictEqual(modules.nu12, 1.0512175946777975);
+  assert.strictEqual(modules.nu13, 0.0008335774635770805);
+  assert.strictEqual(modules.nu23, 0.0007429441887683659);
+  assert.strictEqual(modules.g12, 0.000288216866909449);
+  assert.strictEqual(modules.g13, 0.07995563727728495);
+  assert.strictEqual(modules.g23, 0.0755763830773748);
+});
+
+test("thermal conductivity for unidirectional composite", () => {
+  const k = wasm.thermal_conductivity_for_unidirectional_composite(2, 0, 0, 0.2, 100.0, 1.0);
+  assert.strictEqual(k.k1, 20.8);
+  assert.strictEqual(k.k2, 1.3300670235932428);
+  assert.strictEqual(k.k3, 1.3300670235932428);
+});
+
+test("thermal expansion for unidirectional composite", () => {
+  const alpha = wasm.thermal_expansion_for_unidirectional_composite(1, 0, 0, 0.2, 100.0, 0.3, 1e-6, 5.0, 0.2, 20e-5);
+  assert.strictEqual(alpha.alpha1, 0.00003303092919697953);
+  assert.strictEqual(alpha.alpha2, 0.0001653038466333737);
+  assert.strictEqual(alpha.alpha3, 0.0001653038466333737);
+});
+
+test("thermal expansion for honeycomb", () => {
+  const alpha = wasm.thermal_expansion_for_honeycomb(1, 0, 0, 9.24, 8.4619, 0.4, Math.PI / 6.0, 20e-5);
+  assert.strictEqual(alpha.alpha1, 0.0002);
+  assert.strictEqual(alpha.alpha2, 0.00019999999999999966);
+  assert.strictEqual(alpha.alpha3, 0.0002);
+});
+
+test("density and specific heat for unidirectional composite", () => {
+  const res = wasm.density_and_specific_heat_for_unidirectional_composite(0.6, 1760.0, 1200.0, 750.0, 1100.0);
+  assert.strictEqual(res.rho, 1536.0);
+  assert.strictEqual(res.c, 859.375);
+  assert.ok(Number.isNaN(wasm.density_and_specific_heat_for_unidirectional_composite(0.6, 1760.0, 1200.0).c));
+});
+
+test("strength for unidirectional composite", () => {
+  const strength = wasm.strength_for_unidirectional_composite(1, 0.6, 230.0, 0.2, 3.5, 3.5, 0.35, 0.08, 0.12, 0.07);
+  assert.strictEqual(strength.xt, 2.121304347826087);
+  assert.strictEqual(strength.xc, 3.2407407407407405);
+  assert.strictEqual(strength.yt, 0.039378039108727725);
+  assert.strictEqual(strength.yc, 0.05906705866309158);
+  assert.strictEqual(strength.s12, 0.03672303579437301);
+  assert.throws(() => wasm.strength_for_unidirectional_composite(2, 0.6, 230.0, 0.2, 3.5, 3.5, 0.35, 0.08, 0.12, 0.07));
+});
+
+test("unknown model", () => {
+  assert.throws(() => wasm.thermal_conductivity_for_unidirectional_composite(42, 0, 0, 0.2, 100.0, 1.0), /Unknown model/);
+});
@@ -0,0 +1,664 @@
+use clap::{Args, Parser, Subcommand, ValueEnum};
+use mat_props::{
+    Axis, Case, CaseFormat, CaseResult, Computation, Dimension, ElasticModulesForHoneycomb,
+    ElasticModulesForUnidirectionalComposite, Grid, MaterialLibrary,
+    ThermalConductivityForUnidirectionalComposite, ThermalExpansionForHoneycomb,
+    ThermalExpansionForUnidirectionalComposite, UnitSystem,
+};
+use std::path::PathBuf;
+
+mod output;
+
+use output::{render_text, Format, Table};
+
+type Result<T> = core::result::Result<T, Box<dyn std::error::Error>>;
+
+/// Runs the mat-props computations locally.
+#[derive(Parser)]
+#[command(name = "mat-props", version)]
+struct Cli {
+    /// The format of the printed results.
+    #[arg(long, value_enum, global = true, default_value_t = Format::Table)]
+    format: Format,
+    /// The unit system of the inputs.
+    #[arg(long, value_enum, global = true, default_value_t = Units::Consistent)]
+    units: Units,
+    /// The unit system of the outputs. Defaults to the unit system of the inputs.
+    #[arg(long, value_enum, global = true)]
+    output_units: Option<Units>,
+    /// The TOML or JSON file with the user-defined materials added to the built-in ones.
+    #[arg(long, global = true)]
+    library: Option<PathBuf>,
+    #[command(subcommand)]
+    command: Command,
+}
+
+/// See `mat_props::UnitSystem`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, ValueEnum)]
+enum Units {
+    /// The values are used as they are, the angles are in radians.
+    Consistent,
+    /// Pa, m, rad, W/(m·K), 1/K.
+    Si,
+    /// GPa, mm, °, W/(m·K), 1/K.
+    Engineering,
+}
+
+impl From<Units> for UnitSystem {
+    fn from(units: Units) -> Self {
+        match units {
+            Units::Consistent => Self::Consistent,
+            Units::Si => Self::Si,
+            Units::Engineering => Self::Engineering,
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, ValueEnum)]
+enum Model {
+    RuleOfMixtures,
+    Vanin,
+}
+
+impl Model {
+    /// Returns the `number_of_model` of the function.
+    fn number(self, function: &str) -> Result<u8> {
+        match (function, self) {
+            (
+                "elastic_modules_for_unidirectional_composite"
+                | "thermal_conductivity_for_unidirectional_composite",
+                Model::RuleOfMixtures,
+            ) => Ok(1),
+            (
+                "elastic_modules_for_unidirectional_composite"
+                | "thermal_conductivity_for_unidirectional_composite",
+                Model::Vanin,
+            ) => Ok(2),
+            (_, Model::Vanin) => Ok(1),
+            (_, model) => Err(format!("{model:?} is not available for {function}").into()),
+        }
+    }
+}
+
+/// The constituents of the unidirectional composite.
+#[derive(Args)]
+struct UdArgs {
+    /// The fibre volume fraction.
+    #[arg(long)]
+    vf: Option<f64>,
+    /// The name of the fibre in the material library, e.g. T300.
+    #[arg(long)]
+    fiber: Option<String>,
+    /// The name of the matrix in the material library, e.g. 3501-6.
+    #[arg(long)]
+    matrix: Option<String>,
+}
+
+/// The geometry of the hexagonal cell.
+#[derive(Args)]
+struct HoneycombArgs {
+    /// The side length of the cell.
+    #[arg(long)]
+    l: Option<f64>,
+    /// The height of the cell.
+    #[arg(long)]
+    h: Option<f64>,
+    /// The thickness of the cell walls.
+    #[arg(long)]
+    t: Option<f64>,
+    /// The angle of inclination of the cell.
+    #[arg(long)]
+    angle: Option<f64>,
+    /// The name of the material of the walls in the material library, e.g. "Al 5052".
+    #[arg(long)]
+    core: Option<String>,
+}
+
+#[derive(Subcommand)]
+enum Command {
+    /// Elastic modules for the unidirectional composite.
+    ElasticUd {
+        #[arg(long, value_enum, default_value_t = Model::Vanin)]
+        model: Model,
+        #[command(flatten)]
+        ud: UdArgs,
+        #[arg(long)]
+        e_fiber: Option<f64>,
+        #[arg(long)]
+        nu_fiber: Option<f64>,
+        #[arg(long)]
+        e_matrix: Option<f64>,
+        #[arg(long)]
+        nu_matrix: Option<f64>,
+    },
+    /// Elastic modules for the honeycomb.
+    ElasticHoneycomb {
+        #[arg(long, value_enum, default_value_t = Model::Vanin)]
+        model: Model,
+        #[command(flatten)]
+        honeycomb: HoneycombArgs,
+        #[arg(long)]
+        e: Option<f64>,
+        #[arg(long)]
+        nu: Option<f64>,
+    },
+    /// Thermal conductivity for the unidirectional composite.
+    ThermalConductivityUd {
+        #[arg(long, value_enum, default_value_t = Model::Vanin)]
+        model: Model,
+        #[command(flatten)]
+        ud: UdArgs,
+        #[arg(long)]
+        k_fiber: Option<f64>,
+        #[arg(long)]
+        k_matrix: Option<f64>,
+    },
+    /// Thermal expansion for the unidirectional composite.
+    ThermalExpansionUd {
+        #[arg(long, value_enum, default_value_t = Model::Vanin)]
+        model: Model,
+        #[command(flatten)]
+        ud: UdArgs,
+        #[arg(long)]
+        e_fiber: Option<f64>,
+        #[arg(long)]
+        nu_fiber: Option<f64>,
+        #[arg(long)]
+        alpha_fiber: Option<f64>,
+        #[arg(long)]
+        e_matrix: Option<f64>,
+        #[arg(long)]
+        nu_matrix: Option<f64>,
+        #[arg(long)]
+        alpha_matrix: Option<f64>,
+    },
+    /// Thermal expansion for the honeycomb.
+    ThermalExpansionHoneycomb {
+        #[arg(long, value_enum, default_value_t = Model::Vanin)]
+        model: Model,
+        #[command(flatten)]
+        honeycomb: HoneycombArgs,
+        #[arg(long)]
+        alpha: Option<f64>,
+    },
+    /// Runs the JSON or TOML case file. The units are given by the case file.
+    Case {
+        file: PathBuf,
+        /// The result file in the same format as the case file.
+        #[arg(long, short)]
+        output: Option<PathBuf>,
+    },
+    /// Evaluates the function over the grid of the swept inputs.
+    Sweep {
+        /// The name of the function, e.g. elastic_modules_for_unidirectional_composite.
+        function: String,
+        #[arg(long, value_enum, default_value_t = Model::Vanin)]
+        model: Model,
+        /// The input by name, e.g. --set e_for_fiber=230e9.
+        #[arg(long = "set", value_parser = parse_value)]
+        values: Vec<(String, f64)>,
+        /// The material by role, e.g. --material fiber=T300.
+        #[arg(long = "material", value_parser = parse_material)]
+        materials: Vec<(String, String)>,
+        /// The swept input: fibre_content=0:0.8:5 (linear), fibre_content=log:0.01:1:3 or fibre_content=0.2,0.4.
+        #[arg(long = "axis", required = true, value_parser = parse_axis)]
+        axes: Vec<(String, Grid)>,
+    },
+    /// Lists the materials of the library.
+    Materials,
+}
+
+fn parse_value(s: &str) -> core::result::Result<(String, f64), String> {
+    let (name, value) = s.split_once('=').ok_or("expected name=value")?;
+    let value = value.parse().map_err(|err| format!("{err}"))?;
+    Ok((name.to_owned(), value))
+}
+
+fn parse_material(s: &str) -> core::result::Result<(String, String), String> {
+    let (role, name) = s.split_once('=').ok_or("expected role=name")?;
+    Ok((role.to_owned(), name.to_owned()))
+}
+
+fn parse_axis(s: &str) -> core::result::Result<(String, Grid), String> {
+    let (name, grid) = s.split_once('=').ok_or("expected name=grid")?;
+    let parse = |value: &str| value.parse::<f64>().map_err(|err| format!("{err}"));
+    let grid = match grid.split(':').collect::<Vec<_>>()[..] {
+        ["log", start, end, number_of_points] => Grid::Log {
+            start: parse(start)?,
+            end: parse(end)?,
+            number_of_points: number_of_points.parse().map_err(|err| format!("{err}"))?,
+        },
+        [start, end, number_of_points] => Grid::Linear {
+            start: parse(start)?,
+            end: parse(end)?,
+            number_of_points: number_of_points.parse().map_err(|err| format!("{err}"))?,
+        },
+        [points] => Grid::Explicit(
+            points
+                .split(',')
+                .map(parse)
+                .collect::<core::result::Result<_, _>>()?,
+        ),
+        _ => return Err("expected start:end:n, log:start:end:n or a list of values".to_owned()),
+    };
+    Ok((name.to_owned(), grid))
+}
+
+impl Cli {
+    fn unit_system(&self) -> UnitSystem {
+        self.units.into()
+    }
+
+    fn output_unit_system(&self) -> UnitSystem {
+        self.output_units.unwrap_or(self.units).into()
+    }
+
+    /// Returns the case of the subcommand of a model.
+    fn case(&self) -> Result<Option<Case>> {
+        let ud_inputs = |ud: &UdArgs| {
+            (
+                vec![("fibre_content", ud.vf)],
+                vec![("fiber", ud.fiber.clone()), ("matrix", ud.matrix.clone())],
+            )
+        };
+        let honeycomb_inputs = |honeycomb: &HoneycombArgs| {
+            (
+                vec![
+                    ("l_cell_side_size", honeycomb.l),
+                    ("h_cell_side_size", honeycomb.h),
+                    ("wall_thickness", honeycomb.t),
+                    ("angle", honeycomb.angle),
+                ],
+                vec![("honeycomb", honeycomb.core.clone())],
+            )
+        };
+        let (function, model, (mut inputs, materials), properties) = match self.command {
+            Command::ElasticUd {
+                model,
+                ref ud,
+                e_fiber,
+                nu_fiber,
+                e_matrix,
+                nu_matrix,
+            } => (
+                "elastic_modules_for_unidirectional_composite",
+                model,
+                ud_inputs(ud),
+                vec![
+                    ("e_for_fiber", e_fiber),
+                    ("nu_for_fiber", nu_fiber),
+                    ("e_for_matrix", e_matrix),
+                    ("nu_for_matrix", nu_matrix),
+                ],
+            ),
+            Command::ElasticHoneycomb {
+                model,
+                ref honeycomb,
+                e,
+                nu,
+            } => (
+                "elastic_modules_for_honeycomb",
+                model,
+                honeycomb_inputs(honeycomb),
+                vec![("e_for_honeycomb", e), ("nu_for_honeycomb", nu)],
+            ),
+            Command::ThermalConductivityUd {
+                model,
+                ref ud,
+                k_fiber,
+                k_matrix,
+            } => (
+                "thermal_conductivity_for_unidirectional_composite",
+                model,
+                ud_inputs(ud),
+                vec![("k_for_fiber", k_fiber), ("k_for_matrix", k_matrix)],
+            ),
+            Command::ThermalExpansionUd {
+                model,
+                ref ud,
+                e_fiber,
+                nu_fiber,
+                alpha_fiber,
+                e_matrix,
+                nu_matrix,
+                alpha_matrix,
+            } => (
+                "thermal_expansion_for_unidirectional_composite",
+                model,
+                ud_inputs(ud),
+                vec![
+                    ("e_for_fiber", e_fiber),
+                    ("nu_for_fiber", nu_fiber),
+                    ("alpha_for_fiber", alpha_fiber),
+                    ("e_for_matrix", e_matrix),
+                    ("nu_for_matrix", nu_matrix),
+                    ("alpha_for_matrix", alpha_matrix),
+                ],
+            ),
+            Command::ThermalExpansionHoneycomb {
+                model,
+                ref honeycomb,
+                alpha,
+            } => (
+                "thermal_expansion_for_honeycomb",
+                model,
+                honeycomb_inputs(honeycomb),
+                vec![("alpha_for_honeycomb", alpha)],
+            ),
+            Command::Case { .. } | Command::Sweep { .. } | Command::Materials => return Ok(None),
+        };
+        inputs.extend(properties);
+        Ok(Some(Case {
+            function: function.to_owned(),
+            number_of_model: model.number(function)?,
+            unit_system: self.unit_system(),
+            output_unit_system: self.output_unit_system(),
+            inputs: inputs
+                .into_iter()
+                .filter_map(|(name, value)| Some((name.to_owned(), value?)))
+                .collect(),
+            materials: materials
+                .into_iter()
+                .filter_map(|(role, name)| Some((role.to_owned(), name?)))
+                .collect(),
+        }))
+    }
+}
+
+// the same functions as in `Case::run` except for `effective_properties` which has no outputs
+fn for_computation<R>(function: &str, f: impl ComputationFn<R>) -> Result<R> {
+    match function {
+        "elastic_modules_for_unidirectional_composite" => {
+            f.call::<ElasticModulesForUnidirectionalComposite>()
+        }
+        "elastic_modules_for_honeycomb" => f.call::<ElasticModulesForHoneycomb>(),
+        "thermal_conductivity_for_unidirectional_composite" => {
+            f.call::<ThermalConductivityForUnidirectionalComposite>()
+        }
+        "thermal_expansion_for_unidirectional_composite" => {
+            f.call::<ThermalExpansionForUnidirectionalComposite>()
+        }
+        "thermal_expansion_for_honeycomb" => f.call::<ThermalExpansionForHoneycomb>(),
+        _ => Err(mat_props::Error::UnknownFunction(function.to_owned()).into()),
+    }
+}
+
+/// A generic closure over the computation.
+trait ComputationFn<R> {
+    fn call<C: Computation>(self) -> Result<R>;
+}
+
+fn column(name: &str, dimension: Dimension, unit_system: UnitSystem) -> String {
+    match unit_system.symbol(dimension) {
+        "" => name.to_owned(),
+        symbol => format!("{name} [{symbol}]"),
+    }
+}
+
+/// Lays out the outputs of the case in the order of the function.
+struct ResultTable<'a>(&'a CaseResult);
+
+impl ComputationFn<Table> for ResultTable<'_> {
+    fn call<C: Computation>(self) -> Result<Table> {
+        let result = self.0;
+        Ok(Table {
+            columns: C::OUTPUTS
+                .iter()
+                .zip(C::OUTPUT_DIMENSIONS)
+                .map(|(&name, &dimension)| column(name, dimension, result.case.output_unit_system))
+                .collect(),
+            rows: vec![C::OUTPUTS
+                .iter()
+                .map(|&name| result.outputs[name])
+                .collect()],
+        })
+    }
+}
+
+struct SweepTable<'a> {
+    library: &'a MaterialLibrary,
+    number_of_model: u8,
+    values: &'a [(String, f64)],
+    materials: &'a [(String, String)],
+    axes: &'a [(String, Grid)],
+    unit_system: UnitSystem,
+    output_unit_system: UnitSystem,
+}
+
+impl ComputationFn<Table> for SweepTable<'_> {
+    fn call<C: Computation>(self) -> Result<Table> {
+        let index =
+            |name: &str| C::input_index(name).ok_or_else(|| format!("unknown input `{name}`"));
+        // the properties of the materials are in SI units, so the inputs are swept in SI units
+        let mut values = Vec::with_capacity(self.values.len() + self.axes.len());
+        for (name, value) in self.values {
+            let dimension = C::INPUT_DIMENSIONS[index(name)?];
+            values.push((
+                name.as_str(),
+                self.unit_system.convert(*value, dimension, UnitSystem::Si),
+            ));
+        }
+        // the swept inputs don't have to be given
+        values.extend(self.axes.iter().map(|(name, _)| (name.as_str(), 0.0)));
+        let materials: Vec<(&str, &str)> = self
+            .materials
+            .iter()
+            .map(|(role, name)| (role.as_str(), name.as_str()))
+            .collect();
+        let inputs = self.library.inputs_for::<C>(&values, &materials)?;
+        // the grids in the given units to show in the table
+        let grids: Vec<Vec<f64>> = self.axes.iter().map(|(_, grid)| grid.points()).collect();
+        let axes = self
+            .axes
+            .iter()
+            .zip(&grids)
+            .map(|((name, _), points)| {
+                let i = index(name)?;
+                let points = points
+                    .iter()
+                    .map(|&point| {
+                        self.unit_system
+                            .convert(point, C::INPUT_DIMENSIONS[i], UnitSystem::Si)
+                    })
+                    .collect();
+                Ok(Axis {
+                    input: C::INPUTS[i].into(),
+                    grid: Grid::Explicit(points),
+                })
+            })
+            .collect::<Result<Vec<_>>>()?;
+        let table = mat_props::sweep::<C>(self.number_of_model, &inputs, &axes)?;
+
+        let columns = axes
+            .iter()
+            .map(|axis| {
+                let dimension = C::INPUT_DIMENSIONS[C::input_index(&axis.input).unwrap()];
+                column(&axis.input, dimension, self.unit_system)
+            })
+            .chain(
+                C::OUTPUTS
+                    .iter()
+                    .zip(C::OUTPUT_DIMENSIONS)
+                    .map(|(&name, &dimension)| column(name, dimension, self.output_unit_system)),
+            )
+            .collect();
+        let rows = table
+            .outputs
+            .iter()
+            .enumerate()
+            .map(|(row, outputs)| {
+                // the rows are in the row-major order with the last axis varying the fastest
+                let mut rest = row;
+                let mut inputs = vec![0.0; grids.len()];
+                for (axis, grid) in grids.iter().enumerate().rev() {
+                    inputs[axis] = grid[rest % grid.len()];
+                    rest /= grid.len();
+                }
+                inputs.extend(outputs.iter().zip(C::OUTPUT_DIMENSIONS).map(
+                    |(&output, &dimension)| {
+                        UnitSystem::Si.convert(output, dimension, self.output_unit_system)
+                    },
+                ));
+                inputs
+            })
+            .collect();
+        Ok(Table { columns, rows })
+    }
+}
+
+fn print_result(result: &CaseResult, format: Format) -> Result<()> {
+    if format == Format::Json {
+        println!("{}", result.to_string(CaseFormat::Json)?);
+    } else if !result.outputs.is_empty() {
+        let table = for_computation(&result.case.function, ResultTable(result))?;
+        print!("{}", table.render(format));
+    }
+    Ok(())
+}
+
+fn run(cli: Cli) -> Result<()> {
+    let mut library = MaterialLibrary::builtin();
+    if let Some(path) = &cli.library {
+        library.extend(MaterialLibrary::load(path)?);
+    }
+
+    if let Some(case) = cli.case()? {
+        return print_result(&case.run(&library)?, cli.format);
+    }
+    match cli.command {
+        Command::Case {
+            ref file,
+            ref output,
+        } => {
+            let result = Case::load(file)?.run(&library)?;
+            match output {
+                Some(output) => result.save(output)?,
+                None => print_result(&result, cli.format)?,
+            }
+        }
+        Command::Sweep {
+            ref function,
+            model,
+            ref values,
+            ref materials,
+            ref axes,
+        } => {
+            let table = for_computation(
+                function,
+                SweepTable {
+                    library: &library,
+                    number_of_model: model.number(function)?,
+                    values,
+                    materials,
+                    axes,
+                    unit_system: cli.unit_system(),
+                    output_unit_system: cli.output_unit_system(),
+                },
+            )?;
+            print!("{}", table.render(cli.format));
+        }
+        Command::Materials => {
+            if cli.format == Format::Json {
+                println!("{}", serde_json::to_string_pretty(&library.materials)?);
+            } else {
+                let columns = ["name", "kind", "source"].map(str::to_owned);
+                let rows: Vec<Vec<String>> = library
+                    .materials
+                    .iter()
+                    .map(|material| {
+                        vec![
+                            material.name.clone(),
+                            format!("{:?}", material.kind).to_lowercase(),
+                            material.source.clone(),
+                        ]
+                    })
+                    .collect();
+                print!("{}", render_text(&columns, &rows, cli.format));
+            }
+        }
+        _ => unreachable!("the subcommands of the models are handled by `Cli::case`"),
+    }
+    Ok(())
+}
+
+fn main() {
+    if let Err(err) = run(Cli::parse()) {
+        eprintln!("Error: {err}");
+        std::process::exit(1);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::collections::BTreeMap;
+
+    #[test]
+    fn test() {
+        let cli = Cli::parse_from([
+            "mat-props",
+            "elastic-ud",
+            "--model",
+            "rule-of-mixtures",
+            "--vf",
+            "0.6",
+            "--fiber",
+            "T300",
+            "--matrix",
+            "3501-6",
+            "--units",
+            "si",
+            "--output-units",
+            "engineering",
+        ]);
+        let case = cli.case().unwrap().unwrap();
+        assert_eq!(case.number_of_model, 1);
+        assert_eq!(
+            case.inputs,
+            BTreeMap::from([("fibre_content".to_owned(), 0.6)])
+        );
+        let result = case.run(&MaterialLibrary::builtin()).unwrap();
+        let table = for_computation(&case.function, ResultTable(&result)).unwrap();
+        assert_eq!(table.columns[0], "e1 [GPa]");
+
+        let cli = Cli::parse_from([
+            "mat-props",
+            "sweep",
+            "thermal_conductivity_for_unidirectional_composite",
+            "--set",
+            "k_for_fiber=100",
+            "--set",
+            "k_for_matrix=1",
+            "--axis",
+            "fibre_content=0:0.8:5",
+        ]);
+        let Command::Sweep {
+            ref function,
+            model,
+            ref values,
+            ref materials,
+            ref axes,
+        } = cli.command
+        else {
+            unreachable!()
+        };
+        let table = for_computation(
+            function,
+            SweepTable {
+                library: &MaterialLibrary::builtin(),
+                number_of_model: model.number(function).unwrap(),
+                values,
+                materials,
+                axes,
+                unit_system: cli.unit_system(),
+                output_unit_system: cli.output_unit_system(),
+            },
+        )
+        .unwrap();
+        assert_eq!(table.rows.len(), 5);
+        assert_eq!(table.columns, ["fibre_content", "k1", "k2", "k3"]);
+        assert!(table
+            .render(Format::Csv)
+            .starts_with("fibre_content,k1,k2,k3\n0,"));
+    }
+}
@@ -0,0 +1,81 @@
+use clap::ValueEnum;
+
+/// The format of the printed results.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, ValueEnum)]
+pub(crate) enum Format {
+    /// Aligned columns for reading in the terminal.
+    Table,
+    Csv,
+    Json,
+}
+
+/// The named columns of numbers, e.g. the outputs of a computation or the rows of a sweep.
+pub(crate) struct Table {
+    pub(crate) columns: Vec<String>,
+    pub(crate) rows: Vec<Vec<f64>>,
+}
+
+impl Table {
+    pub(crate) fn render(&self, format: Format) -> String {
+        match format {
+            Format::Json => {
+                let value = serde_json::json!({
+                    "columns": self.columns,
+                    "rows": self.rows,
+                });
+                serde_json::to_string_pretty(&value).unwrap()
+            }
+            Format::Table | Format::Csv => {
+                let rows: Vec<Vec<String>> = self
+                    .rows
+                    .iter()
+                    .map(|row| row.iter().map(f64::to_string).collect())
+                    .collect();
+                render_text(&self.columns, &rows, format)
+            }
+        }
+    }
+}
+
+/// Renders the cells as aligned columns or as CSV.
+pub(crate) fn render_text(columns: &[String], rows: &[Vec<String>], format: Format) -> String {
+    let mut s = String::new();
+    match format {
+        Format::Csv => {
+            for row in std::iter::once(columns).chain(rows.iter().map(Vec::as_slice)) {
+                let cells: Vec<String> = row.iter().map(|cell| csv_escape(cell)).collect();
+                s.push_str(&cells.join(","));
+                s.push('\n');
+            }
+        }
+        _ => {
+            let widths: Vec<usize> = (0..columns.len())
+                .map(|j| {
+                    rows.iter()
+                        .map(|row| row[j].chars().count())
+                        .chain(std::iter::once(columns[j].chars().count()))
+                        .max()
+                        .unwrap_or(0)
+                })
+                .collect();
+            for row in std::iter::once(columns).chain(rows.iter().map(Vec::as_slice)) {
+                let cells: Vec<String> = row
+                    .iter()
+                    .zip(&widths)
+                    .map(|(cell, &width)| format!("{cell:<width$}"))
+                    .collect();
+                s.push_str(cells.join("  ").trim_end());
+                s.push('\n');
+            }
+        }
+    }
+    s
+}
+
+fn csv_escape(cell: &str) -> String {
+    if cell.contains([',', '"', '\n']) {
+        format!("\"{}\"", cell.replace('"', "\"\""))
+    } else {
+        cell.to_owned()
+    }
+}
@@ -0,0 +1,129 @@
+use mat_props_proto::{
+    Computation, ComputationResponse, ComputationVisitor, Endianness, ErrorCode, ErrorMessage,
+    Header, Message, ProtocolVersion, ERROR_CONTENT_TYPE,
+};
+
+use crate::{Error, Response, Transport};
+
+/// Answers the requests like the backend but computes with `mat-props` in the calling task,
+/// so the code that uses a [`crate::Client`] can be tested without the backend.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct InProcess;
+
+impl Transport for InProcess {
+    async fn post(&self, path: &str, content_type: &str, body: Vec<u8>) -> Result<Response, Error> {
+        let name = path.strip_prefix("/compute/").unwrap_or(path);
+        let resp = mat_props_proto::messages::visit_computation(
+            name,
+            Respond {
+                content_type,
+                body: &body,
+            },
+        )
+        .unwrap_or_else(|| {
+            Err(ErrorMessage::new(
+                404,
+                ErrorCode::UnknownFunction,
+                None,
+                format!("Unknown function `{name}`"),
+            ))
+        });
+        Ok(match resp {
+            Ok(resp) => resp,
+            Err(message) => Response {
+                status: message.status,
+                content_type: Some(ERROR_CONTENT_TYPE.to_owned()),
+                body: serde_json::to_vec(&message).unwrap(),
+            },
+        })
+    }
+}
+
+struct Respond<'r> {
+    content_type: &'r str,
+    body: &'r [u8],
+}
+
+impl ComputationVisitor for Respond<'_> {
+    type Output = Result<Response, ErrorMessage>;
+
+    fn visit<C: Computation>(self) -> Self::Output {
+        if !self.content_type.eq_ignore_ascii_case(C::CONTENT_TYPE) {
+            return Err(ErrorMessage::new(
+                415,
+                ErrorCode::UnsupportedContentType,
+                None,
+                format!("Expected the content type `{}`", C::CONTENT_TYPE),
+            ));
+        }
+        let (header, message) = Header::split(self.body).map_err(|_| invalid_header())?;
+        if let Some(header) = header {
+            if header.version != ProtocolVersion::V2 as u8 {
+                return Err(ErrorMessage::new(
+                    400,
+                    ErrorCode::UnsupportedVersion,
+                    Some("header.version"),
+                    format!("Unsupported protocol version {}", header.version),
+                ));
+            }
+            if header.message_id != C::MESSAGE_ID {
+                return Err(ErrorMessage::new(
+                    400,
+                    ErrorCode::MessageIdMismatch,
+                    Some("header.message_id"),
+                    format!(
+                        "Expected the message {} but got the message {}",
+                        C::MESSAGE_ID,
+                        header.message_id
+                    ),
+                ));
+            }
+            if header.payload_length as usize != C::SIZE
+                || message.first() != Some(&(header.endianness as u8))
+            {
+                return Err(invalid_header());
+            }
+        }
+        let Some(endianness) = message.first().copied().and_then(Endianness::try_from_u8) else {
+            return Err(ErrorMessage::invalid_endianness());
+        };
+        let args = C::decode(message, endianness)
+            .map_err(|e| ErrorMessage::invalid_length(e.expected, e.actual))?;
+
+        let inputs = args.inputs();
+        let input_names = <C::Model as mat_props::Computation>::INPUTS;
+        if let Some(i) = inputs.iter().position(|input| !input.is_finite()) {
+            return Err(ErrorMessage::non_finite_input(input_names[i]));
+        }
+        let outputs = mat_props::evaluate_in_units::<C::Model>(
+            args.number_of_model(),
+            &inputs,
+            args.unit_system(),
+            args.output_unit_system(),
+        )?;
+        let output_names = <C::Model as mat_props::Computation>::OUTPUTS;
+        if let Some(i) = outputs.iter().position(|output| output.is_infinite()) {
+            return Err(ErrorMessage::non_finite_output(output_names[i]));
+        }
+
+        let resp = C::Response::from_outputs(&outputs);
+        let body = match header {
+            Some(_) => resp.encode_with_header(endianness),
+            None => resp.encode(endianness),
+        };
+        Ok(Response {
+            status: 200,
+            content_type: Some(C::Response::CONTENT_TYPE.to_owned()),
+            body,
+        })
+    }
+}
+
+fn invalid_header() -> ErrorMessage {
+    ErrorMessage::new(
+        400,
+        ErrorCode::InvalidHeader,
+        Some("header"),
+        "Invalid header",
+    )
+}
@@ -0,0 +1,205 @@
+//! The async client of the compute endpoints of the backend.
+//!
+//! [`Client`] sends the args messages of `mat-props-proto` with the header of the protocol
+//! version 2 through a [`Transport`]: [`ReqwestTransport`] (the `reqwest` feature, on by default)
+//! to a running backend or [`InProcess`] that computes with `mat-props` directly, for the tests.
+//!
+//! ```no_run
+//! # async fn run() -> Result<(), mat_props_client::Error> {
+//! use mat_props_client::{Client, ThermalConductivityForUnidirectionalCompositeArgs};
+//!
+//! let client = Client::with_base_url("http://localhost:8080");
+//! let resp = client
+//!     .thermal_conductivity_for_unidirectional_composite(
+//!         &ThermalConductivityForUnidirectionalCompositeArgs {
+//!             number_of_model: 1,
+//!             fibre_content: 0.2,
+//!             k_for_fiber: 100.0,
+//!             k_for_matrix: 1.0,
+//!             ..Default::default()
+//!         },
+//!     )
+//!     .await?;
+//! println!("{}", resp.k1);
+//! # Ok(())
+//! # }
+//! ```
+
+mod in_process;
+#[cfg(feature = "reqwest")]
+mod reqwest_transport;
+
+use std::future::Future;
+
+use mat_props_proto::{Header, Message, ERROR_CONTENT_TYPE};
+
+pub use in_process::InProcess;
+pub use mat_props_proto::messages::*;
+pub use mat_props_proto::{Computation, ComputationResponse, Endianness, ErrorCode, ErrorMessage};
+#[cfg(feature = "reqwest")]
+pub use reqwest_transport::ReqwestTransport;
+
+/// The response of the backend as received by a [`Transport`].
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Response {
+    pub status: u16,
+    pub content_type: Option<String>,
+    pub body: Vec<u8>,
+}
+
+/// Sends the requests of a [`Client`].
+pub trait Transport {
+    /// Posts the body with the content type to the path of the backend,
+    /// e.g. `/compute/elastic_modules_for_honeycomb`.
+    fn post(
+        &self,
+        path: &str,
+        content_type: &str,
+        body: Vec<u8>,
+    ) -> impl Future<Output = Result<Response, Error>> + Send;
+}
+
+#[derive(Debug)]
+pub enum Error {
+    /// The error response of the backend, see [`ErrorMessage::code`].
+    Api(ErrorMessage),
+    /// The error response without the problem details, e.g. of a proxy.
+    Status { status: u16, body: String },
+    /// The response isn't the response message of the computation.
+    InvalidResponse(String),
+    /// The request couldn't be sent or the response couldn't be received.
+    Transport(Box<dyn std::error::Error + Send + Sync>),
+}
+
+impl Error {
+    /// The code of the error response of the backend, if any.
+    pub fn code(&self) -> Option<ErrorCode> {
+        match self {
+            Self::Api(message) => Some(message.code),
+            _ => None,
+        }
+    }
+
+    fn of_response(resp: Response) -> Self {
+        let is_problem = resp
+            .content_type
+            .as_deref()
+            .is_some_and(|content_type| content_type.starts_with(ERROR_CONTENT_TYPE));
+        match serde_json::from_slice(&resp.body) {
+            Ok(message) if is_problem => Self::Api(message),
+            _ => Self::Status {
+                status: resp.status,
+                body: String::from_utf8_lossy(&resp.body).into_owned(),
+            },
+        }
+    }
+}
+
+impl std::fmt::Display for Error {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Api(message) => write!(f, "{}: {}", message.status, message.detail),
+            Self::Status { status, body } => write!(f, "{status}: {body}"),
+            Self::InvalidResponse(detail) => write!(f, "Invalid response: {detail}"),
+            Self::Transport(e) => e.fmt(f),
+        }
+    }
+}
+
+impl std::error::Error for Error {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Self::Api(message) => Some(message),
+            Self::Transport(e) => Some(e.as_ref()),
+            _ => None,
+        }
+    }
+}
+
+/// Reads the response message after the header.
+fn decode_response<R: Message>(body: &[u8]) -> Result<R, Error> {
+    let (header, message) =
+        Header::split(body).map_err(|e| Error::InvalidResponse(e.to_string()))?;
+    let Some(header) = header else {
+        return Err(Error::InvalidResponse(
+            "The response has no header".to_owned(),
+        ));
+    };
+    if header.message_id != R::MESSAGE_ID {
+        return Err(Error::InvalidResponse(format!(
+            "Expected the message {} but got the message {}",
+            R::MESSAGE_ID,
+            header.message_id
+        )));
+    }
+    R::decode(message, header.endianness).map_err(|e| Error::InvalidResponse(e.to_string()))
+}
+
+/// The client of the compute endpoints with a method per computation.
+#[derive(Clone, Debug, Default)]
+pub struct Client<T> {
+    transport: T,
+}
+
+#[cfg(feature = "reqwest")]
+impl Client<ReqwestTransport> {
+    /// The client of the backend at the base URL, e.g. `http://localhost:8080`.
+    pub fn with_base_url(base_url: impl Into<String>) -> Self {
+        Self::new(ReqwestTransport::new(base_url))
+    }
+}
+
+impl<T: Transport> Client<T> {
+    pub fn new(transport: T) -> Self {
+        Self { transport }
+    }
+
+    pub fn transport(&self) -> &T {
+        &self.transport
+    }
+
+    /// Computes any computation, e.g. in generic code.
+    pub async fn compute<C: Computation>(&self, args: &C) -> Result<C::Response, Error> {
+        let body = args.encode_with_header(Endianness::NATIVE);
+        let resp = self.transport.post(C::PATH, C::CONTENT_TYPE, body).await?;
+        if !(200..300).contains(&resp.status) {
+            return Err(Error::of_response(resp));
+        }
+        decode_response(&resp.body)
+    }
+
+    pub async fn elastic_modules_for_unidirectional_composite(
+        &self,
+        args: &ElasticModulesForUnidirectionalCompositeArgs,
+    ) -> Result<ElasticModulesForUnidirectionalCompositeResponse, Error> {
+        self.compute(args).await
+    }
+
+    pub async fn elastic_modules_for_honeycomb(
+        &self,
+        args: &ElasticModulesForHoneycombArgs,
+    ) -> Result<ElasticModulesForHoneycombResponse, Error> {
+        self.compute(args).await
+    }
+
+    pub async fn thermal_conductivity_for_unidirectional_composite(
+        &self,
+        args: &ThermalConductivityForUnidirectionalCompositeArgs,
+    ) -> Result<ThermalConductivityForUnidirectionalCompositeResponse, Error> {
+        self.compute(args).await
+    }
+
+    pub async fn thermal_expansion_for_unidirectional_composite(
+        &self,
+        args: &ThermalExpansionForUnidirectionalCompositeArgs,
+    ) -> Result<ThermalExpansionForUnidirectionalCompositeResponse, Error> {
+        self.compute(args).await
+    }
+
+    pub async fn thermal_expansion_for_honeycomb(
+        &self,
+        args: &ThermalExpansionForHoneycombArgs,
+    ) -> Result<ThermalExpansionForHoneycombResponse, Error> {
+        self.compute(args).await
+    }
+}
@@ -0,0 +1,50 @@
+use reqwest::header::CONTENT_TYPE;
+
+use crate::{Error, Response, Transport};
+
+/// Sends the requests to the backend at the base URL with `reqwest`.
+#[derive(Clone, Debug)]
+pub struct ReqwestTransport {
+    client: reqwest::Client,
+    base_url: String,
+}
+
+impl ReqwestTransport {
+    pub fn new(base_url: impl Into<String>) -> Self {
+        Self::with_client(reqwest::Client::new(), base_url)
+    }
+
+    /// Shares the connection pool and the settings, e.g. the timeouts, of the client.
+    pub fn with_client(client: reqwest::Client, base_url: impl Into<String>) -> Self {
+        let base_url = base_url.into().trim_end_matches('/').to_owned();
+        Self { client, base_url }
+    }
+}
+
+impl Transport for ReqwestTransport {
+    async fn post(&self, path: &str, content_type: &str, body: Vec<u8>) -> Result<Response, Error> {
+        let resp = self
+            .client
+            .post(format!("{}{path}", self.base_url))
+            .header(CONTENT_TYPE, content_type)
+            .body(body)
+            .send()
+            .await
+            .map_err(|e| Error::Transport(Box::new(e)))?;
+        let status = resp.status().as_u16();
+        let content_type = resp
+            .headers()
+            .get(CONTENT_TYPE)
+            .and_then(|content_type| content_type.to_str().ok())
+            .map(str::to_owned);
+        let body = resp
+            .bytes()
+            .await
+            .map_err(|e| Error::Transport(Box::new(e)))?;
+        Ok(Response {
+            status,
+            content_type,
+            body: body.to_vec(),
+        })
+    }
+}
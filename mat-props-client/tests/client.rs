@@ -0,0 +1,124 @@
+use mat_props_client::{
+    Client, ElasticModulesForHoneycombArgs, Error, ErrorCode, InProcess,
+    ThermalConductivityForUnidirectionalCompositeArgs, Transport,
+};
+
+const THERMAL_CONDUCTIVITY_ARGS: ThermalConductivityForUnidirectionalCompositeArgs =
+    ThermalConductivityForUnidirectionalCompositeArgs {
+        endianness: 0,
+        number_of_model: 1,
+        unit_system: 0,
+        output_unit_system: 0,
+        fibre_content: 0.2,
+        k_for_fiber: 100.0,
+        k_for_matrix: 1.0,
+    };
+
+#[tokio::test]
+async fn in_process_computes_with_mat_props() {
+    let client = Client::new(InProcess);
+    let resp = client
+        .thermal_conductivity_for_unidirectional_composite(&THERMAL_CONDUCTIVITY_ARGS)
+        .await
+        .unwrap();
+    let [k1, k2, k3] =
+        mat_props::thermal_conductivity_for_unidirectional_composite(1, 0.2, 100.0, 1.0).unwrap();
+    assert_eq!([resp.k1, resp.k2, resp.k3], [k1, k2, k3]);
+
+    let args = ElasticModulesForHoneycombArgs {
+        number_of_model: 1,
+        l_cell_side_size: 9.24,
+        h_cell_side_size: 8.4619,
+        wall_thickness: 0.4,
+        angle: std::f64::consts::FRAC_PI_6,
+        e_for_honeycomb: 7_000_000_000.0,
+        nu_for_honeycomb: 0.2,
+        ..Default::default()
+    };
+    let resp = client.elastic_modules_for_honeycomb(&args).await.unwrap();
+    assert!(resp.e1 > 0.0 && resp.g23 > 0.0);
+}
+
+#[tokio::test]
+async fn errors_are_mapped() {
+    let client = Client::new(InProcess);
+    let err = client
+        .thermal_conductivity_for_unidirectional_composite(
+            &ThermalConductivityForUnidirectionalCompositeArgs {
+                number_of_model: 9,
+                ..THERMAL_CONDUCTIVITY_ARGS
+            },
+        )
+        .await
+        .unwrap_err();
+    let Error::Api(message) = &err else {
+        panic!("{err}");
+    };
+    assert_eq!(message.status, 400);
+    assert_eq!(message.field.as_deref(), Some("number_of_model"));
+    assert_eq!(err.code(), Some(ErrorCode::UnknownModel));
+
+    let err = client
+        .thermal_conductivity_for_unidirectional_composite(
+            &ThermalConductivityForUnidirectionalCompositeArgs {
+                k_for_fiber: f64::NAN,
+                ..THERMAL_CONDUCTIVITY_ARGS
+            },
+        )
+        .await
+        .unwrap_err();
+    assert_eq!(err.code(), Some(ErrorCode::NonFiniteInput));
+
+    let resp = InProcess
+        .post("/compute/strength", "application/octet-stream", Vec::new())
+        .await
+        .unwrap();
+    assert_eq!(resp.status, 404);
+}
+
+/// Answers one request with the raw HTTP response.
+#[cfg(feature = "reqwest")]
+async fn serve_once(response: String) -> String {
+    use tokio::io::{AsyncReadExt, AsyncWriteExt};
+
+    let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let base_url = format!("http://{}", listener.local_addr().unwrap());
+    tokio::spawn(async move {
+        let (mut stream, _) = listener.accept().await.unwrap();
+        // the request is small enough for one read
+        let mut buf = [0; 4096];
+        let _ = stream.read(&mut buf).await.unwrap();
+        stream.write_all(response.as_bytes()).await.unwrap();
+    });
+    base_url
+}
+
+#[cfg(feature = "reqwest")]
+#[tokio::test]
+async fn reqwest_transport_maps_the_error_responses() {
+    let body = r#"{"type":"about:blank","title":"Bad Request","status":400,"detail":"Unknown model","code":"unknown_model","field":"number_of_model"}"#;
+    let response = format!(
+        "HTTP/1.1 400 Bad Request\r\ncontent-type: application/problem+json\r\ncontent-length: {}\r\nconnection: close\r\n\r\n{body}",
+        body.len()
+    );
+    let base_url = serve_once(response).await;
+    let err = Client::with_base_url(base_url)
+        .thermal_conductivity_for_unidirectional_composite(&THERMAL_CONDUCTIVITY_ARGS)
+        .await
+        .unwrap_err();
+    assert_eq!(err.code(), Some(ErrorCode::UnknownModel), "{err}");
+
+    let base_url = serve_once(
+        "HTTP/1.1 502 Bad Gateway\r\ncontent-type: text/plain\r\ncontent-length: 3\r\nconnection: close\r\n\r\nbad"
+            .to_owned(),
+    )
+    .await;
+    let err = Client::with_base_url(base_url)
+        .thermal_conductivity_for_unidirectional_composite(&THERMAL_CONDUCTIVITY_ARGS)
+        .await
+        .unwrap_err();
+    assert!(
+        matches!(&err, Error::Status { status: 502, body } if body == "bad"),
+        "{err}"
+    );
+}
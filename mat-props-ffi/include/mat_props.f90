@@ -0,0 +1,162 @@
+! The Fortran interface to the C ABI of mat-props, see mat_props.h.
+! The types are named as the C structs without the `MatProps` prefix.
+!
+! Link with libmat_props_ffi.a (and -lm -lpthread -ldl) or libmat_props_ffi.so.
+!
+!   use, intrinsic :: iso_c_binding, only: c_int8_t
+!   use mat_props
+!   type(thermal_conductivity_for_unidirectional_composite_args) :: args
+!   type(thermal_conductivity) :: k
+!   args = thermal_conductivity_for_unidirectional_composite_args(0.2d0, 100d0, 1d0)
+!   if (mat_props_thermal_conductivity_for_unidirectional_composite(2_c_int8_t, args, k) &
+!       /= MAT_PROPS_STATUS_OK) stop 1
+
+module mat_props
+  use, intrinsic :: iso_c_binding, only: c_int, c_int8_t, c_double, c_ptr
+  implicit none
+  private :: c_int, c_int8_t, c_double, c_ptr
+
+  integer(c_int), parameter :: MAT_PROPS_STATUS_OK = 0
+  integer(c_int), parameter :: MAT_PROPS_STATUS_NULL_POINTER = 1
+  integer(c_int), parameter :: MAT_PROPS_STATUS_NUMERICAL_ERROR = 2
+  integer(c_int), parameter :: MAT_PROPS_STATUS_EXPECTED_ARGUMENT_MISSING = 3
+  integer(c_int), parameter :: MAT_PROPS_STATUS_UNKNOWN_MODEL = 4
+  integer(c_int), parameter :: MAT_PROPS_STATUS_WRONG_NUMBER_OF_INPUTS = 5
+  integer(c_int), parameter :: MAT_PROPS_STATUS_INVALID_CORRELATION_MATRIX = 6
+  integer(c_int), parameter :: MAT_PROPS_STATUS_UNKNOWN_INPUT = 7
+  integer(c_int), parameter :: MAT_PROPS_STATUS_UNKNOWN_OUTPUT = 8
+  integer(c_int), parameter :: MAT_PROPS_STATUS_NO_SOLUTION = 9
+  integer(c_int), parameter :: MAT_PROPS_STATUS_NO_FEASIBLE_DESIGN = 10
+  integer(c_int), parameter :: MAT_PROPS_STATUS_BATCH_SIZE_MISMATCH = 11
+  integer(c_int), parameter :: MAT_PROPS_STATUS_UNKNOWN_UNIT_SYSTEM = 12
+  integer(c_int), parameter :: MAT_PROPS_STATUS_UNKNOWN_MATERIAL = 13
+  integer(c_int), parameter :: MAT_PROPS_STATUS_MISSING_MATERIAL_PROPERTY = 14
+  integer(c_int), parameter :: MAT_PROPS_STATUS_INVALID_MATERIAL_LIBRARY = 15
+  integer(c_int), parameter :: MAT_PROPS_STATUS_INVALID_CASE_FILE = 16
+  integer(c_int), parameter :: MAT_PROPS_STATUS_UNKNOWN_FUNCTION = 17
+  integer(c_int), parameter :: MAT_PROPS_STATUS_INVALID_ARGUMENT = 18
+
+  type, bind(c) :: elastic_modules_for_unidirectional_composite_args
+    real(c_double) :: fibre_content
+    real(c_double) :: e_for_fiber
+    real(c_double) :: nu_for_fiber
+    real(c_double) :: e_for_matrix
+    real(c_double) :: nu_for_matrix
+  end type
+
+  type, bind(c) :: elastic_modules_for_honeycomb_args
+    real(c_double) :: l_cell_side_size
+    real(c_double) :: h_cell_side_size
+    real(c_double) :: wall_thickness
+    real(c_double) :: angle
+    real(c_double) :: e_for_honeycomb
+    real(c_double) :: nu_for_honeycomb
+  end type
+
+  type, bind(c) :: thermal_conductivity_for_unidirectional_composite_args
+    real(c_double) :: fibre_content
+    real(c_double) :: k_for_fiber
+    real(c_double) :: k_for_matrix
+  end type
+
+  type, bind(c) :: thermal_expansion_for_unidirectional_composite_args
+    real(c_double) :: fibre_content
+    real(c_double) :: e_for_fiber
+    real(c_double) :: nu_for_fiber
+    real(c_double) :: alpha_for_fiber
+    real(c_double) :: e_for_matrix
+    real(c_double) :: nu_for_matrix
+    real(c_double) :: alpha_for_matrix
+  end type
+
+  type, bind(c) :: thermal_expansion_for_honeycomb_args
+    real(c_double) :: l_cell_side_size
+    real(c_double) :: h_cell_side_size
+    real(c_double) :: wall_thickness
+    real(c_double) :: angle
+    real(c_double) :: alpha_for_honeycomb
+  end type
+
+  type, bind(c) :: elastic_modules
+    real(c_double) :: e1
+    real(c_double) :: e2
+    real(c_double) :: e3
+    real(c_double) :: nu12
+    real(c_double) :: nu13
+    real(c_double) :: nu23
+    real(c_double) :: g12
+    real(c_double) :: g13
+    real(c_double) :: g23
+  end type
+
+  type, bind(c) :: thermal_conductivity
+    real(c_double) :: k1
+    real(c_double) :: k2
+    real(c_double) :: k3
+  end type
+
+  type, bind(c) :: thermal_expansion
+    real(c_double) :: alpha1
+    real(c_double) :: alpha2
+    real(c_double) :: alpha3
+  end type
+
+  interface
+    function mat_props_elastic_modules_for_unidirectional_composite(number_of_model, args, result) &
+        bind(c, name="mat_props_elastic_modules_for_unidirectional_composite")
+      import :: c_int, c_int8_t, elastic_modules_for_unidirectional_composite_args, &
+        elastic_modules
+      integer(c_int8_t), value :: number_of_model
+      type(elastic_modules_for_unidirectional_composite_args), intent(in) :: args
+      type(elastic_modules), intent(out) :: result
+      integer(c_int) :: mat_props_elastic_modules_for_unidirectional_composite
+    end function
+
+    function mat_props_elastic_modules_for_honeycomb(number_of_model, args, result) &
+        bind(c, name="mat_props_elastic_modules_for_honeycomb")
+      import :: c_int, c_int8_t, elastic_modules_for_honeycomb_args, &
+        elastic_modules
+      integer(c_int8_t), value :: number_of_model
+      type(elastic_modules_for_honeycomb_args), intent(in) :: args
+      type(elastic_modules), intent(out) :: result
+      integer(c_int) :: mat_props_elastic_modules_for_honeycomb
+    end function
+
+    function mat_props_thermal_conductivity_for_unidirectional_composite(number_of_model, args, result) &
+        bind(c, name="mat_props_thermal_conductivity_for_unidirectional_composite")
+      import :: c_int, c_int8_t, thermal_conductivity_for_unidirectional_composite_args, &
+        thermal_conductivity
+      integer(c_int8_t), value :: number_of_model
+      type(thermal_conductivity_for_unidirectional_composite_args), intent(in) :: args
+      type(thermal_conductivity), intent(out) :: result
+      integer(c_int) :: mat_props_thermal_conductivity_for_unidirectional_composite
+    end function
+
+    function mat_props_thermal_expansion_for_unidirectional_composite(number_of_model, args, result) &
+        bind(c, name="mat_props_thermal_expansion_for_unidirectional_composite")
+      import :: c_int, c_int8_t, thermal_expansion_for_unidirectional_composite_args, &
+        thermal_expansion
+      integer(c_int8_t), value :: number_of_model
+      type(thermal_expansion_for_unidirectional_composite_args), intent(in) :: args
+      type(thermal_expansion), intent(out) :: result
+      integer(c_int) :: mat_props_thermal_expansion_for_unidirectional_composite
+    end function
+
+    function mat_props_thermal_expansion_for_honeycomb(number_of_model, args, result) &
+        bind(c, name="mat_props_thermal_expansion_for_honeycomb")
+      import :: c_int, c_int8_t, thermal_expansion_for_honeycomb_args, &
+        thermal_expansion
+      integer(c_int8_t), value :: number_of_model
+      type(thermal_expansion_for_honeycomb_args), intent(in) :: args
+      type(thermal_expansion), intent(out) :: result
+      integer(c_int) :: mat_props_thermal_expansion_for_honeycomb
+    end function
+
+    ! returns the pointer to the static null-terminated description of the status
+    function mat_props_status_message(status) bind(c, name="mat_props_status_message")
+      import :: c_int, c_ptr
+      integer(c_int), value :: status
+      type(c_ptr) :: mat_props_status_message
+    end function
+  end interface
+end module mat_props
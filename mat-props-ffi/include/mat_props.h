@@ -0,0 +1,176 @@
+#ifndef MAT_PROPS_H
+#define MAT_PROPS_H
+
+/* Generated by cbindgen from mat-props-ffi/src/lib.rs, don't edit manually. */
+
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stdint.h>
+#include <stdlib.h>
+
+// The status of a call, `MAT_PROPS_STATUS_OK` or the kind of the error.
+//
+// The values are stable, the new kinds of errors get new values.
+typedef enum MatPropsStatus {
+  MAT_PROPS_STATUS_OK = 0,
+  MAT_PROPS_STATUS_NULL_POINTER = 1,
+  MAT_PROPS_STATUS_NUMERICAL_ERROR = 2,
+  MAT_PROPS_STATUS_EXPECTED_ARGUMENT_MISSING = 3,
+  MAT_PROPS_STATUS_UNKNOWN_MODEL = 4,
+  MAT_PROPS_STATUS_WRONG_NUMBER_OF_INPUTS = 5,
+  MAT_PROPS_STATUS_INVALID_CORRELATION_MATRIX = 6,
+  MAT_PROPS_STATUS_UNKNOWN_INPUT = 7,
+  MAT_PROPS_STATUS_UNKNOWN_OUTPUT = 8,
+  MAT_PROPS_STATUS_NO_SOLUTION = 9,
+  MAT_PROPS_STATUS_NO_FEASIBLE_DESIGN = 10,
+  MAT_PROPS_STATUS_BATCH_SIZE_MISMATCH = 11,
+  MAT_PROPS_STATUS_UNKNOWN_UNIT_SYSTEM = 12,
+  MAT_PROPS_STATUS_UNKNOWN_MATERIAL = 13,
+  MAT_PROPS_STATUS_MISSING_MATERIAL_PROPERTY = 14,
+  MAT_PROPS_STATUS_INVALID_MATERIAL_LIBRARY = 15,
+  MAT_PROPS_STATUS_INVALID_CASE_FILE = 16,
+  MAT_PROPS_STATUS_UNKNOWN_FUNCTION = 17,
+  MAT_PROPS_STATUS_INVALID_ARGUMENT = 18,
+} MatPropsStatus;
+
+// Inputs of `mat_props_elastic_modules_for_unidirectional_composite`.
+typedef struct MatPropsElasticModulesForUnidirectionalCompositeArgs {
+  double fibre_content;
+  double e_for_fiber;
+  double nu_for_fiber;
+  double e_for_matrix;
+  double nu_for_matrix;
+} MatPropsElasticModulesForUnidirectionalCompositeArgs;
+
+// Elastic modules of an orthotropic material.
+typedef struct MatPropsElasticModules {
+  double e1;
+  double e2;
+  double e3;
+  double nu12;
+  double nu13;
+  double nu23;
+  double g12;
+  double g13;
+  double g23;
+} MatPropsElasticModules;
+
+// Inputs of `mat_props_elastic_modules_for_honeycomb`.
+typedef struct MatPropsElasticModulesForHoneycombArgs {
+  double l_cell_side_size;
+  double h_cell_side_size;
+  double wall_thickness;
+  double angle;
+  double e_for_honeycomb;
+  double nu_for_honeycomb;
+} MatPropsElasticModulesForHoneycombArgs;
+
+// Inputs of `mat_props_thermal_conductivity_for_unidirectional_composite`.
+typedef struct MatPropsThermalConductivityForUnidirectionalCompositeArgs {
+  double fibre_content;
+  double k_for_fiber;
+  double k_for_matrix;
+} MatPropsThermalConductivityForUnidirectionalCompositeArgs;
+
+// Thermal conductivity in the three primary directions.
+typedef struct MatPropsThermalConductivity {
+  double k1;
+  double k2;
+  double k3;
+} MatPropsThermalConductivity;
+
+// Inputs of `mat_props_thermal_expansion_for_unidirectional_composite`.
+typedef struct MatPropsThermalExpansionForUnidirectionalCompositeArgs {
+  double fibre_content;
+  double e_for_fiber;
+  double nu_for_fiber;
+  double alpha_for_fiber;
+  double e_for_matrix;
+  double nu_for_matrix;
+  double alpha_for_matrix;
+} MatPropsThermalExpansionForUnidirectionalCompositeArgs;
+
+// Coefficients of thermal expansion in the three primary directions.
+typedef struct MatPropsThermalExpansion {
+  double alpha1;
+  double alpha2;
+  double alpha3;
+} MatPropsThermalExpansion;
+
+// Inputs of `mat_props_thermal_expansion_for_honeycomb`.
+typedef struct MatPropsThermalExpansionForHoneycombArgs {
+  double l_cell_side_size;
+  double h_cell_side_size;
+  double wall_thickness;
+  double angle;
+  double alpha_for_honeycomb;
+} MatPropsThermalExpansionForHoneycombArgs;
+
+#ifdef __cplusplus
+extern "C" {
+#endif // __cplusplus
+
+// Computes elastic modules for unidirectional composite.
+//
+// `number_of_model` is 1 for the rule of mixtures and 2 for Vanin's model.
+//
+// ## Safety
+//
+// `args` and `result` must be null or point to valid structs.
+enum MatPropsStatus mat_props_elastic_modules_for_unidirectional_composite(uint8_t number_of_model,
+                                                                           const struct MatPropsElasticModulesForUnidirectionalCompositeArgs *args,
+                                                                           struct MatPropsElasticModules *result);
+
+// Computes elastic modules for honeycomb.
+//
+// `number_of_model` is 1 for Vanin's model.
+//
+// ## Safety
+//
+// `args` and `result` must be null or point to valid structs.
+enum MatPropsStatus mat_props_elastic_modules_for_honeycomb(uint8_t number_of_model,
+                                                            const struct MatPropsElasticModulesForHoneycombArgs *args,
+                                                            struct MatPropsElasticModules *result);
+
+// Computes thermal conductivity for unidirectional composite.
+//
+// `number_of_model` is 1 for the rule of mixtures and 2 for Vanin's model.
+//
+// ## Safety
+//
+// `args` and `result` must be null or point to valid structs.
+enum MatPropsStatus mat_props_thermal_conductivity_for_unidirectional_composite(uint8_t number_of_model,
+                                                                                const struct MatPropsThermalConductivityForUnidirectionalCompositeArgs *args,
+                                                                                struct MatPropsThermalConductivity *result);
+
+// Computes coefficients of thermal expansion for unidirectional composite.
+//
+// `number_of_model` is 1 for Vanin's model.
+//
+// ## Safety
+//
+// `args` and `result` must be null or point to valid structs.
+enum MatPropsStatus mat_props_thermal_expansion_for_unidirectional_composite(uint8_t number_of_model,
+                                                                             const struct MatPropsThermalExpansionForUnidirectionalCompositeArgs *args,
+                                                                             struct MatPropsThermalExpansion *result);
+
+// Computes coefficients of thermal expansion for honeycomb.
+//
+// `number_of_model` is 1 for Vanin's model.
+//
+// ## Safety
+//
+// `args` and `result` must be null or point to valid structs.
+enum MatPropsStatus mat_props_thermal_expansion_for_honeycomb(uint8_t number_of_model,
+                                                              const struct MatPropsThermalExpansionForHoneycombArgs *args,
+                                                              struct MatPropsThermalExpansion *result);
+
+// Returns the static null-terminated description of the status, `Unknown status` if the value
+// is none of the statuses.
+const char *mat_props_status_message(int status);
+
+#ifdef __cplusplus
+}  // extern "C"
+#endif  // __cplusplus
+
+#endif  /* MAT_PROPS_H */
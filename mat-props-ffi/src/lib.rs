@@ -0,0 +1,406 @@
+//! The C ABI of `mat-props`.
+//!
+//! Every model is a function that takes the number of the model, a pointer to the plain struct
+//! of the inputs and a pointer to the plain struct of the outputs, and returns [`Status`].
+//! The inputs are in consistent units with the angles in radians, as in `mat-props`.
+//!
+//! The header `include/mat_props.h` is generated by cbindgen on build and
+//! `include/mat_props.f90` is the Fortran interface to the same functions.
+
+use core::ffi::{c_char, c_int};
+
+/// The status of a call, `MAT_PROPS_STATUS_OK` or the kind of the error.
+///
+/// The values are stable, the new kinds of errors get new values.
+#[repr(C)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Status {
+    Ok = 0,
+    NullPointer = 1,
+    NumericalError = 2,
+    ExpectedArgumentMissing = 3,
+    UnknownModel = 4,
+    WrongNumberOfInputs = 5,
+    InvalidCorrelationMatrix = 6,
+    UnknownInput = 7,
+    UnknownOutput = 8,
+    NoSolution = 9,
+    NoFeasibleDesign = 10,
+    BatchSizeMismatch = 11,
+    UnknownUnitSystem = 12,
+    UnknownMaterial = 13,
+    MissingMaterialProperty = 14,
+    InvalidMaterialLibrary = 15,
+    InvalidCaseFile = 16,
+    UnknownFunction = 17,
+    InvalidArgument = 18,
+}
+
+impl From<&mat_props::Error> for Status {
+    fn from(err: &mat_props::Error) -> Self {
+        use mat_props::Error;
+        match err {
+            Error::NumericalError(_) => Self::NumericalError,
+            Error::ExpectedArgumentMissing(_) => Self::ExpectedArgumentMissing,
+            Error::UnknownModel => Self::UnknownModel,
+            Error::WrongNumberOfInputs { .. } => Self::WrongNumberOfInputs,
+            Error::InvalidCorrelationMatrix => Self::InvalidCorrelationMatrix,
+            Error::UnknownInput(_) => Self::UnknownInput,
+            Error::UnknownOutput(_) => Self::UnknownOutput,
+            Error::NoSolution { .. } => Self::NoSolution,
+            Error::NoFeasibleDesign => Self::NoFeasibleDesign,
+            Error::BatchSizeMismatch { .. } => Self::BatchSizeMismatch,
+            Error::UnknownUnitSystem => Self::UnknownUnitSystem,
+            Error::UnknownMaterial(_) => Self::UnknownMaterial,
+            Error::MissingMaterialProperty { .. } => Self::MissingMaterialProperty,
+            Error::InvalidMaterialLibrary(_) => Self::InvalidMaterialLibrary,
+            Error::InvalidCaseFile(_) => Self::InvalidCaseFile,
+            Error::UnknownFunction(_) => Self::UnknownFunction,
+            Error::InvalidArgument { .. } => Self::InvalidArgument,
+        }
+    }
+}
+
+/// Inputs of `mat_props_elastic_modules_for_unidirectional_composite`.
+#[repr(C)]
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct ElasticModulesForUnidirectionalCompositeArgs {
+    pub fibre_content: f64,
+    pub e_for_fiber: f64,
+    pub nu_for_fiber: f64,
+    pub e_for_matrix: f64,
+    pub nu_for_matrix: f64,
+}
+
+/// Inputs of `mat_props_elastic_modules_for_honeycomb`.
+#[repr(C)]
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct ElasticModulesForHoneycombArgs {
+    pub l_cell_side_size: f64,
+    pub h_cell_side_size: f64,
+    pub wall_thickness: f64,
+    pub angle: f64,
+    pub e_for_honeycomb: f64,
+    pub nu_for_honeycomb: f64,
+}
+
+/// Inputs of `mat_props_thermal_conductivity_for_unidirectional_composite`.
+#[repr(C)]
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct ThermalConductivityForUnidirectionalCompositeArgs {
+    pub fibre_content: f64,
+    pub k_for_fiber: f64,
+    pub k_for_matrix: f64,
+}
+
+/// Inputs of `mat_props_thermal_expansion_for_unidirectional_composite`.
+#[repr(C)]
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct ThermalExpansionForUnidirectionalCompositeArgs {
+    pub fibre_content: f64,
+    pub e_for_fiber: f64,
+    pub nu_for_fiber: f64,
+    pub alpha_for_fiber: f64,
+    pub e_for_matrix: f64,
+    pub nu_for_matrix: f64,
+    pub alpha_for_matrix: f64,
+}
+
+/// Inputs of `mat_props_thermal_expansion_for_honeycomb`.
+#[repr(C)]
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct ThermalExpansionForHoneycombArgs {
+    pub l_cell_side_size: f64,
+    pub h_cell_side_size: f64,
+    pub wall_thickness: f64,
+    pub angle: f64,
+    pub alpha_for_honeycomb: f64,
+}
+
+/// Elastic modules of an orthotropic material.
+#[repr(C)]
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct ElasticModules {
+    pub e1: f64,
+    pub e2: f64,
+    pub e3: f64,
+    pub nu12: f64,
+    pub nu13: f64,
+    pub nu23: f64,
+    pub g12: f64,
+    pub g13: f64,
+    pub g23: f64,
+}
+
+/// Thermal conductivity in the three primary directions.
+#[repr(C)]
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct ThermalConductivity {
+    pub k1: f64,
+    pub k2: f64,
+    pub k3: f64,
+}
+
+/// Coefficients of thermal expansion in the three primary directions.
+#[repr(C)]
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct ThermalExpansion {
+    pub alpha1: f64,
+    pub alpha2: f64,
+    pub alpha3: f64,
+}
+
+/// Calls `f` with the dereferenced pointers and writes its result if it succeeds.
+///
+/// ## Safety
+///
+/// `args` and `result` must be null or valid for reads and writes respectively.
+unsafe fn call<A: Copy, R>(
+    args: *const A,
+    result: *mut R,
+    f: impl FnOnce(A) -> mat_props::Result<R>,
+) -> Status {
+    if args.is_null() || result.is_null() {
+        return Status::NullPointer;
+    }
+    match f(args.read()) {
+        Ok(res) => {
+            result.write(res);
+            Status::Ok
+        }
+        Err(err) => Status::from(&err),
+    }
+}
+
+/// Computes elastic modules for unidirectional composite.
+///
+/// `number_of_model` is 1 for the rule of mixtures and 2 for Vanin's model.
+///
+/// ## Safety
+///
+/// `args` and `result` must be null or point to valid structs.
+#[no_mangle]
+pub unsafe extern "C" fn mat_props_elastic_modules_for_unidirectional_composite(
+    number_of_model: u8,
+    args: *const ElasticModulesForUnidirectionalCompositeArgs,
+    result: *mut ElasticModules,
+) -> Status {
+    call(args, result, |args| {
+        let [e1, e2, e3, nu12, nu13, nu23, g12, g13, g23] =
+            mat_props::elastic_modules_for_unidirectional_composite(
+                number_of_model,
+                args.fibre_content,
+                args.e_for_fiber,
+                args.nu_for_fiber,
+                args.e_for_matrix,
+                args.nu_for_matrix,
+            )?;
+        Ok(ElasticModules {
+            e1,
+            e2,
+            e3,
+            nu12,
+            nu13,
+            nu23,
+            g12,
+            g13,
+            g23,
+        })
+    })
+}
+
+/// Computes elastic modules for honeycomb.
+///
+/// `number_of_model` is 1 for Vanin's model.
+///
+/// ## Safety
+///
+/// `args` and `result` must be null or point to valid structs.
+#[no_mangle]
+pub unsafe extern "C" fn mat_props_elastic_modules_for_honeycomb(
+    number_of_model: u8,
+    args: *const ElasticModulesForHoneycombArgs,
+    result: *mut ElasticModules,
+) -> Status {
+    call(args, result, |args| {
+        let [e1, e2, e3, nu12, nu13, nu23, g12, g13, g23] =
+            mat_props::elastic_modules_for_honeycomb(
+                number_of_model,
+                args.l_cell_side_size,
+                args.h_cell_side_size,
+                args.wall_thickness,
+                args.angle,
+                args.e_for_honeycomb,
+                args.nu_for_honeycomb,
+            )?;
+        Ok(ElasticModules {
+            e1,
+            e2,
+            e3,
+            nu12,
+            nu13,
+            nu23,
+            g12,
+            g13,
+            g23,
+        })
+    })
+}
+
+/// Computes thermal conductivity for unidirectional composite.
+///
+/// `number_of_model` is 1 for the rule of mixtures and 2 for Vanin's model.
+///
+/// ## Safety
+///
+/// `args` and `result` must be null or point to valid structs.
+#[no_mangle]
+pub unsafe extern "C" fn mat_props_thermal_conductivity_for_unidirectional_composite(
+    number_of_model: u8,
+    args: *const ThermalConductivityForUnidirectionalCompositeArgs,
+    result: *mut ThermalConductivity,
+) -> Status {
+    call(args, result, |args| {
+        let [k1, k2, k3] = mat_props::thermal_conductivity_for_unidirectional_composite(
+            number_of_model,
+            args.fibre_content,
+            args.k_for_fiber,
+            args.k_for_matrix,
+        )?;
+        Ok(ThermalConductivity { k1, k2, k3 })
+    })
+}
+
+/// Computes coefficients of thermal expansion for unidirectional composite.
+///
+/// `number_of_model` is 1 for Vanin's model.
+///
+/// ## Safety
+///
+/// `args` and `result` must be null or point to valid structs.
+#[no_mangle]
+pub unsafe extern "C" fn mat_props_thermal_expansion_for_unidirectional_composite(
+    number_of_model: u8,
+    args: *const ThermalExpansionForUnidirectionalCompositeArgs,
+    result: *mut ThermalExpansion,
+) -> Status {
+    call(args, result, |args| {
+        let [alpha1, alpha2, alpha3] = mat_props::thermal_expansion_for_unidirectional_composite(
+            number_of_model,
+            args.fibre_content,
+            args.e_for_fiber,
+            args.nu_for_fiber,
+            args.alpha_for_fiber,
+            args.e_for_matrix,
+            args.nu_for_matrix,
+            args.alpha_for_matrix,
+        )?;
+        Ok(ThermalExpansion {
+            alpha1,
+            alpha2,
+            alpha3,
+        })
+    })
+}
+
+/// Computes coefficients of thermal expansion for honeycomb.
+///
+/// `number_of_model` is 1 for Vanin's model.
+///
+/// ## Safety
+///
+/// `args` and `result` must be null or point to valid structs.
+#[no_mangle]
+pub unsafe extern "C" fn mat_props_thermal_expansion_for_honeycomb(
+    number_of_model: u8,
+    args: *const ThermalExpansionForHoneycombArgs,
+    result: *mut ThermalExpansion,
+) -> Status {
+    call(args, result, |args| {
+        let [alpha1, alpha2, alpha3] = mat_props::thermal_expansion_for_honeycomb(
+            number_of_model,
+            args.l_cell_side_size,
+            args.h_cell_side_size,
+            args.wall_thickness,
+            args.angle,
+            args.alpha_for_honeycomb,
+        )?;
+        Ok(ThermalExpansion {
+            alpha1,
+            alpha2,
+            alpha3,
+        })
+    })
+}
+
+/// Returns the static null-terminated description of the status, `Unknown status` if the value
+/// is none of the statuses.
+#[no_mangle]
+pub extern "C" fn mat_props_status_message(status: c_int) -> *const c_char {
+    // the value comes from C or Fortran, so it isn't trusted to be a `Status`
+    let message: &'static core::ffi::CStr = match status {
+        s if s == Status::Ok as c_int => c"Ok",
+        s if s == Status::NullPointer as c_int => c"Null pointer",
+        s if s == Status::NumericalError as c_int => c"Numerical error",
+        s if s == Status::ExpectedArgumentMissing as c_int => c"Expected argument is missing",
+        s if s == Status::UnknownModel as c_int => c"Unknown model",
+        s if s == Status::WrongNumberOfInputs as c_int => c"Wrong number of inputs",
+        s if s == Status::InvalidCorrelationMatrix as c_int => c"Invalid correlation matrix",
+        s if s == Status::UnknownInput as c_int => c"Unknown input",
+        s if s == Status::UnknownOutput as c_int => c"Unknown output",
+        s if s == Status::NoSolution as c_int => c"No solution",
+        s if s == Status::NoFeasibleDesign as c_int => c"No feasible design",
+        s if s == Status::BatchSizeMismatch as c_int => c"Batch size mismatch",
+        s if s == Status::UnknownUnitSystem as c_int => c"Unknown unit system",
+        s if s == Status::UnknownMaterial as c_int => c"Unknown material",
+        s if s == Status::MissingMaterialProperty as c_int => c"Missing material property",
+        s if s == Status::InvalidMaterialLibrary as c_int => c"Invalid material library",
+        s if s == Status::InvalidCaseFile as c_int => c"Invalid case file",
+        s if s == Status::UnknownFunction as c_int => c"Unknown function",
+        s if s == Status::InvalidArgument as c_int => c"Invalid argument",
+        _ => c"Unknown status",
+    };
+    message.as_ptr()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test() {
+        let args = ThermalConductivityForUnidirectionalCompositeArgs {
+            fibre_content: 0.2,
+            k_for_fiber: 100.0,
+            k_for_matrix: 1.0,
+        };
+        let mut result = ThermalConductivity::default();
+        let status = unsafe {
+            mat_props_thermal_conductivity_for_unidirectional_composite(2, &args, &mut result)
+        };
+        assert_eq!(status, Status::Ok);
+        assert_eq!(result.k1, 20.8);
+
+        let status = unsafe {
+            mat_props_thermal_conductivity_for_unidirectional_composite(42, &args, &mut result)
+        };
+        assert_eq!(status, Status::UnknownModel);
+        let status = unsafe {
+            mat_props_thermal_conductivity_for_unidirectional_composite(
+                2,
+                &args,
+                core::ptr::null_mut(),
+            )
+        };
+        assert_eq!(status, Status::NullPointer);
+
+        let message =
+            |status| unsafe { core::ffi::CStr::from_ptr(mat_props_status_message(status)) };
+        assert_eq!(
+            message(Status::InvalidArgument as c_int),
+            c"Invalid argument"
+        );
+        assert_eq!(message(42), c"Unknown status");
+        assert_eq!(message(-1), c"Unknown status");
+    }
+}
@@ -0,0 +1,30 @@
+use std::{path::Path, process::Command};
+
+/// Compiles `tests/reference.c` against the static library and runs it.
+#[test]
+fn reference() {
+    let manifest_dir = Path::new(env!("CARGO_MANIFEST_DIR"));
+    // target/<profile>/deps/c-<hash>
+    let exe = std::env::current_exe().unwrap();
+    let profile_dir = exe.parent().unwrap().parent().unwrap();
+    let out = Path::new(env!("CARGO_TARGET_TMPDIR")).join("reference");
+
+    let cc = std::env::var("CC").unwrap_or_else(|_| "cc".to_owned());
+    let status = Command::new(cc)
+        .arg(manifest_dir.join("tests/reference.c"))
+        .arg("-I")
+        .arg(manifest_dir.join("include"))
+        .arg(profile_dir.join("libmat_props_ffi.a"))
+        .args(["-lm", "-lpthread", "-ldl", "-o"])
+        .arg(&out)
+        .status()
+        .unwrap();
+    assert!(status.success());
+
+    let output = Command::new(&out).output().unwrap();
+    assert!(
+        output.status.success(),
+        "{}",
+        String::from_utf8_lossy(&output.stdout)
+    );
+}
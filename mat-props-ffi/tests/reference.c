@@ -0,0 +1,84 @@
+/* The same reference values as the unit tests of mat-props. */
+
+#include <math.h>
+#include <stdio.h>
+#include <string.h>
+
+#include "mat_props.h"
+
+static int failures = 0;
+
+static void check(const char *name, double actual, double expected) {
+  if (actual != expected) {
+    printf("%s: %.17g != %.17g\n", name, actual, expected);
+    failures++;
+  }
+}
+
+static void check_status(const char *name, MatPropsStatus actual, MatPropsStatus expected) {
+  if (actual != expected) {
+    printf("%s: %s != %s\n", name, mat_props_status_message(actual),
+           mat_props_status_message(expected));
+    failures++;
+  }
+}
+
+int main(void) {
+  MatPropsElasticModules modules;
+  MatPropsThermalConductivity k;
+  MatPropsThermalExpansion alpha;
+
+  MatPropsElasticModulesForUnidirectionalCompositeArgs ud = {0.2, 100.0, 0.3, 5.0, 0.2};
+  check_status("elastic ud", mat_props_elastic_modules_for_unidirectional_composite(2, &ud, &modules),
+               MAT_PROPS_STATUS_OK);
+  check("e1", modules.e1, 24.011723329425557);
+  check("e2", modules.e2, 6.5683701067350135);
+  check("nu12", modules.nu12, 0.06240625050144681);
+  check("nu23", modules.nu23, 0.18585515203940609);
+  check("g12", modules.g12, 2.9945407835581253);
+  check("g23", modules.g23, 2.769465602708258);
+
+  MatPropsElasticModulesForHoneycombArgs honeycomb = {9.24, 8.4619, 0.4, M_PI / 6.0, 7.07, 0.2};
+  check_status("elastic honeycomb", mat_props_elastic_modules_for_honeycomb(1, &honeycomb, &modules),
+               MAT_PROPS_STATUS_OK);
+  check("e1", modules.e1, 0.0014972693834675922);
+  check("e3", modules.e3, 0.3592394105863781);
+  check("nu12", modules.nu12, 1.0512175946777975);
+  check("g23", modules.g23, 0.0755763830773748);
+
+  MatPropsThermalConductivityForUnidirectionalCompositeArgs conductivity = {0.2, 100.0, 1.0};
+  check_status("conductivity",
+               mat_props_thermal_conductivity_for_unidirectional_composite(2, &conductivity, &k),
+               MAT_PROPS_STATUS_OK);
+  check("k1", k.k1, 20.8);
+  check("k2", k.k2, 1.3300670235932428);
+
+  MatPropsThermalExpansionForUnidirectionalCompositeArgs expansion = {0.2, 100.0, 0.3, 1e-6,
+                                                                      5.0, 0.2,   20e-5};
+  check_status("expansion ud",
+               mat_props_thermal_expansion_for_unidirectional_composite(1, &expansion, &alpha),
+               MAT_PROPS_STATUS_OK);
+  check("alpha1", alpha.alpha1, 0.00003303092919697953);
+  check("alpha2", alpha.alpha2, 0.0001653038466333737);
+
+  MatPropsThermalExpansionForHoneycombArgs honeycomb_expansion = {9.24, 8.4619, 0.4, M_PI / 6.0,
+                                                                  20e-5};
+  check_status("expansion honeycomb",
+               mat_props_thermal_expansion_for_honeycomb(1, &honeycomb_expansion, &alpha),
+               MAT_PROPS_STATUS_OK);
+  check("alpha1", alpha.alpha1, 0.0002);
+  check("alpha2", alpha.alpha2, 0.00019999999999999966);
+
+  check_status("unknown model",
+               mat_props_thermal_conductivity_for_unidirectional_composite(42, &conductivity, &k),
+               MAT_PROPS_STATUS_UNKNOWN_MODEL);
+  check_status("null pointer",
+               mat_props_thermal_conductivity_for_unidirectional_composite(2, NULL, &k),
+               MAT_PROPS_STATUS_NULL_POINTER);
+  if (strcmp(mat_props_status_message(1000), "Unknown status") != 0) {
+    printf("unknown status: %s\n", mat_props_status_message(1000));
+    failures++;
+  }
+
+  return failures == 0 ? 0 : 1;
+}
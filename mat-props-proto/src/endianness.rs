@@ -0,0 +1,38 @@
+/// The endianness of a message, its first byte: 0 for little endian, 1 for big endian.
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+#[repr(u8)]
+pub enum Endianness {
+    Little,
+    Big,
+}
+
+impl Endianness {
+    pub const NATIVE: Self = if cfg!(target_endian = "little") {
+        Self::Little
+    } else {
+        Self::Big
+    };
+
+    pub fn try_from_u8(n: u8) -> Option<Self> {
+        match n {
+            0 => Some(Self::Little),
+            1 => Some(Self::Big),
+            _ => None,
+        }
+    }
+
+    pub(crate) fn f64_to_bytes(self, value: f64) -> [u8; 8] {
+        match self {
+            Self::Little => value.to_le_bytes(),
+            Self::Big => value.to_be_bytes(),
+        }
+    }
+
+    pub(crate) fn f64_from_bytes(self, bytes: &[u8]) -> f64 {
+        let bytes = bytes.try_into().unwrap();
+        match self {
+            Self::Little => f64::from_le_bytes(bytes),
+            Self::Big => f64::from_be_bytes(bytes),
+        }
+    }
+}
@@ -0,0 +1,198 @@
+pub const ERROR_CONTENT_TYPE: &str = "application/problem+json";
+pub const ERROR_MESSAGE_CONTENT_TYPE: &str = "application/x.error-message";
+
+/// The machine-readable kind of the error. The binary error message carries its number.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "snake_case"))]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
+#[repr(u16)]
+pub enum ErrorCode {
+    InvalidEndianness = 1,
+    UnknownModel = 2,
+    UnknownUnitSystem = 3,
+    MissingArgument = 4,
+    NonFiniteInput = 5,
+    NonFiniteOutput = 6,
+    WrongNumberOfInputs = 7,
+    UnknownInput = 8,
+    UnknownOutput = 9,
+    InvalidCorrelationMatrix = 10,
+    NoSolution = 11,
+    NoFeasibleDesign = 12,
+    UnknownFunction = 13,
+    UnknownMaterial = 14,
+    MissingMaterialProperty = 15,
+    NumericalError = 16,
+    Internal = 17,
+    UnsupportedContentType = 18,
+    InvalidLength = 19,
+    PayloadTooLarge = 20,
+    InvalidJson = 21,
+    InvalidMessagePack = 22,
+    InvalidCbor = 23,
+    InvalidHeader = 24,
+    UnsupportedVersion = 25,
+    MessageIdMismatch = 26,
+    UnknownMessageId = 27,
+    InvalidArgument = 28,
+}
+
+/// The problem details (RFC 9457) of a failed request.
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
+#[cfg_attr(feature = "utoipa", schema(example = json!({
+    "type": "about:blank",
+    "title": "Bad Request",
+    "status": 400,
+    "detail": "Unknown model",
+    "code": "unknown_model",
+    "field": "number_of_model",
+})))]
+pub struct ErrorMessage {
+    #[cfg_attr(feature = "serde", serde(rename = "type"))]
+    pub type_: String,
+    /// The reason phrase of the status.
+    pub title: String,
+    pub status: u16,
+    /// The human-readable explanation of the error.
+    pub detail: String,
+    pub code: ErrorCode,
+    /// The name of the field of the request that caused the error, if any.
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
+    pub field: Option<String>,
+}
+
+/// The reason phrases of the statuses of the backend.
+fn reason_phrase(status: u16) -> &'static str {
+    match status {
+        400 => "Bad Request",
+        404 => "Not Found",
+        413 => "Payload Too Large",
+        415 => "Unsupported Media Type",
+        422 => "Unprocessable Entity",
+        500 => "Internal Server Error",
+        _ => "",
+    }
+}
+
+impl ErrorMessage {
+    pub fn new(
+        status: u16,
+        code: ErrorCode,
+        field: Option<&str>,
+        detail: impl Into<String>,
+    ) -> Self {
+        Self {
+            type_: "about:blank".to_owned(),
+            title: reason_phrase(status).to_owned(),
+            status,
+            detail: detail.into(),
+            code,
+            field: field.map(str::to_owned),
+        }
+    }
+
+    pub fn invalid_endianness() -> Self {
+        Self::new(
+            400,
+            ErrorCode::InvalidEndianness,
+            Some("endianness"),
+            "Invalid endianness",
+        )
+    }
+
+    pub fn non_finite_input(field: &str) -> Self {
+        Self::new(
+            400,
+            ErrorCode::NonFiniteInput,
+            Some(field),
+            format!("The input `{field}` is not finite"),
+        )
+    }
+
+    pub fn non_finite_output(field: &str) -> Self {
+        Self::new(
+            422,
+            ErrorCode::NonFiniteOutput,
+            None,
+            format!(
+                "The output `{field}` is infinite, the inputs are out of the range of the model"
+            ),
+        )
+    }
+
+    pub fn invalid_length(expected: usize, actual: usize) -> Self {
+        Self::new(
+            400,
+            ErrorCode::InvalidLength,
+            None,
+            format!("Expected the payload of {expected} bytes but got {actual} bytes"),
+        )
+    }
+}
+
+impl std::fmt::Display for ErrorMessage {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&self.detail)
+    }
+}
+
+impl std::error::Error for ErrorMessage {}
+
+impl From<mat_props::Error> for ErrorMessage {
+    fn from(e: mat_props::Error) -> Self {
+        use mat_props::Error;
+
+        let detail = e.to_string();
+        let (status, code, field) = match &e {
+            Error::NumericalError(_) => (500, ErrorCode::NumericalError, None),
+            Error::ExpectedArgumentMissing(name) => (400, ErrorCode::MissingArgument, Some(*name)),
+            Error::UnknownModel => (400, ErrorCode::UnknownModel, Some("number_of_model")),
+            Error::WrongNumberOfInputs { .. } => {
+                (400, ErrorCode::WrongNumberOfInputs, Some("inputs"))
+            }
+            Error::InvalidCorrelationMatrix => (400, ErrorCode::InvalidCorrelationMatrix, None),
+            Error::UnknownInput(name) => (400, ErrorCode::UnknownInput, Some(name.as_str())),
+            Error::UnknownOutput(name) => (400, ErrorCode::UnknownOutput, Some(name.as_str())),
+            Error::NoSolution { output, .. } => (422, ErrorCode::NoSolution, Some(output.as_str())),
+            Error::NoFeasibleDesign => (422, ErrorCode::NoFeasibleDesign, None),
+            Error::UnknownUnitSystem => (400, ErrorCode::UnknownUnitSystem, Some("unit_system")),
+            Error::UnknownMaterial(_) => (404, ErrorCode::UnknownMaterial, None),
+            Error::MissingMaterialProperty { property, .. } => {
+                (422, ErrorCode::MissingMaterialProperty, Some(*property))
+            }
+            Error::UnknownFunction(_) => (404, ErrorCode::UnknownFunction, None),
+            Error::InvalidArgument { name, .. } => (400, ErrorCode::InvalidArgument, Some(*name)),
+            // the backend computes in batches, loads the library and reads no case files
+            // on its own, so these are its faults
+            Error::BatchSizeMismatch { .. }
+            | Error::InvalidMaterialLibrary(_)
+            | Error::InvalidCaseFile(_) => (500, ErrorCode::Internal, None),
+        };
+        Self::new(status, code, field, detail)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn client_errors_are_4xx_and_name_the_field() {
+        let err = ErrorMessage::from(mat_props::Error::UnknownModel);
+        assert_eq!((err.status, err.title.as_str()), (400, "Bad Request"));
+        assert_eq!(err.field.as_deref(), Some("number_of_model"));
+
+        let err = ErrorMessage::from(mat_props::Error::ExpectedArgumentMissing("angle"));
+        assert_eq!(err.status, 400);
+        assert_eq!(err.field.as_deref(), Some("angle"));
+
+        let err = ErrorMessage::from(mat_props::Error::NumericalError(Box::new("overflow")));
+        assert_eq!(err.status, 500);
+    }
+}
@@ -0,0 +1,147 @@
+use crate::Endianness;
+
+/// The first bytes of the header. The first byte is neither 0 nor 1, so it can't be mistaken
+/// for the endianness of a message without the header.
+pub const MAGIC: [u8; 4] = *b"MATP";
+pub const HEADER_SIZE: usize = 16;
+/// See <https://docs.python.org/3/library/struct.html#format-strings>.
+pub const HEADER_PY_STRUCT_FORMAT_STRING: &str = "4sBBHIxxxx";
+
+/// The version of the binary protocol.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ProtocolVersion {
+    /// The bare message, as sent by the clients that predate the header.
+    V1 = 1,
+    /// The message preceded by the [`Header`].
+    V2 = 2,
+}
+
+/// The header of the messages of [`ProtocolVersion::V2`]: the magic, the version, the endianness
+/// of the header, the id of the message and the length of the message that follows.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Header {
+    pub version: u8,
+    pub endianness: Endianness,
+    pub message_id: u16,
+    pub payload_length: u32,
+}
+
+/// The reason why the bytes aren't a [`Header`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum HeaderError {
+    InvalidMagic,
+    InvalidEndianness(u8),
+}
+
+impl std::fmt::Display for HeaderError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::InvalidMagic => f.write_str("Invalid magic"),
+            Self::InvalidEndianness(n) => write!(f, "Invalid endianness {n}"),
+        }
+    }
+}
+
+impl std::error::Error for HeaderError {}
+
+impl Header {
+    pub fn new(endianness: Endianness, message_id: u16, payload_length: usize) -> Self {
+        Self {
+            version: ProtocolVersion::V2 as u8,
+            endianness,
+            message_id,
+            payload_length: payload_length as u32,
+        }
+    }
+
+    pub fn to_bytes(self) -> [u8; HEADER_SIZE] {
+        let mut bytes = [0u8; HEADER_SIZE];
+        bytes[..4].copy_from_slice(&MAGIC);
+        bytes[4] = self.version;
+        bytes[5] = self.endianness as u8;
+        let (message_id, payload_length) = match self.endianness {
+            Endianness::Little => (
+                self.message_id.to_le_bytes(),
+                self.payload_length.to_le_bytes(),
+            ),
+            Endianness::Big => (
+                self.message_id.to_be_bytes(),
+                self.payload_length.to_be_bytes(),
+            ),
+        };
+        bytes[6..8].copy_from_slice(&message_id);
+        bytes[8..12].copy_from_slice(&payload_length);
+        bytes
+    }
+
+    /// Reads the header without checking the version.
+    pub fn from_bytes(bytes: &[u8; HEADER_SIZE]) -> Result<Self, HeaderError> {
+        if bytes[..4] != MAGIC {
+            return Err(HeaderError::InvalidMagic);
+        }
+        let Some(endianness) = Endianness::try_from_u8(bytes[5]) else {
+            return Err(HeaderError::InvalidEndianness(bytes[5]));
+        };
+        let message_id = [bytes[6], bytes[7]];
+        let payload_length = [bytes[8], bytes[9], bytes[10], bytes[11]];
+        let (message_id, payload_length) = match endianness {
+            Endianness::Little => (
+                u16::from_le_bytes(message_id),
+                u32::from_le_bytes(payload_length),
+            ),
+            Endianness::Big => (
+                u16::from_be_bytes(message_id),
+                u32::from_be_bytes(payload_length),
+            ),
+        };
+        Ok(Self {
+            version: bytes[4],
+            endianness,
+            message_id,
+            payload_length,
+        })
+    }
+
+    /// Splits the header, if any, off the message.
+    pub fn split(bytes: &[u8]) -> Result<(Option<Self>, &[u8]), HeaderError> {
+        match bytes.get(..HEADER_SIZE) {
+            Some(head) if head.starts_with(&MAGIC) => Ok((
+                Some(Self::from_bytes(head.try_into().unwrap())?),
+                &bytes[HEADER_SIZE..],
+            )),
+            _ => Ok((None, bytes)),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn header_round_trip() {
+        for endianness in [Endianness::Little, Endianness::Big] {
+            let header = Header::new(endianness, 0x8001, 72);
+            let bytes = header.to_bytes();
+            assert_eq!(bytes[..4], *b"MATP");
+            assert_eq!(Header::from_bytes(&bytes).unwrap(), header);
+        }
+        assert_eq!(
+            Header::new(Endianness::Big, 1, 48).to_bytes()[4..12],
+            [2, 1, 0, 1, 0, 0, 0, 48]
+        );
+    }
+
+    #[test]
+    fn header_is_split_off() {
+        let bytes = [&Header::new(Endianness::NATIVE, 3, 2).to_bytes()[..], &[0, 1]].concat();
+        let (header, message) = Header::split(&bytes).unwrap();
+        assert_eq!(header.unwrap().message_id, 3);
+        assert_eq!(message, [0, 1]);
+        assert_eq!(Header::split(&[0, 1]).unwrap(), (None, &[0, 1][..]));
+
+        let mut bytes = bytes;
+        bytes[5] = 7;
+        assert_eq!(Header::split(&bytes), Err(HeaderError::InvalidEndianness(7)));
+    }
+}
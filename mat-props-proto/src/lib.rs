@@ -0,0 +1,108 @@
+//! The binary protocol of the compute endpoints of the backend for the Rust clients.
+//!
+//! An args message is a `#[repr(C)]` struct whose first byte is its [`Endianness`], optionally
+//! preceded by the [`Header`] since [`ProtocolVersion::V2`].
+//!
+//! The backend shares the [`messages`], [`Endianness`], [`Header`], [`ErrorCode`] and
+//! [`ErrorMessage`] with the clients: it extracts the messages from the requests and sends
+//! them with the extractors and the responders of actix-web in `back/src/proto`.
+
+mod endianness;
+mod error;
+mod header;
+pub mod messages;
+
+pub use endianness::Endianness;
+pub use error::{ErrorCode, ErrorMessage, ERROR_CONTENT_TYPE, ERROR_MESSAGE_CONTENT_TYPE};
+pub use header::{
+    Header, HeaderError, ProtocolVersion, HEADER_PY_STRUCT_FORMAT_STRING, HEADER_SIZE, MAGIC,
+};
+
+/// The bytes aren't a message.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct InvalidLength {
+    pub expected: usize,
+    pub actual: usize,
+}
+
+impl InvalidLength {
+    fn check<M: Message>(bytes: &[u8]) -> Result<(), Self> {
+        if bytes.len() == M::SIZE {
+            Ok(())
+        } else {
+            Err(Self {
+                expected: M::SIZE,
+                actual: bytes.len(),
+            })
+        }
+    }
+}
+
+impl std::fmt::Display for InvalidLength {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "Expected {} bytes but got {} bytes",
+            self.expected, self.actual
+        )
+    }
+}
+
+impl std::error::Error for InvalidLength {}
+
+/// A message without the header.
+pub trait Message: Sized {
+    const CONTENT_TYPE: &'static str;
+    const MESSAGE_ID: u16;
+    const SIZE: usize;
+    /// The names of the fields in the order of the message, including the endianness of an args message.
+    const FIELDS: &'static [&'static str];
+
+    /// The message with the given endianness. The padding is zeroed.
+    fn encode(&self, endianness: Endianness) -> Vec<u8>;
+    /// Reads the message with the given endianness. The endianness of an args message
+    /// isn't checked against its first byte.
+    fn decode(bytes: &[u8], endianness: Endianness) -> Result<Self, InvalidLength>;
+    /// See <https://docs.python.org/3/library/struct.html#format-strings>.
+    fn py_struct_format_string() -> String;
+
+    /// The message preceded by the [`Header`].
+    fn encode_with_header(&self, endianness: Endianness) -> Vec<u8> {
+        let mut bytes = Header::new(endianness, Self::MESSAGE_ID, Self::SIZE)
+            .to_bytes()
+            .to_vec();
+        bytes.extend(self.encode(endianness));
+        bytes
+    }
+}
+
+/// The args message of a computation.
+pub trait Computation: Message {
+    type Response: ComputationResponse;
+    /// The model of `mat-props`.
+    type Model: mat_props::Computation;
+
+    /// The name of the computation in the path of its endpoint.
+    const NAME: &'static str;
+    /// The path of the endpoint, `/compute/{NAME}`.
+    const PATH: &'static str;
+
+    fn number_of_model(&self) -> u8;
+    fn unit_system(&self) -> u8;
+    fn output_unit_system(&self) -> u8;
+    /// The inputs in the order of [`mat_props::Computation::INPUTS`].
+    fn inputs(&self) -> Vec<f64>;
+}
+
+/// The response message of a computation.
+pub trait ComputationResponse: Message {
+    /// The response from the outputs in the order of [`mat_props::Computation::OUTPUTS`].
+    fn from_outputs(outputs: &[f64]) -> Self;
+}
+
+/// Picks a [`Computation`] at runtime, see [`messages::visit_computation`].
+pub trait ComputationVisitor {
+    type Output;
+
+    fn visit<C: Computation>(self) -> Self::Output;
+}
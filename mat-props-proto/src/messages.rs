@@ -0,0 +1,453 @@
+//! The args and the response messages of the computations of the backend.
+//!
+//! The messages are `#[repr(C)]` structs whose layout is the binary layout of the protocol: the `u8`
+//! fields, the padding up to the alignment of `f64` and the `f64` fields. The backend extracts and
+//! sends the same structs, so a message is declared once here with `decl_computation!`.
+
+use crate::{
+    Computation, ComputationResponse, ComputationVisitor, Endianness, InvalidLength, Message,
+};
+
+/// The Python struct format string of the `u8` fields followed by the `f64` fields.
+fn py_struct_format_string(number_of_u8: usize, number_of_f64: usize) -> String {
+    let align = core::mem::align_of::<f64>();
+    let padding = if number_of_u8 == 0 || number_of_f64 == 0 {
+        0
+    } else {
+        (align - number_of_u8 % align) % align
+    };
+    "B".repeat(number_of_u8) + &"x".repeat(padding) + &"d".repeat(number_of_f64)
+}
+
+macro_rules! decl_computation {
+    (
+        computation $name:ident: $model:path;
+
+        #[content_type = $args_content_type:literal]
+        #[message_id = $args_message_id:literal]
+        args $args:ident {
+            #[schema($($number_of_model_schema:tt)*)]
+            number_of_model: u8,
+            $(
+                $(#[doc = $input_doc:literal])*
+                $input:ident: f64
+            ),+ $(,)?
+        }
+
+        #[content_type = $response_content_type:literal]
+        #[message_id = $response_message_id:literal]
+        response $response:ident {
+            $(
+                $(#[doc = $output_doc:literal])*
+                $output:ident: f64
+            ),+ $(,)?
+        }
+    ) => {
+        #[derive(Clone, Copy, Debug, Default, PartialEq)]
+        #[cfg_attr(
+            feature = "serde",
+            derive(serde::Serialize, serde::Deserialize),
+            serde(deny_unknown_fields)
+        )]
+        #[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
+        #[repr(C)]
+        pub struct $args {
+            /// 0: little endian, 1: big endian.
+            ///
+            /// The endianness is the first field to enable optimization where
+            /// the bytes of multi-byte fields are swapped to match the native endianness
+            /// of the server as they are received. It is ignored in the self-describing encodings.
+            /// [`Message::encode`] writes the endianness it is given instead, and [`Message::decode`]
+            /// sets it to the endianness it reads with.
+            #[cfg_attr(feature = "serde", serde(default))]
+            #[cfg_attr(feature = "utoipa", schema(minimum = 0, maximum = 1))]
+            pub endianness: u8,
+            #[cfg_attr(feature = "utoipa", schema($($number_of_model_schema)*))]
+            pub number_of_model: u8,
+            /// The unit system of the inputs. 0: consistent units, the angles in radians; 1: SI; 2: GPa, mm, degrees.
+            #[cfg_attr(feature = "serde", serde(default))]
+            #[cfg_attr(feature = "utoipa", schema(minimum = 0, maximum = 2))]
+            pub unit_system: u8,
+            /// The unit system of the outputs, see `unit_system`.
+            #[cfg_attr(feature = "serde", serde(default))]
+            #[cfg_attr(feature = "utoipa", schema(minimum = 0, maximum = 2))]
+            pub output_unit_system: u8,
+            $(
+                $(#[doc = $input_doc])*
+                pub $input: f64,
+            )+
+        }
+
+        impl Message for $args {
+            const CONTENT_TYPE: &'static str = $args_content_type;
+            const MESSAGE_ID: u16 = $args_message_id;
+            const SIZE: usize = core::mem::size_of::<Self>();
+            const FIELDS: &'static [&'static str] = &[
+                "endianness",
+                "number_of_model",
+                "unit_system",
+                "output_unit_system",
+                $(stringify!($input)),+
+            ];
+
+            fn encode(&self, endianness: Endianness) -> Vec<u8> {
+                let mut bytes = vec![0; Self::SIZE];
+                bytes[0] = endianness as u8;
+                bytes[core::mem::offset_of!(Self, number_of_model)] = self.number_of_model;
+                bytes[core::mem::offset_of!(Self, unit_system)] = self.unit_system;
+                bytes[core::mem::offset_of!(Self, output_unit_system)] = self.output_unit_system;
+                $(
+                    let offset = core::mem::offset_of!(Self, $input);
+                    bytes[offset..offset + 8].copy_from_slice(&endianness.f64_to_bytes(self.$input));
+                )+
+                bytes
+            }
+
+            fn decode(bytes: &[u8], endianness: Endianness) -> Result<Self, InvalidLength> {
+                InvalidLength::check::<Self>(bytes)?;
+                Ok(Self {
+                    endianness: endianness as u8,
+                    number_of_model: bytes[core::mem::offset_of!(Self, number_of_model)],
+                    unit_system: bytes[core::mem::offset_of!(Self, unit_system)],
+                    output_unit_system: bytes[core::mem::offset_of!(Self, output_unit_system)],
+                    $(
+                        $input: {
+                            let offset = core::mem::offset_of!(Self, $input);
+                            endianness.f64_from_bytes(&bytes[offset..offset + 8])
+                        },
+                    )+
+                })
+            }
+
+            fn py_struct_format_string() -> String {
+                py_struct_format_string(4, [$(stringify!($input)),+].len())
+            }
+        }
+
+        #[derive(Clone, Copy, Debug, Default, PartialEq)]
+        #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+        #[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
+        #[repr(C)]
+        pub struct $response {
+            $(
+                $(#[doc = $output_doc])*
+                pub $output: f64,
+            )+
+        }
+
+        impl Message for $response {
+            const CONTENT_TYPE: &'static str = $response_content_type;
+            const MESSAGE_ID: u16 = $response_message_id;
+            const SIZE: usize = core::mem::size_of::<Self>();
+            const FIELDS: &'static [&'static str] = &[$(stringify!($output)),+];
+
+            fn encode(&self, endianness: Endianness) -> Vec<u8> {
+                let mut bytes = vec![0; Self::SIZE];
+                $(
+                    let offset = core::mem::offset_of!(Self, $output);
+                    bytes[offset..offset + 8].copy_from_slice(&endianness.f64_to_bytes(self.$output));
+                )+
+                bytes
+            }
+
+            fn decode(bytes: &[u8], endianness: Endianness) -> Result<Self, InvalidLength> {
+                InvalidLength::check::<Self>(bytes)?;
+                Ok(Self {
+                    $(
+                        $output: {
+                            let offset = core::mem::offset_of!(Self, $output);
+                            endianness.f64_from_bytes(&bytes[offset..offset + 8])
+                        },
+                    )+
+                })
+            }
+
+            fn py_struct_format_string() -> String {
+                py_struct_format_string(0, Self::FIELDS.len())
+            }
+        }
+
+        impl Computation for $args {
+            type Response = $response;
+            type Model = $model;
+
+            const NAME: &'static str = stringify!($name);
+            const PATH: &'static str = concat!("/compute/", stringify!($name));
+
+            fn number_of_model(&self) -> u8 {
+                self.number_of_model
+            }
+
+            fn unit_system(&self) -> u8 {
+                self.unit_system
+            }
+
+            fn output_unit_system(&self) -> u8 {
+                self.output_unit_system
+            }
+
+            fn inputs(&self) -> Vec<f64> {
+                vec![$(self.$input),+]
+            }
+        }
+
+        impl ComputationResponse for $response {
+            fn from_outputs(outputs: &[f64]) -> Self {
+                let mut outputs = outputs.iter().copied();
+                Self {
+                    $($output: outputs.next().unwrap()),+
+                }
+            }
+        }
+    };
+}
+
+decl_computation! {
+    computation elastic_modules_for_unidirectional_composite:
+        mat_props::ElasticModulesForUnidirectionalComposite;
+
+    #[content_type = "application/x.elastic-modules-for-unidirectional-composite-args-message"]
+    #[message_id = 1]
+    args ElasticModulesForUnidirectionalCompositeArgs {
+        #[schema(minimum = 1, maximum = 2)]
+        number_of_model: u8,
+        /// Dimensionless.
+        fibre_content: f64,
+        /// Arbitrary units of pressure (unit_system = 0), Pa (unit_system = 1) or GPa (unit_system = 2).
+        e_for_fiber: f64,
+        /// Dimensionless.
+        nu_for_fiber: f64,
+        /// Arbitrary units of pressure (unit_system = 0), Pa (unit_system = 1) or GPa (unit_system = 2).
+        e_for_matrix: f64,
+        /// Dimensionless.
+        nu_for_matrix: f64,
+    }
+
+    #[content_type = "application/x.elastic-modules-for-unidirectional-composite-response-message"]
+    #[message_id = 0x8001]
+    response ElasticModulesForUnidirectionalCompositeResponse {
+        /// Arbitrary units of pressure (output_unit_system = 0), Pa (output_unit_system = 1) or GPa (output_unit_system = 2).
+        e1: f64,
+        /// Arbitrary units of pressure (output_unit_system = 0), Pa (output_unit_system = 1) or GPa (output_unit_system = 2).
+        e2: f64,
+        /// Arbitrary units of pressure (output_unit_system = 0), Pa (output_unit_system = 1) or GPa (output_unit_system = 2).
+        e3: f64,
+        /// Dimensionless.
+        nu12: f64,
+        /// Dimensionless.
+        nu13: f64,
+        /// Dimensionless.
+        nu23: f64,
+        /// Arbitrary units of pressure (output_unit_system = 0), Pa (output_unit_system = 1) or GPa (output_unit_system = 2).
+        g12: f64,
+        /// Arbitrary units of pressure (output_unit_system = 0), Pa (output_unit_system = 1) or GPa (output_unit_system = 2).
+        g13: f64,
+        /// Arbitrary units of pressure (output_unit_system = 0), Pa (output_unit_system = 1) or GPa (output_unit_system = 2).
+        g23: f64,
+    }
+}
+
+decl_computation! {
+    computation elastic_modules_for_honeycomb: mat_props::ElasticModulesForHoneycomb;
+
+    #[content_type = "application/x.elastic-modules-for-honeycomb-args-message"]
+    #[message_id = 2]
+    args ElasticModulesForHoneycombArgs {
+        #[schema(minimum = 1, maximum = 1)]
+        number_of_model: u8,
+        /// Arbitrary units of length (unit_system = 0), m (unit_system = 1) or mm (unit_system = 2).
+        l_cell_side_size: f64,
+        /// Arbitrary units of length (unit_system = 0), m (unit_system = 1) or mm (unit_system = 2).
+        h_cell_side_size: f64,
+        /// Arbitrary units of length (unit_system = 0), m (unit_system = 1) or mm (unit_system = 2).
+        wall_thickness: f64,
+        /// Radians (unit_system = 0 or 1) or degrees (unit_system = 2).
+        angle: f64,
+        /// Arbitrary units of pressure (unit_system = 0), Pa (unit_system = 1) or GPa (unit_system = 2).
+        e_for_honeycomb: f64,
+        /// Dimensionless.
+        nu_for_honeycomb: f64,
+    }
+
+    #[content_type = "application/x.elastic-modules-for-honeycomb-response-message"]
+    #[message_id = 0x8002]
+    response ElasticModulesForHoneycombResponse {
+        /// Arbitrary units of pressure (output_unit_system = 0), Pa (output_unit_system = 1) or GPa (output_unit_system = 2).
+        e1: f64,
+        /// Arbitrary units of pressure (output_unit_system = 0), Pa (output_unit_system = 1) or GPa (output_unit_system = 2).
+        e2: f64,
+        /// Arbitrary units of pressure (output_unit_system = 0), Pa (output_unit_system = 1) or GPa (output_unit_system = 2).
+        e3: f64,
+        /// Dimensionless.
+        nu12: f64,
+        /// Dimensionless.
+        nu13: f64,
+        /// Dimensionless.
+        nu23: f64,
+        /// Arbitrary units of pressure (output_unit_system = 0), Pa (output_unit_system = 1) or GPa (output_unit_system = 2).
+        g12: f64,
+        /// Arbitrary units of pressure (output_unit_system = 0), Pa (output_unit_system = 1) or GPa (output_unit_system = 2).
+        g13: f64,
+        /// Arbitrary units of pressure (output_unit_system = 0), Pa (output_unit_system = 1) or GPa (output_unit_system = 2).
+        g23: f64,
+    }
+}
+
+decl_computation! {
+    computation thermal_conductivity_for_unidirectional_composite:
+        mat_props::ThermalConductivityForUnidirectionalComposite;
+
+    #[content_type = "application/x.thermal-conductivity-for-unidirectional-composite-args-message"]
+    #[message_id = 3]
+    args ThermalConductivityForUnidirectionalCompositeArgs {
+        #[schema(minimum = 1, maximum = 2)]
+        number_of_model: u8,
+        /// Dimensionless.
+        fibre_content: f64,
+        /// Arbitrary units (unit_system = 0) or W/(m·K) (unit_system = 1 or 2).
+        k_for_fiber: f64,
+        /// Arbitrary units (unit_system = 0) or W/(m·K) (unit_system = 1 or 2).
+        k_for_matrix: f64,
+    }
+
+    #[content_type = "application/x.thermal-conductivity-for-unidirectional-composite-response-message"]
+    #[message_id = 0x8003]
+    response ThermalConductivityForUnidirectionalCompositeResponse {
+        /// Arbitrary units (output_unit_system = 0) or W/(m·K) (output_unit_system = 1 or 2).
+        k1: f64,
+        /// Arbitrary units (output_unit_system = 0) or W/(m·K) (output_unit_system = 1 or 2).
+        k2: f64,
+        /// Arbitrary units (output_unit_system = 0) or W/(m·K) (output_unit_system = 1 or 2).
+        k3: f64,
+    }
+}
+
+decl_computation! {
+    computation thermal_expansion_for_unidirectional_composite:
+        mat_props::ThermalExpansionForUnidirectionalComposite;
+
+    #[content_type = "application/x.thermal-expansion-for-unidirectional-composite-args-message"]
+    #[message_id = 4]
+    args ThermalExpansionForUnidirectionalCompositeArgs {
+        #[schema(minimum = 1, maximum = 1)]
+        number_of_model: u8,
+        /// Dimensionless.
+        fibre_content: f64,
+        /// Arbitrary units of pressure (unit_system = 0), Pa (unit_system = 1) or GPa (unit_system = 2).
+        e_for_fiber: f64,
+        /// Dimensionless.
+        nu_for_fiber: f64,
+        /// Arbitrary units (unit_system = 0) or 1/K (unit_system = 1 or 2).
+        alpha_for_fiber: f64,
+        /// Arbitrary units of pressure (unit_system = 0), Pa (unit_system = 1) or GPa (unit_system = 2).
+        e_for_matrix: f64,
+        /// Dimensionless.
+        nu_for_matrix: f64,
+        /// Arbitrary units (unit_system = 0) or 1/K (unit_system = 1 or 2).
+        alpha_for_matrix: f64,
+    }
+
+    #[content_type = "application/x.thermal-expansion-for-unidirectional-composite-response-message"]
+    #[message_id = 0x8004]
+    response ThermalExpansionForUnidirectionalCompositeResponse {
+        /// Arbitrary units (output_unit_system = 0) or 1/K (output_unit_system = 1 or 2).
+        alpha1: f64,
+        /// Arbitrary units (output_unit_system = 0) or 1/K (output_unit_system = 1 or 2).
+        alpha2: f64,
+        /// Arbitrary units (output_unit_system = 0) or 1/K (output_unit_system = 1 or 2).
+        alpha3: f64,
+    }
+}
+
+decl_computation! {
+    computation thermal_expansion_for_honeycomb: mat_props::ThermalExpansionForHoneycomb;
+
+    #[content_type = "application/x.thermal-expansion-for-honeycomb-args-message"]
+    #[message_id = 5]
+    args ThermalExpansionForHoneycombArgs {
+        #[schema(minimum = 1, maximum = 1)]
+        number_of_model: u8,
+        /// Arbitrary units of length (unit_system = 0), m (unit_system = 1) or mm (unit_system = 2).
+        l_cell_side_size: f64,
+        /// Arbitrary units of length (unit_system = 0), m (unit_system = 1) or mm (unit_system = 2).
+        h_cell_side_size: f64,
+        /// Arbitrary units of length (unit_system = 0), m (unit_system = 1) or mm (unit_system = 2).
+        _wall_thickness: f64,
+        /// Radians (unit_system = 0 or 1) or degrees (unit_system = 2).
+        angle: f64,
+        /// Arbitrary units (unit_system = 0) or 1/K (unit_system = 1 or 2).
+        alpha_for_honeycomb: f64,
+    }
+
+    #[content_type = "application/x.thermal-expansion-for-honeycomb-response-message"]
+    #[message_id = 0x8005]
+    response ThermalExpansionForHoneycombResponse {
+        /// Arbitrary units (output_unit_system = 0) or 1/K (output_unit_system = 1 or 2).
+        alpha1: f64,
+        /// Arbitrary units (output_unit_system = 0) or 1/K (output_unit_system = 1 or 2).
+        alpha2: f64,
+        /// Arbitrary units (output_unit_system = 0) or 1/K (output_unit_system = 1 or 2).
+        alpha3: f64,
+    }
+}
+
+/// Visits the [`Computation`] with the given name, if any.
+pub fn visit_computation<V: ComputationVisitor>(name: &str, visitor: V) -> Option<V::Output> {
+    match name {
+        ElasticModulesForUnidirectionalCompositeArgs::NAME => {
+            Some(visitor.visit::<ElasticModulesForUnidirectionalCompositeArgs>())
+        }
+        ElasticModulesForHoneycombArgs::NAME => {
+            Some(visitor.visit::<ElasticModulesForHoneycombArgs>())
+        }
+        ThermalConductivityForUnidirectionalCompositeArgs::NAME => {
+            Some(visitor.visit::<ThermalConductivityForUnidirectionalCompositeArgs>())
+        }
+        ThermalExpansionForUnidirectionalCompositeArgs::NAME => {
+            Some(visitor.visit::<ThermalExpansionForUnidirectionalCompositeArgs>())
+        }
+        ThermalExpansionForHoneycombArgs::NAME => {
+            Some(visitor.visit::<ThermalExpansionForHoneycombArgs>())
+        }
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn layout_is_the_layout_of_the_protocol() {
+        type Args = ThermalConductivityForUnidirectionalCompositeArgs;
+        assert_eq!(Args::SIZE, 32);
+        assert_eq!(Args::py_struct_format_string(), "BBBBxxxxddd");
+        assert_eq!(ThermalExpansionForHoneycombResponse::SIZE, 24);
+        assert_eq!(
+            ThermalExpansionForHoneycombResponse::py_struct_format_string(),
+            "ddd"
+        );
+
+        let args = Args {
+            endianness: 0,
+            number_of_model: 1,
+            unit_system: 2,
+            output_unit_system: 1,
+            fibre_content: 0.2,
+            k_for_fiber: 100.0,
+            k_for_matrix: 1.0,
+        };
+        let bytes = args.encode(Endianness::Big);
+        assert_eq!(bytes[..8], [1, 1, 2, 1, 0, 0, 0, 0]);
+        assert_eq!(bytes[16..24], 100.0f64.to_be_bytes());
+        let decoded = Args::decode(&bytes, Endianness::Big).unwrap();
+        assert_eq!(decoded.endianness, Endianness::Big as u8);
+        assert_eq!(decoded.k_for_fiber, 100.0);
+        assert_eq!(
+            Args::decode(&bytes[1..], Endianness::Big)
+                .unwrap_err()
+                .expected,
+            32
+        );
+    }
+}
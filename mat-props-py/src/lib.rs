@@ -0,0 +1,422 @@
+//! Python bindings of `mat-props`.
+//!
+//! The models take keyword arguments named as in `mat-props`. Every input is either a number or
+//! a 1-D array (a NumPy array or a sequence of numbers). The arrays must be of the same length
+//! and the numbers are broadcast to it. The fields of the results are numbers if all the inputs
+//! are numbers and NumPy arrays otherwise.
+
+use mat_props::{
+    evaluate_batch_columns, evaluate_in_units, Computation, ElasticModulesForHoneycomb,
+    ElasticModulesForUnidirectionalComposite, Error, ThermalConductivityForUnidirectionalComposite,
+    ThermalExpansionForHoneycomb, ThermalExpansionForUnidirectionalComposite, UnitSystem,
+};
+use num_traits::FromPrimitive;
+use numpy::{AllowTypeChange, PyArray1, PyArrayLike1};
+use pyo3::{
+    create_exception,
+    exceptions::{PyTypeError, PyValueError},
+    prelude::*,
+    IntoPyObjectExt,
+};
+
+create_exception!(
+    mat_props,
+    MatPropsError,
+    PyValueError,
+    "The error of a model, e.g. an unknown model or a numerical failure."
+);
+
+fn to_py_err(err: Error) -> PyErr {
+    MatPropsError::new_err(err.to_string())
+}
+
+enum Input {
+    Number(f64),
+    Array(Vec<f64>),
+}
+
+fn extract_input(name: &str, value: &Bound<'_, PyAny>) -> PyResult<Input> {
+    if let Ok(value) = value.extract::<f64>() {
+        return Ok(Input::Number(value));
+    }
+    let array: PyArrayLike1<'_, f64, AllowTypeChange> = value.extract().map_err(|_| {
+        PyTypeError::new_err(format!(
+            "`{name}` must be a number or a 1-D array of numbers"
+        ))
+    })?;
+    Ok(Input::Array(array.as_array().to_vec()))
+}
+
+/// Evaluates the computation for the inputs in the order of [`Computation::INPUTS`].
+///
+/// Returns the outputs in the order of [`Computation::OUTPUTS`].
+fn evaluate<C: Computation>(
+    py: Python<'_>,
+    number_of_model: u8,
+    unit_system: u8,
+    output_unit_system: u8,
+    inputs: &[&Bound<'_, PyAny>],
+) -> PyResult<Vec<Py<PyAny>>> {
+    let inputs = C::INPUTS
+        .iter()
+        .zip(inputs)
+        .map(|(name, value)| extract_input(name, value))
+        .collect::<PyResult<Vec<_>>>()?;
+    let len = inputs.iter().find_map(|input| match input {
+        Input::Number(_) => None,
+        Input::Array(array) => Some(array.len()),
+    });
+    let Some(len) = len else {
+        let inputs: Vec<f64> = inputs
+            .iter()
+            .map(|input| match input {
+                Input::Number(value) => *value,
+                Input::Array(_) => unreachable!(),
+            })
+            .collect();
+        return evaluate_in_units::<C>(number_of_model, &inputs, unit_system, output_unit_system)
+            .map_err(to_py_err)?
+            .into_iter()
+            .map(|output| output.into_py_any(py))
+            .collect();
+    };
+
+    // the same conversions as in `evaluate_in_units`, but for the columns
+    let unit_system =
+        UnitSystem::from_u8(unit_system).ok_or_else(|| to_py_err(Error::UnknownUnitSystem))?;
+    let output_unit_system = UnitSystem::from_u8(output_unit_system)
+        .ok_or_else(|| to_py_err(Error::UnknownUnitSystem))?;
+    let columns = C::INPUTS
+        .iter()
+        .zip(C::INPUT_DIMENSIONS)
+        .zip(inputs)
+        .map(|((name, &dimension), input)| {
+            let column = match input {
+                Input::Number(value) => vec![value; len],
+                Input::Array(array) if array.len() == len => array,
+                Input::Array(array) => {
+                    return Err(PyValueError::new_err(format!(
+                        "`{name}` has {} elements but the other arrays have {len}",
+                        array.len()
+                    )))
+                }
+            };
+            Ok(column
+                .into_iter()
+                .map(|value| unit_system.convert(value, dimension, UnitSystem::Si))
+                .collect::<Vec<f64>>())
+        })
+        .collect::<PyResult<Vec<_>>>()?;
+    let mut outputs = vec![vec![0.0; len]; C::OUTPUTS.len()];
+    py.detach(|| {
+        let inputs: Vec<&[f64]> = columns.iter().map(Vec::as_slice).collect();
+        let mut outputs: Vec<&mut [f64]> = outputs.iter_mut().map(Vec::as_mut_slice).collect();
+        evaluate_batch_columns::<C>(number_of_model, &inputs, &mut outputs)
+    })
+    .map_err(to_py_err)?;
+    Ok(outputs
+        .into_iter()
+        .zip(C::OUTPUT_DIMENSIONS)
+        .map(|(column, &dimension)| {
+            PyArray1::from_iter(
+                py,
+                column
+                    .into_iter()
+                    .map(|output| UnitSystem::Si.convert(output, dimension, output_unit_system)),
+            )
+            .into_any()
+            .unbind()
+        })
+        .collect())
+}
+
+macro_rules! decl_result_class {
+    ($(#[$attr:meta])* $name:ident { $($field:ident),+ $(,)? }) => {
+        $(#[$attr])*
+        #[pyclass(frozen, get_all, module = "mat_props")]
+        pub struct $name {
+            $($field: Py<PyAny>),+
+        }
+
+        impl $name {
+            fn from_outputs(outputs: Vec<Py<PyAny>>) -> Self {
+                let mut outputs = outputs.into_iter();
+                Self {
+                    $($field: outputs.next().unwrap()),+
+                }
+            }
+        }
+
+        #[pymethods]
+        impl $name {
+            fn __repr__(&self, py: Python<'_>) -> PyResult<String> {
+                let fields = [
+                    $(format!("{}={}", stringify!($field), self.$field.bind(py).repr()?)),+
+                ];
+                Ok(format!("{}({})", stringify!($name), fields.join(", ")))
+            }
+        }
+    };
+}
+
+decl_result_class!(
+    /// Elastic modules of an orthotropic material.
+    ElasticModules {
+        e1, e2, e3, nu12, nu13, nu23, g12, g13, g23,
+    }
+);
+
+decl_result_class!(
+    /// Thermal conductivity in the three primary directions.
+    ThermalConductivity { k1, k2, k3 }
+);
+
+decl_result_class!(
+    /// Coefficients of thermal expansion in the three primary directions.
+    ThermalExpansion {
+        alpha1, alpha2, alpha3,
+    }
+);
+
+/// Computes elastic modules for unidirectional composite.
+///
+/// `number_of_model` is 1 for the rule of mixtures and 2 for Vanin's model.
+/// `unit_system` and `output_unit_system` are 0 for consistent units, 1 for SI
+/// and 2 for the engineering units (GPa, mm, degrees).
+#[allow(clippy::too_many_arguments)]
+#[pyfunction]
+#[pyo3(signature = (
+    *,
+    number_of_model,
+    fibre_content,
+    e_for_fiber,
+    nu_for_fiber,
+    e_for_matrix,
+    nu_for_matrix,
+    unit_system = 0,
+    output_unit_system = 0,
+))]
+fn elastic_modules_for_unidirectional_composite(
+    py: Python<'_>,
+    number_of_model: u8,
+    fibre_content: &Bound<'_, PyAny>,
+    e_for_fiber: &Bound<'_, PyAny>,
+    nu_for_fiber: &Bound<'_, PyAny>,
+    e_for_matrix: &Bound<'_, PyAny>,
+    nu_for_matrix: &Bound<'_, PyAny>,
+    unit_system: u8,
+    output_unit_system: u8,
+) -> PyResult<ElasticModules> {
+    evaluate::<ElasticModulesForUnidirectionalComposite>(
+        py,
+        number_of_model,
+        unit_system,
+        output_unit_system,
+        &[
+            fibre_content,
+            e_for_fiber,
+            nu_for_fiber,
+            e_for_matrix,
+            nu_for_matrix,
+        ],
+    )
+    .map(ElasticModules::from_outputs)
+}
+
+/// Computes elastic modules for honeycomb.
+///
+/// `number_of_model` is 1 for Vanin's model.
+/// See `elastic_modules_for_unidirectional_composite` for the unit systems.
+#[allow(clippy::too_many_arguments)]
+#[pyfunction]
+#[pyo3(signature = (
+    *,
+    number_of_model,
+    l_cell_side_size,
+    h_cell_side_size,
+    wall_thickness,
+    angle,
+    e_for_honeycomb,
+    nu_for_honeycomb,
+    unit_system = 0,
+    output_unit_system = 0,
+))]
+fn elastic_modules_for_honeycomb(
+    py: Python<'_>,
+    number_of_model: u8,
+    l_cell_side_size: &Bound<'_, PyAny>,
+    h_cell_side_size: &Bound<'_, PyAny>,
+    wall_thickness: &Bound<'_, PyAny>,
+    angle: &Bound<'_, PyAny>,
+    e_for_honeycomb: &Bound<'_, PyAny>,
+    nu_for_honeycomb: &Bound<'_, PyAny>,
+    unit_system: u8,
+    output_unit_system: u8,
+) -> PyResult<ElasticModules> {
+    evaluate::<ElasticModulesForHoneycomb>(
+        py,
+        number_of_model,
+        unit_system,
+        output_unit_system,
+        &[
+            l_cell_side_size,
+            h_cell_side_size,
+            wall_thickness,
+            angle,
+            e_for_honeycomb,
+            nu_for_honeycomb,
+        ],
+    )
+    .map(ElasticModules::from_outputs)
+}
+
+/// Computes thermal conductivity for unidirectional composite.
+///
+/// `number_of_model` is 1 for the rule of mixtures and 2 for Vanin's model.
+/// See `elastic_modules_for_unidirectional_composite` for the unit systems.
+#[pyfunction]
+#[pyo3(signature = (
+    *,
+    number_of_model,
+    fibre_content,
+    k_for_fiber,
+    k_for_matrix,
+    unit_system = 0,
+    output_unit_system = 0,
+))]
+fn thermal_conductivity_for_unidirectional_composite(
+    py: Python<'_>,
+    number_of_model: u8,
+    fibre_content: &Bound<'_, PyAny>,
+    k_for_fiber: &Bound<'_, PyAny>,
+    k_for_matrix: &Bound<'_, PyAny>,
+    unit_system: u8,
+    output_unit_system: u8,
+) -> PyResult<ThermalConductivity> {
+    evaluate::<ThermalConductivityForUnidirectionalComposite>(
+        py,
+        number_of_model,
+        unit_system,
+        output_unit_system,
+        &[fibre_content, k_for_fiber, k_for_matrix],
+    )
+    .map(ThermalConductivity::from_outputs)
+}
+
+/// Computes coefficients of thermal expansion for unidirectional composite.
+///
+/// `number_of_model` is 1 for Vanin's model.
+/// See `elastic_modules_for_unidirectional_composite` for the unit systems.
+#[allow(clippy::too_many_arguments)]
+#[pyfunction]
+#[pyo3(signature = (
+    *,
+    number_of_model,
+    fibre_content,
+    e_for_fiber,
+    nu_for_fiber,
+    alpha_for_fiber,
+    e_for_matrix,
+    nu_for_matrix,
+    alpha_for_matrix,
+    unit_system = 0,
+    output_unit_system = 0,
+))]
+fn thermal_expansion_for_unidirectional_composite(
+    py: Python<'_>,
+    number_of_model: u8,
+    fibre_content: &Bound<'_, PyAny>,
+    e_for_fiber: &Bound<'_, PyAny>,
+    nu_for_fiber: &Bound<'_, PyAny>,
+    alpha_for_fiber: &Bound<'_, PyAny>,
+    e_for_matrix: &Bound<'_, PyAny>,
+    nu_for_matrix: &Bound<'_, PyAny>,
+    alpha_for_matrix: &Bound<'_, PyAny>,
+    unit_system: u8,
+    output_unit_system: u8,
+) -> PyResult<ThermalExpansion> {
+    evaluate::<ThermalExpansionForUnidirectionalComposite>(
+        py,
+        number_of_model,
+        unit_system,
+        output_unit_system,
+        &[
+            fibre_content,
+            e_for_fiber,
+            nu_for_fiber,
+            alpha_for_fiber,
+            e_for_matrix,
+            nu_for_matrix,
+            alpha_for_matrix,
+        ],
+    )
+    .map(ThermalExpansion::from_outputs)
+}
+
+/// Computes coefficients of thermal expansion for honeycomb.
+///
+/// `number_of_model` is 1 for Vanin's model.
+/// See `elastic_modules_for_unidirectional_composite` for the unit systems.
+#[allow(clippy::too_many_arguments)]
+#[pyfunction]
+#[pyo3(signature = (
+    *,
+    number_of_model,
+    l_cell_side_size,
+    h_cell_side_size,
+    wall_thickness,
+    angle,
+    alpha_for_honeycomb,
+    unit_system = 0,
+    output_unit_system = 0,
+))]
+fn thermal_expansion_for_honeycomb(
+    py: Python<'_>,
+    number_of_model: u8,
+    l_cell_side_size: &Bound<'_, PyAny>,
+    h_cell_side_size: &Bound<'_, PyAny>,
+    wall_thickness: &Bound<'_, PyAny>,
+    angle: &Bound<'_, PyAny>,
+    alpha_for_honeycomb: &Bound<'_, PyAny>,
+    unit_system: u8,
+    output_unit_system: u8,
+) -> PyResult<ThermalExpansion> {
+    evaluate::<ThermalExpansionForHoneycomb>(
+        py,
+        number_of_model,
+        unit_system,
+        output_unit_system,
+        &[
+            l_cell_side_size,
+            h_cell_side_size,
+            wall_thickness,
+            angle,
+            alpha_for_honeycomb,
+        ],
+    )
+    .map(ThermalExpansion::from_outputs)
+}
+
+#[pymodule]
+#[pyo3(name = "mat_props")]
+fn py_mat_props(m: &Bound<'_, PyModule>) -> PyResult<()> {
+    m.add("MatPropsError", m.py().get_type::<MatPropsError>())?;
+    m.add_class::<ElasticModules>()?;
+    m.add_class::<ThermalConductivity>()?;
+    m.add_class::<ThermalExpansion>()?;
+    m.add_function(wrap_pyfunction!(
+        elastic_modules_for_unidirectional_composite,
+        m
+    )?)?;
+    m.add_function(wrap_pyfunction!(elastic_modules_for_honeycomb, m)?)?;
+    m.add_function(wrap_pyfunction!(
+        thermal_conductivity_for_unidirectional_composite,
+        m
+    )?)?;
+    m.add_function(wrap_pyfunction!(
+        thermal_expansion_for_unidirectional_composite,
+        m
+    )?)?;
+    m.add_function(wrap_pyfunction!(thermal_expansion_for_honeycomb, m)?)?;
+    Ok(())
+}
@@ -0,0 +1,119 @@
+# The same reference values as the unit tests of mat-props.
+#
+# Run `maturin develop && pytest tests` from `mat-props-py`.
+
+import math
+
+import numpy as np
+import pytest
+
+import mat_props
+
+
+def test_elastic_modules_for_unidirectional_composite():
+    modules = mat_props.elastic_modules_for_unidirectional_composite(
+        number_of_model=2,
+        fibre_content=0.2,
+        e_for_fiber=100.0,
+        nu_for_fiber=0.3,
+        e_for_matrix=5.0,
+        nu_for_matrix=0.2,
+    )
+    assert modules.e1 == 24.011723329425557
+    assert modules.e2 == 6.5683701067350135
+    assert modules.e3 == 6.5683701067350135
+    assert modules.nu12 == 0.06240625050144681
+    assert modules.nu13 == 0.06240625050144681
+    assert modules.nu23 == 0.18585515203940609
+    assert modules.g12 == 2.9945407835581253
+    assert modules.g13 == 2.9945407835581253
+    assert modules.g23 == 2.769465602708258
+
+
+def test_elastic_modules_for_honeycomb():
+    modules = mat_props.elastic_modules_for_honeycomb(
+        number_of_model=1,
+        l_cell_side_size=9.24,
+        h_cell_side_size=8.4619,
+        wall_thickness=0.4,
+        angle=math.pi / 6.0,
+        e_for_honeycomb=7.07,
+        nu_for_honeycomb=0.2,
+    )
+    assert modules.e1 == 0.0014972693834675922
+    assert modules.e3 == 0.3592394105863781
+    assert modules.nu12 == 1.0512175946777975
+    assert modules.g23 == 0.0755763830773748
+
+
+def test_thermal_conductivity_for_unidirectional_composite():
+    k = mat_props.thermal_conductivity_for_unidirectional_composite(
+        number_of_model=2, fibre_content=0.2, k_for_fiber=100.0, k_for_matrix=1.0
+    )
+    assert (k.k1, k.k2, k.k3) == (20.8, 1.3300670235932428, 1.3300670235932428)
+
+
+def test_thermal_expansion():
+    alpha = mat_props.thermal_expansion_for_unidirectional_composite(
+        number_of_model=1,
+        fibre_content=0.2,
+        e_for_fiber=100.0,
+        nu_for_fiber=0.3,
+        alpha_for_fiber=1e-6,
+        e_for_matrix=5.0,
+        nu_for_matrix=0.2,
+        alpha_for_matrix=20e-5,
+    )
+    assert alpha.alpha1 == 0.00003303092919697953
+    assert alpha.alpha2 == 0.0001653038466333737
+
+    alpha = mat_props.thermal_expansion_for_honeycomb(
+        number_of_model=1,
+        l_cell_side_size=9.24,
+        h_cell_side_size=8.4619,
+        wall_thickness=0.4,
+        angle=math.pi / 6.0,
+        alpha_for_honeycomb=20e-5,
+    )
+    assert (alpha.alpha1, alpha.alpha2, alpha.alpha3) == (0.0002, 0.00019999999999999966, 0.0002)
+
+
+def test_arrays():
+    fibre_content = np.linspace(0.1, 0.7, 7)
+    k = mat_props.thermal_conductivity_for_unidirectional_composite(
+        number_of_model=2, fibre_content=fibre_content, k_for_fiber=100.0, k_for_matrix=[1.0] * 7
+    )
+    assert isinstance(k.k1, np.ndarray)
+    for i, vf in enumerate(fibre_content):
+        expected = mat_props.thermal_conductivity_for_unidirectional_composite(
+            number_of_model=2, fibre_content=vf, k_for_fiber=100.0, k_for_matrix=1.0
+        )
+        assert (k.k1[i], k.k2[i], k.k3[i]) == (expected.k1, expected.k2, expected.k3)
+
+    with pytest.raises(ValueError):
+        mat_props.thermal_conductivity_for_unidirectional_composite(
+            number_of_model=2, fibre_content=fibre_content, k_for_fiber=[100.0, 50.0], k_for_matrix=1.0
+        )
+
+
+def test_units():
+    modules = mat_props.elastic_modules_for_unidirectional_composite(
+        number_of_model=2,
+        fibre_content=0.2,
+        e_for_fiber=100e9,
+        nu_for_fiber=0.3,
+        e_for_matrix=5e9,
+        nu_for_matrix=0.2,
+        unit_system=1,
+        output_unit_system=2,
+    )
+    assert modules.e1 == pytest.approx(24.011723329425557)
+
+
+def test_errors():
+    with pytest.raises(mat_props.MatPropsError, match="Unknown model"):
+        mat_props.thermal_conductivity_for_unidirectional_composite(
+            number_of_model=42, fibre_content=0.2, k_for_fiber=100.0, k_for_matrix=1.0
+        )
+    with pytest.raises(TypeError):
+        mat_props.thermal_conductivity_for_unidirectional_composite(2, 0.2, 100.0, 1.0)
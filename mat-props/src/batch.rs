@@ -0,0 +1,155 @@
+use crate::{computation::Computation, Error, Result};
+use alloc::{vec, vec::Vec};
+#[cfg(feature = "rayon")]
+use rayon::prelude::*;
+
+/// Evaluates the computation for many sets of inputs at once.
+///
+/// Unlike calling [`Computation::evaluate`] in a loop, the panics are caught once for the whole batch.
+/// With the `rayon` feature, the sets of inputs are evaluated in parallel.
+///
+/// ## Arguments
+///
+/// * `number_of_model` - the number of the selected model of the computation.
+/// * `inputs` - the packed sets of inputs, each in the order of [`Computation::INPUTS`].
+/// * `outputs` - the buffer for the packed sets of outputs, each in the order of [`Computation::OUTPUTS`].
+///
+/// ## Returns
+///
+/// Returns [`Error::BatchSizeMismatch`] if the lengths of the slices don't correspond to the same number of sets.
+pub fn evaluate_batch<C: Computation>(
+    number_of_model: u8,
+    inputs: &[f64],
+    outputs: &mut [f64],
+) -> Result<()> {
+    let number_of_inputs = C::INPUTS.len();
+    let number_of_outputs = C::OUTPUTS.len();
+    if !inputs.len().is_multiple_of(number_of_inputs)
+        || outputs.len() != inputs.len() / number_of_inputs * number_of_outputs
+    {
+        return Err(Error::BatchSizeMismatch {
+            inputs: inputs.len(),
+            outputs: outputs.len(),
+        });
+    }
+
+    let evaluate = |(inputs, outputs): (&[f64], &mut [f64])| -> Result<()> {
+        outputs.copy_from_slice(&C::compute(number_of_model, inputs)?);
+        Ok(())
+    };
+    let nested_res = crate::catch_unwind(core::panic::AssertUnwindSafe(|| {
+        #[cfg(feature = "rayon")]
+        let res = inputs
+            .par_chunks(number_of_inputs)
+            .zip(outputs.par_chunks_mut(number_of_outputs))
+            .try_for_each(evaluate);
+        #[cfg(not(feature = "rayon"))]
+        let res = inputs
+            .chunks(number_of_inputs)
+            .zip(outputs.chunks_mut(number_of_outputs))
+            .try_for_each(evaluate);
+        res
+    }));
+    match nested_res {
+        Ok(res) => res,
+        Err(err) => Err(err),
+    }
+}
+
+/// Evaluates the computation for many sets of inputs at once with the [structure of arrays] layout.
+///
+/// See [`evaluate_batch`] for the details.
+///
+/// ## Arguments
+///
+/// * `number_of_model` - the number of the selected model of the computation.
+/// * `inputs` - the columns of inputs in the order of [`Computation::INPUTS`], i.e. `inputs[j][i]`
+///   is the `j`-th input of the `i`-th set.
+/// * `outputs` - the buffers for the columns of outputs in the order of [`Computation::OUTPUTS`].
+///
+/// ## Returns
+///
+/// Returns [`Error::WrongNumberOfInputs`] if the number of columns of inputs is wrong and
+/// [`Error::BatchSizeMismatch`] if the columns are of different lengths.
+///
+/// [structure of arrays]: https://en.wikipedia.org/wiki/AoS_and_SoA
+pub fn evaluate_batch_columns<C: Computation>(
+    number_of_model: u8,
+    inputs: &[&[f64]],
+    outputs: &mut [&mut [f64]],
+) -> Result<()> {
+    if inputs.len() != C::INPUTS.len() {
+        return Err(Error::WrongNumberOfInputs {
+            expected: C::INPUTS.len(),
+            actual: inputs.len(),
+        });
+    }
+    let number_of_sets = inputs.first().map_or(0, |column| column.len());
+    if outputs.len() != C::OUTPUTS.len()
+        || inputs.iter().any(|column| column.len() != number_of_sets)
+        || outputs.iter().any(|column| column.len() != number_of_sets)
+    {
+        return Err(Error::BatchSizeMismatch {
+            inputs: inputs.iter().map(|column| column.len()).sum(),
+            outputs: outputs.iter().map(|column| column.len()).sum(),
+        });
+    }
+
+    let mut packed_inputs = Vec::with_capacity(number_of_sets * inputs.len());
+    for i in 0..number_of_sets {
+        packed_inputs.extend(inputs.iter().map(|column| column[i]));
+    }
+    let mut packed_outputs = vec![0.0; number_of_sets * outputs.len()];
+    evaluate_batch::<C>(number_of_model, &packed_inputs, &mut packed_outputs)?;
+    for (i, set) in packed_outputs.chunks(outputs.len().max(1)).enumerate() {
+        for (column, &output) in outputs.iter_mut().zip(set) {
+            column[i] = output;
+        }
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        thermal_conductivity_for_unidirectional_composite,
+        ThermalConductivityForUnidirectionalComposite,
+    };
+
+    #[test]
+    fn test() {
+        let inputs = [0.2, 100.0, 1.0, 0.4, 100.0, 1.0, 0.6, 50.0, 2.0];
+        let mut outputs = [0.0; 9];
+        evaluate_batch::<ThermalConductivityForUnidirectionalComposite>(2, &inputs, &mut outputs)
+            .unwrap();
+        for (inputs, outputs) in inputs.chunks(3).zip(outputs.chunks(3)) {
+            let expected = thermal_conductivity_for_unidirectional_composite(
+                2, inputs[0], inputs[1], inputs[2],
+            )
+            .unwrap();
+            assert_eq!(outputs, expected);
+        }
+
+        let fibre_content = [0.2, 0.4, 0.6];
+        let k_for_fiber = [100.0, 100.0, 50.0];
+        let k_for_matrix = [1.0, 1.0, 2.0];
+        let [mut k1, mut k2, mut k3] = [[0.0; 3]; 3];
+        evaluate_batch_columns::<ThermalConductivityForUnidirectionalComposite>(
+            2,
+            &[&fibre_content, &k_for_fiber, &k_for_matrix],
+            &mut [&mut k1, &mut k2, &mut k3],
+        )
+        .unwrap();
+        assert_eq!([k1[1], k2[1], k3[1]], outputs[3..6]);
+
+        assert!(matches!(
+            evaluate_batch::<ThermalConductivityForUnidirectionalComposite>(
+                2,
+                &inputs,
+                &mut [0.0; 6]
+            ),
+            Err(Error::BatchSizeMismatch { .. })
+        ));
+    }
+}
@@ -0,0 +1,244 @@
+//! The case files describing a single computation.
+//!
+//! A case file is a JSON object or a TOML document with the following keys:
+//!
+//! * `function` - the name of the function, e.g. `"elastic_modules_for_unidirectional_composite"`.
+//! * `number_of_model` - the number of the selected model of the function.
+//! * `unit_system` and `output_unit_system` - optional, `"consistent"` (the default), `"si"` or `"engineering"`,
+//!   see [`UnitSystem`].
+//! * `inputs` - the inputs by name, e.g. `fibre_content = 0.6`.
+//! * `materials` - optional, the names of the materials of the [`MaterialLibrary`] by role, e.g. `fiber = "T300"`.
+//!   The properties of the materials are in SI units.
+//!
+//! ```toml
+//! function = "elastic_modules_for_unidirectional_composite"
+//! number_of_model = 2
+//! unit_system = "si"
+//! output_unit_system = "engineering"
+//!
+//! [inputs]
+//! fibre_content = 0.6
+//!
+//! [materials]
+//! fiber = "T300"
+//! matrix = "3501-6"
+//! ```
+//!
+//! The result file is the case file with the `outputs` added by name.
+
+use crate::{
+    computation::Computation, effective_properties, materials::MaterialLibrary, units::UnitSystem,
+    ElasticModulesForHoneycomb, ElasticModulesForUnidirectionalComposite, Error, Result,
+    ThermalConductivityForUnidirectionalComposite, ThermalExpansionForHoneycomb,
+    ThermalExpansionForUnidirectionalComposite,
+};
+use std::collections::BTreeMap;
+
+/// The format of a case file.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum CaseFormat {
+    Json,
+    Toml,
+}
+
+impl CaseFormat {
+    /// Returns the format of the file with the `.json` or `.toml` extension.
+    pub fn from_path(path: &std::path::Path) -> Result<Self> {
+        match path.extension().and_then(|ext| ext.to_str()) {
+            Some("json") => Ok(Self::Json),
+            Some("toml") => Ok(Self::Toml),
+            _ => Err(Error::InvalidCaseFile(format!(
+                "unsupported file extension of {}",
+                path.display()
+            ))),
+        }
+    }
+}
+
+/// A single computation, see the [module](self) for the format.
+#[derive(Clone, Debug, PartialEq, serde::Serialize, serde::Deserialize)]
+pub struct Case {
+    pub function: String,
+    pub number_of_model: u8,
+    #[serde(default)]
+    pub unit_system: UnitSystem,
+    #[serde(default)]
+    pub output_unit_system: UnitSystem,
+    pub inputs: BTreeMap<String, f64>,
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub materials: BTreeMap<String, String>,
+}
+
+/// The case with the outputs.
+#[derive(Clone, Debug, PartialEq, serde::Serialize, serde::Deserialize)]
+pub struct CaseResult {
+    #[serde(flatten)]
+    pub case: Case,
+    pub outputs: BTreeMap<String, f64>,
+}
+
+impl Case {
+    /// Parses the case in the given format.
+    pub fn parse(s: &str, format: CaseFormat) -> Result<Self> {
+        match format {
+            CaseFormat::Json => {
+                serde_json::from_str(s).map_err(|err| Error::InvalidCaseFile(err.to_string()))
+            }
+            CaseFormat::Toml => {
+                toml::from_str(s).map_err(|err| Error::InvalidCaseFile(err.to_string()))
+            }
+        }
+    }
+
+    /// Loads the case from the `.json` or `.toml` file.
+    pub fn load(path: impl AsRef<std::path::Path>) -> Result<Self> {
+        let path = path.as_ref();
+        let s =
+            std::fs::read_to_string(path).map_err(|err| Error::InvalidCaseFile(err.to_string()))?;
+        Self::parse(&s, CaseFormat::from_path(path)?)
+    }
+
+    /// Runs the computation of the case.
+    ///
+    /// `effective_properties` prints its results, so its case has no outputs.
+    pub fn run(&self, library: &MaterialLibrary) -> Result<CaseResult> {
+        let outputs = match self.function.as_str() {
+            "elastic_modules_for_unidirectional_composite" => {
+                self.run_computation::<ElasticModulesForUnidirectionalComposite>(library)?
+            }
+            "elastic_modules_for_honeycomb" => {
+                self.run_computation::<ElasticModulesForHoneycomb>(library)?
+            }
+            "thermal_conductivity_for_unidirectional_composite" => {
+                self.run_computation::<ThermalConductivityForUnidirectionalComposite>(library)?
+            }
+            "thermal_expansion_for_unidirectional_composite" => {
+                self.run_computation::<ThermalExpansionForUnidirectionalComposite>(library)?
+            }
+            "thermal_expansion_for_honeycomb" => {
+                self.run_computation::<ThermalExpansionForHoneycomb>(library)?
+            }
+            "effective_properties" => {
+                let input = |name: &'static str| {
+                    self.inputs
+                        .get(name)
+                        .copied()
+                        .ok_or(Error::ExpectedArgumentMissing(name))
+                };
+                effective_properties(
+                    self.number_of_model,
+                    input("l_x")?,
+                    input("l_y")?,
+                    input("l_z")?,
+                    input("f_x")?,
+                    input("f_y")?,
+                    input("f_z")?,
+                    input("uuu")?,
+                    self.inputs.get("u_for_nu_1").copied(),
+                    self.inputs.get("u_for_nu_2").copied(),
+                )?;
+                BTreeMap::new()
+            }
+            function => return Err(Error::UnknownFunction(function.to_owned())),
+        };
+        Ok(CaseResult {
+            case: self.clone(),
+            outputs,
+        })
+    }
+
+    fn run_computation<C: Computation>(
+        &self,
+        library: &MaterialLibrary,
+    ) -> Result<BTreeMap<String, f64>> {
+        // the properties of the materials are in SI units, so the given inputs are converted first
+        let values: Vec<(&str, f64)> = self
+            .inputs
+            .iter()
+            .map(|(name, &value)| {
+                let dimension = C::input_index(name)
+                    .map(|i| C::INPUT_DIMENSIONS[i])
+                    .ok_or_else(|| Error::InvalidCaseFile(format!("unknown input `{name}`")))?;
+                Ok((
+                    name.as_str(),
+                    self.unit_system.convert(value, dimension, UnitSystem::Si),
+                ))
+            })
+            .collect::<Result<_>>()?;
+        let materials: Vec<(&str, &str)> = self
+            .materials
+            .iter()
+            .map(|(role, name)| (role.as_str(), name.as_str()))
+            .collect();
+        let inputs = library.inputs_for::<C>(&values, &materials)?;
+        let outputs = C::evaluate(self.number_of_model, &inputs)?;
+        Ok(C::OUTPUTS
+            .iter()
+            .zip(C::OUTPUT_DIMENSIONS)
+            .zip(outputs)
+            .map(|((&name, &dimension), output)| {
+                (
+                    name.to_owned(),
+                    UnitSystem::Si.convert(output, dimension, self.output_unit_system),
+                )
+            })
+            .collect())
+    }
+}
+
+impl CaseResult {
+    /// Writes the result in the given format.
+    pub fn to_string(&self, format: CaseFormat) -> Result<String> {
+        match format {
+            CaseFormat::Json => serde_json::to_string_pretty(self)
+                .map_err(|err| Error::InvalidCaseFile(err.to_string())),
+            CaseFormat::Toml => {
+                toml::to_string(self).map_err(|err| Error::InvalidCaseFile(err.to_string()))
+            }
+        }
+    }
+
+    /// Saves the result to the `.json` or `.toml` file.
+    pub fn save(&self, path: impl AsRef<std::path::Path>) -> Result<()> {
+        let path = path.as_ref();
+        let s = self.to_string(CaseFormat::from_path(path)?)?;
+        std::fs::write(path, s).map_err(|err| Error::InvalidCaseFile(err.to_string()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test() {
+        let library = MaterialLibrary::builtin();
+        let case = Case::parse(
+            r#"
+            function = "elastic_modules_for_unidirectional_composite"
+            number_of_model = 2
+            output_unit_system = "engineering"
+
+            [inputs]
+            fibre_content = 0.6
+
+            [materials]
+            fiber = "T300"
+            matrix = "3501-6"
+            "#,
+            CaseFormat::Toml,
+        )
+        .unwrap();
+        let result = case.run(&library).unwrap();
+        let [e1, ..] =
+            crate::elastic_modules_for_unidirectional_composite(2, 0.6, 230e9, 0.2, 4.3e9, 0.35)
+                .unwrap();
+        assert_eq!(result.outputs["e1"], e1 / 1e9);
+
+        for format in [CaseFormat::Json, CaseFormat::Toml] {
+            let s = result.to_string(format).unwrap();
+            let case = Case::parse(&s, format).unwrap();
+            assert_eq!(case.run(&library).unwrap(), result);
+        }
+    }
+}
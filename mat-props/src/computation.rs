@@ -0,0 +1,55 @@
+use crate::{
+    scalar::{Scalar, ScalarOps},
+    units::Dimension,
+    Error, Result,
+};
+use alloc::vec::Vec;
+
+/// A model of `mat-props` seen as a function of real inputs that can be evaluated over any [`Scalar`].
+///
+/// It is implemented by the unit structs named after the functions they stand for,
+/// e.g. [`ElasticModulesForUnidirectionalComposite`](crate::ElasticModulesForUnidirectionalComposite)
+/// for [`elastic_modules_for_unidirectional_composite`](crate::elastic_modules_for_unidirectional_composite).
+pub trait Computation {
+    /// The names of the inputs in the order of the arguments of the function,
+    /// excluding `number_of_model`.
+    const INPUTS: &'static [&'static str];
+    /// The names of the outputs in the order of the returned array.
+    const OUTPUTS: &'static [&'static str];
+    /// The physical dimensions of the inputs in the order of [`Computation::INPUTS`].
+    const INPUT_DIMENSIONS: &'static [Dimension];
+    /// The physical dimensions of the outputs in the order of [`Computation::OUTPUTS`].
+    const OUTPUT_DIMENSIONS: &'static [Dimension];
+
+    /// Evaluates the computation over any [`Scalar`]. Unlike [`Computation::evaluate`], it doesn't catch panics.
+    fn compute<T: Scalar>(number_of_model: u8, inputs: &[T]) -> Result<Vec<T>>
+    where
+        f64: ScalarOps<T>;
+
+    /// Evaluates the computation for the given inputs.
+    fn evaluate(number_of_model: u8, inputs: &[f64]) -> Result<Vec<f64>> {
+        let nested_res = crate::catch_unwind(|| Self::compute(number_of_model, inputs));
+        match nested_res {
+            Ok(res) => res,
+            Err(err) => Err(err),
+        }
+    }
+
+    /// Returns the index of the input with the given name.
+    fn input_index(name: &str) -> Option<usize> {
+        Self::INPUTS.iter().position(|&input| input == name)
+    }
+
+    /// Returns the index of the output with the given name.
+    fn output_index(name: &str) -> Option<usize> {
+        Self::OUTPUTS.iter().position(|&output| output == name)
+    }
+}
+
+/// Converts the slice of inputs into the array to be destructured.
+pub(crate) fn unpack_inputs<T: Copy, const N: usize>(inputs: &[T]) -> Result<[T; N]> {
+    inputs.try_into().map_err(|_| Error::WrongNumberOfInputs {
+        expected: N,
+        actual: inputs.len(),
+    })
+}
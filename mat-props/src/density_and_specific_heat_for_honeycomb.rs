@@ -0,0 +1,70 @@
+use crate::Result;
+#[cfg(not(any(feature = "std", test)))]
+use num_traits::Float;
+
+/// Computes relative density, [density] and [specific heat capacity] for the honeycomb structure.
+///
+/// The relative density `rho / rho_for_honeycomb` is the fraction of the cell cross-section
+/// occupied by the walls (the solid fraction of a cellular solid in Gibson and Ashby),
+/// computed from the same cell geometry as in [`elastic_modules_for_honeycomb`](crate::elastic_modules_for_honeycomb).
+///
+/// ## Arguments
+///
+/// * `l_cell_side_size` - the side length of the hexagonal cell.
+/// * `h_cell_side_size` - the height of the hexagonal cell.
+/// * `wall_thickness` - the thickness of the cell walls.
+/// * `angle` - the angle of inclination of the hexagonal cell.
+/// * `rho_for_honeycomb` - the [density] of the honeycomb material.
+/// * `c_for_honeycomb` - the [specific heat capacity] of the honeycomb material, if known.
+///
+/// ## Returns
+///
+/// Returns the array in the following order:
+///
+/// * `rho_relative` - the relative density of the honeycomb.
+/// * `rho` - the [density] of the honeycomb.
+/// * `c` - the [specific heat capacity] of the honeycomb. The air in the cells is neglected,
+///   so it is `c_for_honeycomb` or `NaN` if it was not provided.
+///
+/// [density]: https://en.wikipedia.org/wiki/Density
+/// [specific heat capacity]: https://en.wikipedia.org/wiki/Specific_heat_capacity
+pub fn density_and_specific_heat_for_honeycomb(
+    l_cell_side_size: f64,
+    h_cell_side_size: f64,
+    wall_thickness: f64,
+    angle: f64,
+    rho_for_honeycomb: f64,
+    c_for_honeycomb: Option<f64>,
+) -> Result<[f64; 3]> {
+    crate::catch_unwind(|| {
+        let lb = l_cell_side_size - wall_thickness / (2.0 * angle.cos());
+        let hb = h_cell_side_size - wall_thickness * (1.0 - angle.sin()) / angle.cos();
+        let rho_relative = 1.0
+            - (lb * (hb + lb * angle.sin()))
+                / (l_cell_side_size * (h_cell_side_size + l_cell_side_size * angle.sin()));
+        let rho = rho_relative * rho_for_honeycomb;
+        let c = c_for_honeycomb.unwrap_or(f64::NAN);
+        [rho_relative, rho, c]
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test() {
+        let [rho_relative, rho, c] = density_and_specific_heat_for_honeycomb(
+            9.24,
+            8.4619,
+            0.4,
+            std::f64::consts::PI / 6.0,
+            2680.0,
+            Some(900.0),
+        )
+        .unwrap();
+        assert_eq!(rho_relative, 0.05081179781985545);
+        assert_eq!(rho, 136.17561815721263);
+        assert_eq!(c, 900.0);
+    }
+}
@@ -0,0 +1,66 @@
+use crate::Result;
+
+/// Computes [density] and [specific heat capacity] for unidirectional composite.
+///
+/// ## Arguments
+///
+/// * `fibre_content` - the fibre content in the range from `0.0` to `1.0` where `0.0` is the matrix and `1.0` is the fibre.
+/// * `rho_for_fiber` - the [density] of the fibre.
+/// * `rho_for_matrix` - the [density] of the matrix.
+/// * `c_for_fiber` - the [specific heat capacity] of the fibre, if known.
+/// * `c_for_matrix` - the [specific heat capacity] of the matrix, if known.
+///
+/// ## Returns
+///
+/// Returns the array in the following order:
+///
+/// * `rho` - the [density] of the composite (rule of mixtures by volume).
+/// * `c` - the [specific heat capacity] of the composite (rule of mixtures by mass).
+///   It is `NaN` unless both `c_for_fiber` and `c_for_matrix` are provided.
+///
+/// [density]: https://en.wikipedia.org/wiki/Density
+/// [specific heat capacity]: https://en.wikipedia.org/wiki/Specific_heat_capacity
+pub fn density_and_specific_heat_for_unidirectional_composite(
+    fibre_content: f64,
+    rho_for_fiber: f64,
+    rho_for_matrix: f64,
+    c_for_fiber: Option<f64>,
+    c_for_matrix: Option<f64>,
+) -> Result<[f64; 2]> {
+    crate::catch_unwind(|| {
+        let rho = fibre_content * rho_for_fiber + (1.0 - fibre_content) * rho_for_matrix;
+        let c = match (c_for_fiber, c_for_matrix) {
+            (Some(c_for_fiber), Some(c_for_matrix)) => {
+                (fibre_content * rho_for_fiber * c_for_fiber
+                    + (1.0 - fibre_content) * rho_for_matrix * c_for_matrix)
+                    / rho
+            }
+            _ => f64::NAN,
+        };
+        [rho, c]
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test() {
+        let [rho, c] = density_and_specific_heat_for_unidirectional_composite(
+            0.6,
+            1760.0,
+            1200.0,
+            Some(750.0),
+            Some(1100.0),
+        )
+        .unwrap();
+        assert_eq!(rho, 1536.0);
+        assert_eq!(c, 859.375);
+
+        let [_, c] =
+            density_and_specific_heat_for_unidirectional_composite(0.6, 1760.0, 1200.0, None, None)
+                .unwrap();
+        assert!(c.is_nan());
+    }
+}
@@ -0,0 +1,518 @@
+use crate::{
+    computation::Computation,
+    density_and_specific_heat_for_honeycomb::density_and_specific_heat_for_honeycomb,
+    identification::Unknown, ElasticModulesForHoneycomb, Error, Result,
+};
+use alloc::{borrow::Cow, borrow::ToOwned, vec, vec::Vec};
+
+const MAX_ITERATIONS: usize = 200;
+const NUMBER_OF_SUBINTERVALS: usize = 16;
+
+/// Finds the value of the input of the computation that gives the target value of the output
+/// with [Brent's method].
+///
+/// The bracket is scanned for the first change of sign, so the smallest solution within
+/// the bracket is found if there are several.
+///
+/// ## Arguments
+///
+/// * `number_of_model` - the number of the selected model of the computation.
+/// * `inputs` - the inputs in the order of [`Computation::INPUTS`]. The value of the sought input is ignored.
+/// * `unknown` - the sought input and the bracket in which it is sought.
+/// * `output` - the name of the output, see [`Computation::OUTPUTS`].
+/// * `target` - the target value of the output.
+///
+/// ## Returns
+///
+/// Returns the value of the input or [`Error::NoSolution`] if the output doesn't reach
+/// the target within the bracket.
+///
+/// [Brent's method]: https://en.wikipedia.org/wiki/Brent%27s_method
+pub fn goal_seek<C: Computation>(
+    number_of_model: u8,
+    inputs: &[f64],
+    unknown: Unknown,
+    output: &str,
+    target: f64,
+) -> Result<f64> {
+    if inputs.len() != C::INPUTS.len() {
+        return Err(Error::WrongNumberOfInputs {
+            expected: C::INPUTS.len(),
+            actual: inputs.len(),
+        });
+    }
+    let i = C::input_index(&unknown.input)
+        .ok_or_else(|| Error::UnknownInput(unknown.input.clone().into_owned()))?;
+    let k = C::output_index(output).ok_or_else(|| Error::UnknownOutput(output.to_owned()))?;
+    let mut inputs = inputs.to_vec();
+    let mut residual = |x: f64| -> Result<f64> {
+        inputs[i] = x;
+        Ok(C::evaluate(number_of_model, &inputs)?[k] - target)
+    };
+
+    let step = (unknown.max - unknown.min) / NUMBER_OF_SUBINTERVALS as f64;
+    let mut a = unknown.min;
+    let mut fa = residual(a)?;
+    for n in 1..=NUMBER_OF_SUBINTERVALS {
+        let b = if n == NUMBER_OF_SUBINTERVALS {
+            unknown.max
+        } else {
+            unknown.min + n as f64 * step
+        };
+        let fb = residual(b)?;
+        if fa == 0.0 {
+            return Ok(a);
+        }
+        if fa * fb <= 0.0 {
+            return brent(&mut residual, a, b, fa, fb);
+        }
+        a = b;
+        fa = fb;
+    }
+    Err(Error::NoSolution {
+        output: output.to_owned(),
+        target,
+    })
+}
+
+fn brent(
+    f: &mut impl FnMut(f64) -> Result<f64>,
+    mut a: f64,
+    mut b: f64,
+    mut fa: f64,
+    mut fb: f64,
+) -> Result<f64> {
+    if fa.abs() < fb.abs() {
+        core::mem::swap(&mut a, &mut b);
+        core::mem::swap(&mut fa, &mut fb);
+    }
+    let mut c = a;
+    let mut fc = fa;
+    let mut d = b - a;
+    let mut bisected = true;
+    for _ in 0..MAX_ITERATIONS {
+        if fb == 0.0 || (b - a).abs() <= 4.0 * f64::EPSILON * b.abs() {
+            return Ok(b);
+        }
+        let mut s = if fa != fc && fb != fc {
+            // Inverse quadratic interpolation
+            a * fb * fc / ((fa - fb) * (fa - fc))
+                + b * fa * fc / ((fb - fa) * (fb - fc))
+                + c * fa * fb / ((fc - fa) * (fc - fb))
+        } else {
+            // Secant method
+            b - fb * (b - a) / (fb - fa)
+        };
+        let tolerance = 2.0 * f64::EPSILON * b.abs();
+        let outside = (s - (3.0 * a + b) / 4.0) * (s - b) >= 0.0;
+        if outside
+            || (bisected && (s - b).abs() >= (b - c).abs() / 2.0)
+            || (!bisected && (s - b).abs() >= (c - d).abs() / 2.0)
+            || (bisected && (b - c).abs() < tolerance)
+            || (!bisected && (c - d).abs() < tolerance)
+        {
+            s = (a + b) / 2.0;
+            bisected = true;
+        } else {
+            bisected = false;
+        }
+        let fs = f(s)?;
+        d = c;
+        c = b;
+        fc = fb;
+        if fa * fs < 0.0 {
+            b = s;
+            fb = fs;
+        } else {
+            a = s;
+            fa = fs;
+        }
+        if fa.abs() < fb.abs() {
+            core::mem::swap(&mut a, &mut b);
+            core::mem::swap(&mut fa, &mut fb);
+        }
+    }
+    Ok(b)
+}
+
+/// The lower bound of an output of [`elastic_modules_for_honeycomb`](crate::elastic_modules_for_honeycomb).
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Constraint {
+    /// The name of the output, see [`Computation::OUTPUTS`].
+    pub output: Cow<'static, str>,
+    /// The smallest allowed value of the output.
+    pub min: f64,
+}
+
+/// The result of [`minimize_density_for_honeycomb`].
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct HoneycombDesign {
+    /// The optimal values of the variables in the same order.
+    pub values: Vec<f64>,
+    /// The relative density, the density of the optimal honeycomb over the density of its
+    /// material.
+    pub rho_relative: f64,
+    /// The elastic modules of the optimal honeycomb in the order of
+    /// [`elastic_modules_for_honeycomb`](crate::elastic_modules_for_honeycomb).
+    pub elastic_modules: Vec<f64>,
+}
+
+/// Minimizes the density of the honeycomb over its geometry subject to the lower bounds of
+/// its elastic modules.
+///
+/// The constraints are enforced by the quadratic penalty with the growing weight, and
+/// each penalized problem is solved by the [Nelder–Mead method] within the bounds of the variables.
+///
+/// ## Arguments
+///
+/// * `number_of_model` - the number of the selected model, see
+///   [`elastic_modules_for_honeycomb`](crate::elastic_modules_for_honeycomb).
+/// * `inputs` - the inputs of [`elastic_modules_for_honeycomb`](crate::elastic_modules_for_honeycomb)
+///   in the order of [`ElasticModulesForHoneycomb::INPUTS`]. The values of the variables are the initial guesses.
+/// * `variables` - the geometry parameters to be optimized: `l_cell_side_size`, `h_cell_side_size`,
+///   `wall_thickness` or `angle`. Their bounds must be finite.
+/// * `constraints` - the lower bounds of the elastic modules, which must be positive.
+///
+/// ## Returns
+///
+/// Returns the [`HoneycombDesign`] or [`Error::NoFeasibleDesign`] if the constraints can't be
+/// satisfied within the bounds of the variables.
+///
+/// [Nelder–Mead method]: https://en.wikipedia.org/wiki/Nelder%E2%80%93Mead_method
+pub fn minimize_density_for_honeycomb(
+    number_of_model: u8,
+    inputs: &[f64],
+    variables: &[Unknown],
+    constraints: &[Constraint],
+) -> Result<HoneycombDesign> {
+    type C = ElasticModulesForHoneycomb;
+    if inputs.len() != C::INPUTS.len() {
+        return Err(Error::WrongNumberOfInputs {
+            expected: C::INPUTS.len(),
+            actual: inputs.len(),
+        });
+    }
+    let variable_indices = variables
+        .iter()
+        .map(|variable| match C::input_index(&variable.input) {
+            Some(i) if i < 4 => Ok(i),
+            _ => Err(Error::UnknownInput(variable.input.clone().into_owned())),
+        })
+        .collect::<Result<Vec<_>>>()?;
+    let output_indices = constraints
+        .iter()
+        .map(|constraint| {
+            C::output_index(&constraint.output)
+                .ok_or_else(|| Error::UnknownOutput(constraint.output.clone().into_owned()))
+        })
+        .collect::<Result<Vec<_>>>()?;
+    if variables.iter().any(|variable| {
+        !variable.min.is_finite() || !variable.max.is_finite() || variable.min > variable.max
+    }) {
+        return Err(Error::InvalidArgument {
+            name: "variables",
+            reason: "the bounds must be finite and min must not be greater than max",
+        });
+    }
+    // The violations are relative to the bounds
+    if constraints
+        .iter()
+        .any(|constraint| !constraint.min.is_finite() || constraint.min <= 0.0)
+    {
+        return Err(Error::InvalidArgument {
+            name: "constraints",
+            reason: "the lower bounds must be finite and positive",
+        });
+    }
+
+    let evaluate = |values: &[f64]| -> Result<(Vec<f64>, f64, Vec<f64>)> {
+        let mut inputs = inputs.to_vec();
+        for (&i, &value) in variable_indices.iter().zip(values) {
+            inputs[i] = value;
+        }
+        let [rho_relative, _, _] = density_and_specific_heat_for_honeycomb(
+            inputs[0], inputs[1], inputs[2], inputs[3], 1.0, None,
+        )?;
+        let elastic_modules = C::evaluate(number_of_model, &inputs)?;
+        // The relative violations of the constraints
+        let violations = output_indices
+            .iter()
+            .zip(constraints)
+            .map(|(&k, constraint)| (1.0 - elastic_modules[k] / constraint.min).max(0.0))
+            .collect();
+        Ok((violations, rho_relative, elastic_modules))
+    };
+    let clamp = |values: &mut [f64]| {
+        for (value, variable) in values.iter_mut().zip(variables) {
+            *value = value.clamp(variable.min, variable.max);
+        }
+    };
+
+    let mut values: Vec<f64> = variable_indices.iter().map(|&i| inputs[i]).collect();
+    clamp(&mut values);
+    let mut weight = 1e2;
+    while weight <= 1e12 {
+        let penalized = |values: &[f64]| -> Result<f64> {
+            let (violations, rho_relative, _) = evaluate(values)?;
+            let penalty: f64 = violations.iter().map(|v| v * v).sum();
+            Ok(if rho_relative.is_nan() || penalty.is_nan() {
+                f64::INFINITY
+            } else {
+                rho_relative + weight * penalty
+            })
+        };
+        values = nelder_mead(penalized, &values, variables, clamp)?;
+        weight *= 1e2;
+    }
+
+    let (violations, rho_relative, elastic_modules) = evaluate(&values)?;
+    if violations.iter().any(|&v| v > 1e-6) {
+        return Err(Error::NoFeasibleDesign);
+    }
+    Ok(HoneycombDesign {
+        values,
+        rho_relative,
+        elastic_modules,
+    })
+}
+
+fn nelder_mead(
+    f: impl Fn(&[f64]) -> Result<f64>,
+    start: &[f64],
+    variables: &[Unknown],
+    clamp: impl Fn(&mut [f64]),
+) -> Result<Vec<f64>> {
+    let n = start.len();
+    let mut simplex = vec![start.to_vec()];
+    for (i, variable) in variables.iter().enumerate() {
+        let mut vertex = start.to_vec();
+        let step = 0.05 * (variable.max - variable.min);
+        vertex[i] = if vertex[i] + step <= variable.max {
+            vertex[i] + step
+        } else {
+            vertex[i] - step
+        };
+        simplex.push(vertex);
+    }
+    let mut values = simplex
+        .iter()
+        .map(|vertex| f(vertex))
+        .collect::<Result<Vec<_>>>()?;
+    let point = |from: &[f64], to: &[f64], t: f64| -> Vec<f64> {
+        let mut point: Vec<f64> = from.iter().zip(to).map(|(a, b)| a + t * (b - a)).collect();
+        clamp(&mut point);
+        point
+    };
+
+    for _ in 0..MAX_ITERATIONS * (n + 1) {
+        let mut order: Vec<usize> = (0..=n).collect();
+        order.sort_by(|&i, &j| values[i].total_cmp(&values[j]));
+        simplex = order.iter().map(|&i| simplex[i].clone()).collect();
+        values = order.iter().map(|&i| values[i]).collect();
+        if (values[n] - values[0]).abs() <= 1e-14 * values[0].abs().max(1e-14) {
+            break;
+        }
+
+        let centroid: Vec<f64> = (0..n)
+            .map(|j| simplex[..n].iter().map(|vertex| vertex[j]).sum::<f64>() / n as f64)
+            .collect();
+        let reflected = point(&simplex[n], &centroid, 2.0);
+        let reflected_value = f(&reflected)?;
+        if reflected_value < values[0] {
+            let expanded = point(&simplex[n], &centroid, 3.0);
+            let expanded_value = f(&expanded)?;
+            if expanded_value < reflected_value {
+                simplex[n] = expanded;
+                values[n] = expanded_value;
+            } else {
+                simplex[n] = reflected;
+                values[n] = reflected_value;
+            }
+        } else if reflected_value < values[n - 1] {
+            simplex[n] = reflected;
+            values[n] = reflected_value;
+        } else {
+            let contracted = point(&simplex[n], &centroid, 0.5);
+            let contracted_value = f(&contracted)?;
+            if contracted_value < values[n] {
+                simplex[n] = contracted;
+                values[n] = contracted_value;
+            } else {
+                for i in 1..=n {
+                    simplex[i] = point(&simplex[0], &simplex[i], 0.5);
+                    values[i] = f(&simplex[i])?;
+                }
+            }
+        }
+    }
+    let best = (0..=n)
+        .min_by(|&i, &j| values[i].total_cmp(&values[j]))
+        .unwrap_or(0);
+    Ok(simplex.swap_remove(best))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        elastic_modules_for_honeycomb, elastic_modules_for_unidirectional_composite,
+        ElasticModulesForUnidirectionalComposite,
+    };
+    use std::f64::consts::PI;
+
+    #[test]
+    fn test() {
+        let fibre_content = goal_seek::<ElasticModulesForUnidirectionalComposite>(
+            1,
+            &[0.0, 230.0, 0.2, 3.5, 0.35],
+            Unknown {
+                input: "fibre_content".into(),
+                min: 0.0,
+                max: 1.0,
+            },
+            "e1",
+            60.0,
+        )
+        .unwrap();
+        let [e1, ..] =
+            elastic_modules_for_unidirectional_composite(1, fibre_content, 230.0, 0.2, 3.5, 0.35)
+                .unwrap();
+        assert!((e1 - 60.0).abs() < 1e-9);
+
+        // The output is chosen at runtime, e.g. read from a case file
+        let output = String::from("e1");
+        assert!(matches!(
+            goal_seek::<ElasticModulesForUnidirectionalComposite>(
+                1,
+                &[0.0, 230.0, 0.2, 3.5, 0.35],
+                Unknown {
+                    input: "fibre_content".into(),
+                    min: 0.0,
+                    max: 1.0,
+                },
+                &output,
+                300.0,
+            ),
+            Err(Error::NoSolution { output, .. }) if output == "e1"
+        ));
+
+        let wall_thickness = goal_seek::<ElasticModulesForHoneycomb>(
+            1,
+            &[9.24, 8.4619, 0.0, PI / 6.0, 7.07, 0.2],
+            Unknown {
+                input: "wall_thickness".into(),
+                min: 0.01,
+                max: 2.0,
+            },
+            "g13",
+            0.1,
+        )
+        .unwrap();
+        let [.., g13, _] =
+            elastic_modules_for_honeycomb(1, 9.24, 8.4619, wall_thickness, PI / 6.0, 7.07, 0.2)
+                .unwrap();
+        assert!((g13 - 0.1).abs() < 1e-12);
+    }
+
+    #[test]
+    fn minimize_density_test() {
+        let design = minimize_density_for_honeycomb(
+            1,
+            &[9.24, 8.4619, 0.4, PI / 6.0, 7.07, 0.2],
+            &[
+                Unknown {
+                    input: "wall_thickness".into(),
+                    min: 0.05,
+                    max: 1.0,
+                },
+                Unknown {
+                    input: "angle".into(),
+                    min: 0.0,
+                    max: PI / 3.0,
+                },
+            ],
+            &[
+                Constraint {
+                    output: "e3".into(),
+                    min: 0.2,
+                },
+                Constraint {
+                    output: "g13".into(),
+                    min: 0.05,
+                },
+            ],
+        )
+        .unwrap();
+        assert!(design.elastic_modules[2] >= 0.2 * (1.0 - 1e-6));
+        assert!(design.elastic_modules[7] >= 0.05 * (1.0 - 1e-6));
+        // E3 is proportional to the relative density, so the E3 constraint is active at the optimum
+        assert!((design.elastic_modules[2] - 0.2).abs() < 1e-4);
+
+        assert!(matches!(
+            minimize_density_for_honeycomb(
+                1,
+                &[9.24, 8.4619, 0.4, PI / 6.0, 7.07, 0.2],
+                &[Unknown {
+                    input: "wall_thickness".into(),
+                    min: 0.05,
+                    max: 0.1,
+                }],
+                &[Constraint {
+                    output: "e3".into(),
+                    min: 7.0,
+                }],
+            ),
+            Err(Error::NoFeasibleDesign)
+        ));
+
+        let minimize = |variable: Unknown, constraint: Constraint| {
+            minimize_density_for_honeycomb(
+                1,
+                &[9.24, 8.4619, 0.4, PI / 6.0, 7.07, 0.2],
+                &[variable],
+                &[constraint],
+            )
+        };
+        let wall_thickness = |min, max| Unknown {
+            input: "wall_thickness".into(),
+            min,
+            max,
+        };
+        let e3 = |min| Constraint {
+            output: "e3".into(),
+            min,
+        };
+        for variable in [wall_thickness(1.0, 0.05), wall_thickness(f64::NAN, 1.0)] {
+            assert!(matches!(
+                minimize(variable, e3(0.2)),
+                Err(Error::InvalidArgument {
+                    name: "variables",
+                    ..
+                })
+            ));
+        }
+        assert!(matches!(
+            minimize(wall_thickness(0.05, 1.0), e3(0.0)),
+            Err(Error::InvalidArgument {
+                name: "constraints",
+                ..
+            })
+        ));
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn constraint_round_trips() {
+        let constraint = Constraint {
+            output: "e3".into(),
+            min: 1e8,
+        };
+        let json = serde_json::to_string(&constraint).unwrap();
+        assert_eq!(
+            serde_json::from_str::<Constraint>(&json).unwrap(),
+            constraint
+        );
+    }
+}
@@ -0,0 +1,176 @@
+use crate::scalar::Scalar;
+use core::ops::{Add, Div, Mul, Neg, Sub};
+
+/// The [dual number] `re + eps * ε` where `ε² = 0`.
+///
+/// Evaluating a model over dual numbers with `eps = 1.0` for one of the inputs
+/// (and `eps = 0.0` for the rest) yields the derivatives of the outputs with respect to that input
+/// in the `eps` parts, i.e. it is the forward-mode [automatic differentiation].
+///
+/// [dual number]: https://en.wikipedia.org/wiki/Dual_number
+/// [automatic differentiation]: https://en.wikipedia.org/wiki/Automatic_differentiation
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Dual {
+    /// The real part, i.e. the value.
+    pub re: f64,
+    /// The dual part, i.e. the derivative.
+    pub eps: f64,
+}
+
+impl Dual {
+    pub const fn new(re: f64, eps: f64) -> Self {
+        Self { re, eps }
+    }
+
+    /// The independent variable, i.e. the one with respect to which the derivatives are taken.
+    pub const fn variable(re: f64) -> Self {
+        Self { re, eps: 1.0 }
+    }
+}
+
+impl From<f64> for Dual {
+    fn from(re: f64) -> Self {
+        Self { re, eps: 0.0 }
+    }
+}
+
+impl Add for Dual {
+    type Output = Self;
+
+    fn add(self, rhs: Self) -> Self {
+        Self::new(self.re + rhs.re, self.eps + rhs.eps)
+    }
+}
+
+impl Sub for Dual {
+    type Output = Self;
+
+    fn sub(self, rhs: Self) -> Self {
+        Self::new(self.re - rhs.re, self.eps - rhs.eps)
+    }
+}
+
+impl Mul for Dual {
+    type Output = Self;
+
+    fn mul(self, rhs: Self) -> Self {
+        Self::new(self.re * rhs.re, self.eps * rhs.re + self.re * rhs.eps)
+    }
+}
+
+impl Div for Dual {
+    type Output = Self;
+
+    fn div(self, rhs: Self) -> Self {
+        Self::new(
+            self.re / rhs.re,
+            (self.eps * rhs.re - self.re * rhs.eps) / (rhs.re * rhs.re),
+        )
+    }
+}
+
+impl Neg for Dual {
+    type Output = Self;
+
+    fn neg(self) -> Self {
+        Self::new(-self.re, -self.eps)
+    }
+}
+
+impl Add<f64> for Dual {
+    type Output = Self;
+
+    fn add(self, rhs: f64) -> Self {
+        Self::new(self.re + rhs, self.eps)
+    }
+}
+
+impl Sub<f64> for Dual {
+    type Output = Self;
+
+    fn sub(self, rhs: f64) -> Self {
+        Self::new(self.re - rhs, self.eps)
+    }
+}
+
+impl Mul<f64> for Dual {
+    type Output = Self;
+
+    fn mul(self, rhs: f64) -> Self {
+        Self::new(self.re * rhs, self.eps * rhs)
+    }
+}
+
+impl Div<f64> for Dual {
+    type Output = Self;
+
+    fn div(self, rhs: f64) -> Self {
+        Self::new(self.re / rhs, self.eps / rhs)
+    }
+}
+
+impl Add<Dual> for f64 {
+    type Output = Dual;
+
+    fn add(self, rhs: Dual) -> Dual {
+        Dual::new(self + rhs.re, rhs.eps)
+    }
+}
+
+impl Sub<Dual> for f64 {
+    type Output = Dual;
+
+    fn sub(self, rhs: Dual) -> Dual {
+        Dual::new(self - rhs.re, -rhs.eps)
+    }
+}
+
+impl Mul<Dual> for f64 {
+    type Output = Dual;
+
+    fn mul(self, rhs: Dual) -> Dual {
+        Dual::new(self * rhs.re, self * rhs.eps)
+    }
+}
+
+impl Div<Dual> for f64 {
+    type Output = Dual;
+
+    fn div(self, rhs: Dual) -> Dual {
+        Dual::new(self / rhs.re, -self * rhs.eps / (rhs.re * rhs.re))
+    }
+}
+
+impl Scalar for Dual {
+    fn sin(self) -> Self {
+        Self::new(self.re.sin(), self.eps * self.re.cos())
+    }
+
+    fn cos(self) -> Self {
+        Self::new(self.re.cos(), -self.eps * self.re.sin())
+    }
+
+    fn tan(self) -> Self {
+        let tan = self.re.tan();
+        Self::new(tan, self.eps * (1.0 + tan * tan))
+    }
+
+    fn powf(self, n: f64) -> Self {
+        Self::new(self.re.powf(n), self.eps * n * self.re.powf(n - 1.0))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test() {
+        // d/dx (sin(x) * x^3 / (1 + x)) at x = 2
+        let x = Dual::variable(2.0);
+        let y = x.sin() * x.powf(3.0) / (1.0 + x);
+        let expected = (2.0f64.cos() * 8.0 + 2.0f64.sin() * 12.0) / 3.0 - 2.0f64.sin() * 8.0 / 9.0;
+        assert!((y.eps - expected).abs() < 1e-12);
+    }
+}
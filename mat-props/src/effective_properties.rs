@@ -65,6 +65,7 @@ impl ModelResults {
     }
 }
 
+#[allow(clippy::too_many_arguments)]
 pub fn effective_properties(
     number_of_model: u8,
     l_x: f64,
@@ -79,7 +80,7 @@ pub fn effective_properties(
 ) -> Result<()> {
     let model = Model::from_u8(number_of_model).ok_or(Error::UnknownModel)?;
 
-    let nested_res = std::panic::catch_unwind(|| {
+    let nested_res = crate::catch_unwind(|| {
         let res = match model {
             Model::M0 => {
                 let Some(u_for_nu_1) = u_for_nu_1 else {
@@ -150,8 +151,7 @@ pub fn effective_properties(
             }
         };
         Ok(res)
-    })
-    .map_err(Error::NumericalError);
+    });
     let normalized_res = match nested_res {
         Ok(res) => res,
         Err(err) => Err(err),
@@ -160,6 +160,7 @@ pub fn effective_properties(
     Ok(())
 }
 
+#[allow(dead_code)]
 pub fn quadrilateral_area(pos: [[f64; 4]; 2]) {
-    let [v0, v1] = pos;
+    let [_v0, _v1] = pos;
 }
@@ -1,4 +1,10 @@
-use crate::{Error, Result};
+use crate::{
+    computation::{unpack_inputs, Computation},
+    scalar::{Scalar, ScalarOps},
+    units::Dimension,
+    Error, Result,
+};
+use alloc::vec::Vec;
 use enum_primitive_derive::Primitive;
 use num_traits::FromPrimitive;
 
@@ -16,7 +22,7 @@ enum Model {
 /// * `l_cell_side_size` - the side length of the hexagonal cell.
 /// * `h_cell_side_size` - the height of the hexagonal cell.
 /// * `wall_thickness` - the thickness of the cell walls.
-/// * `angle` - the angle of inclination of the hexagonal cell.
+/// * `angle` - the angle of inclination of the hexagonal cell in radians.
 /// * `e_for_honeycomb` - Young's modulus for the honeycomb material.
 /// * `nu_for_honeycomb` - Poisson's ratio for the honeycomb material.
 ///
@@ -49,90 +55,191 @@ pub fn elastic_modules_for_honeycomb(
 ) -> Result<[f64; 9]> {
     let model = Model::from_u8(number_of_model).ok_or(Error::UnknownModel)?;
 
-    std::panic::catch_unwind(|| {
-        let g_for_honeycomb = e_for_honeycomb / (2.0 * (1.0 + nu_for_honeycomb));
-        match model {
-            Model::Vanin => {
-                let lb = l_cell_side_size - wall_thickness / (2.0 * angle.cos());
-                let hb = h_cell_side_size - wall_thickness * (1.0 - angle.sin()) / angle.cos();
-                let e1 = e_for_honeycomb
-                    * (wall_thickness / lb).powf(3.0)
-                    * (angle.cos()
-                        / ((h_cell_side_size / l_cell_side_size + angle.sin())
-                            * angle.sin()
-                            * angle.sin()))
-                    * (1.0
-                        / (1.0
-                            + (2.4 + 1.5 * nu_for_honeycomb + 1.0 / (angle.tan() * angle.tan()))
-                                * (wall_thickness * wall_thickness)
-                                / (lb * lb)));
-                let e2 = e_for_honeycomb
-                    * (wall_thickness / lb).powf(3.0)
-                    * ((h_cell_side_size / l_cell_side_size + angle.sin())
-                        / (angle.cos() * angle.cos() * angle.cos()))
-                    * (1.0
-                        / (1.0
-                            + (2.4
-                                + 1.5 * nu_for_honeycomb
-                                + angle.tan() * angle.tan()
-                                + (2.0 * hb / lb) / (angle.cos() * angle.cos()))
-                                * (wall_thickness * wall_thickness)
-                                / (lb * lb)));
-                let e3 = e_for_honeycomb
-                    * (1.0
-                        - (lb * (hb + lb * angle.sin()))
-                            / (l_cell_side_size
-                                * (h_cell_side_size + l_cell_side_size * angle.sin())));
-                let nu12 = ((angle.cos() * angle.cos())
-                    / ((h_cell_side_size / l_cell_side_size + angle.sin()) * angle.sin()))
-                    * ((1.0
-                        + (1.4 + 1.5 * nu_for_honeycomb) * (wall_thickness * wall_thickness)
-                            / (lb * lb))
-                        / (1.0
-                            + (2.4 + 1.5 * nu_for_honeycomb + 1.0 / (angle.tan() * angle.tan()))
-                                * (wall_thickness * wall_thickness)
-                                / (lb * lb)));
-                let nu13 = e1 / e3 * nu_for_honeycomb;
-                let nu23 = e2 / e3 * nu_for_honeycomb;
-                let c = 1.0
-                    + 2.0 * hb / lb
-                    + (wall_thickness * wall_thickness) / (lb * lb)
-                        * ((2.4 + 1.5 * nu_for_honeycomb)
-                            / (hb / lb
-                                * (2.0 + h_cell_side_size / l_cell_side_size + angle.sin()))
-                            + (h_cell_side_size / l_cell_side_size + angle.sin())
-                                / ((wall_thickness * wall_thickness) / (lb * lb))
-                                * ((h_cell_side_size / l_cell_side_size + angle.sin())
-                                    * angle.tan()
-                                    * angle.tan()
-                                    + angle.sin()));
-                let g12 = e_for_honeycomb
-                    * (wall_thickness / lb).powf(3.0)
-                    * (h_cell_side_size / l_cell_side_size + angle.sin())
-                    / ((hb * hb) / (lb * lb) * angle.cos())
-                    * 1.0
-                    / c;
-                let g13 = g_for_honeycomb
-                    * (((wall_thickness) / (l_cell_side_size))
-                        / (((h_cell_side_size) / (l_cell_side_size) + angle.sin()) * angle.cos()))
-                    * (angle.cos() * angle.cos() * lb / l_cell_side_size
-                        + 0.75 * wall_thickness / l_cell_side_size * 2.0 * angle.tan()
-                        - angle.cos() / 2.0 * wall_thickness / l_cell_side_size
-                            * (2.0 * angle.sin() - 1.0));
-                let g23 = g_for_honeycomb
-                    * (((wall_thickness) / (l_cell_side_size))
-                        / (((h_cell_side_size) / (l_cell_side_size) + angle.sin()) * angle.cos()))
-                    * (angle.sin() * angle.sin() * lb / l_cell_side_size
-                        + hb / (2.0 * l_cell_side_size)
-                        + 0.75 * wall_thickness / l_cell_side_size * 2.0 * angle.tan()
-                        - (angle.sin() * angle.sin()) / (2.0 * angle.cos()) * wall_thickness
-                            / l_cell_side_size
-                            * (2.0 * angle.sin() - 1.0));
-                [e1, e2, e3, nu12, nu13, nu23, g12, g13, g23]
-            }
-        }
+    crate::catch_unwind(|| {
+        compute(
+            model,
+            l_cell_side_size,
+            h_cell_side_size,
+            wall_thickness,
+            angle,
+            e_for_honeycomb,
+            nu_for_honeycomb,
+        )
     })
-    .map_err(Error::NumericalError)
+}
+
+/// Generic counterpart of [`elastic_modules_for_honeycomb`] that can be evaluated
+/// over any [`Scalar`]. Unlike the former, it doesn't catch panics.
+pub(crate) fn elastic_modules_for_honeycomb_generic<T: Scalar>(
+    number_of_model: u8,
+    l_cell_side_size: T,
+    h_cell_side_size: T,
+    wall_thickness: T,
+    angle: T,
+    e_for_honeycomb: T,
+    nu_for_honeycomb: T,
+) -> Result<[T; 9]>
+where
+    f64: ScalarOps<T>,
+{
+    let model = Model::from_u8(number_of_model).ok_or(Error::UnknownModel)?;
+
+    Ok(compute(
+        model,
+        l_cell_side_size,
+        h_cell_side_size,
+        wall_thickness,
+        angle,
+        e_for_honeycomb,
+        nu_for_honeycomb,
+    ))
+}
+
+/// The [`Computation`] standing for [`elastic_modules_for_honeycomb`].
+pub struct ElasticModulesForHoneycomb;
+
+impl Computation for ElasticModulesForHoneycomb {
+    const INPUTS: &'static [&'static str] = &[
+        "l_cell_side_size",
+        "h_cell_side_size",
+        "wall_thickness",
+        "angle",
+        "e_for_honeycomb",
+        "nu_for_honeycomb",
+    ];
+    const OUTPUTS: &'static [&'static str] = &[
+        "e1", "e2", "e3", "nu12", "nu13", "nu23", "g12", "g13", "g23",
+    ];
+    const INPUT_DIMENSIONS: &'static [Dimension] = &[
+        Dimension::Length,
+        Dimension::Length,
+        Dimension::Length,
+        Dimension::Angle,
+        Dimension::Pressure,
+        Dimension::Dimensionless,
+    ];
+    const OUTPUT_DIMENSIONS: &'static [Dimension] = &[
+        Dimension::Pressure,
+        Dimension::Pressure,
+        Dimension::Pressure,
+        Dimension::Dimensionless,
+        Dimension::Dimensionless,
+        Dimension::Dimensionless,
+        Dimension::Pressure,
+        Dimension::Pressure,
+        Dimension::Pressure,
+    ];
+
+    fn compute<T: Scalar>(number_of_model: u8, inputs: &[T]) -> Result<Vec<T>>
+    where
+        f64: ScalarOps<T>,
+    {
+        let [l_cell_side_size, h_cell_side_size, wall_thickness, angle, e_for_honeycomb, nu_for_honeycomb] =
+            unpack_inputs(inputs)?;
+        elastic_modules_for_honeycomb_generic(
+            number_of_model,
+            l_cell_side_size,
+            h_cell_side_size,
+            wall_thickness,
+            angle,
+            e_for_honeycomb,
+            nu_for_honeycomb,
+        )
+        .map(Vec::from)
+    }
+}
+
+fn compute<T: Scalar>(
+    model: Model,
+    l_cell_side_size: T,
+    h_cell_side_size: T,
+    wall_thickness: T,
+    angle: T,
+    e_for_honeycomb: T,
+    nu_for_honeycomb: T,
+) -> [T; 9]
+where
+    f64: ScalarOps<T>,
+{
+    let g_for_honeycomb = e_for_honeycomb / (2.0 * (1.0 + nu_for_honeycomb));
+    match model {
+        Model::Vanin => {
+            let lb = l_cell_side_size - wall_thickness / (2.0 * angle.cos());
+            let hb = h_cell_side_size - wall_thickness * (1.0 - angle.sin()) / angle.cos();
+            let e1 = e_for_honeycomb
+                * (wall_thickness / lb).powf(3.0)
+                * (angle.cos()
+                    / ((h_cell_side_size / l_cell_side_size + angle.sin())
+                        * angle.sin()
+                        * angle.sin()))
+                * (1.0
+                    / (1.0
+                        + (2.4 + 1.5 * nu_for_honeycomb + 1.0 / (angle.tan() * angle.tan()))
+                            * (wall_thickness * wall_thickness)
+                            / (lb * lb)));
+            let e2 = e_for_honeycomb
+                * (wall_thickness / lb).powf(3.0)
+                * ((h_cell_side_size / l_cell_side_size + angle.sin())
+                    / (angle.cos() * angle.cos() * angle.cos()))
+                * (1.0
+                    / (1.0
+                        + (2.4
+                            + 1.5 * nu_for_honeycomb
+                            + angle.tan() * angle.tan()
+                            + (2.0 * hb / lb) / (angle.cos() * angle.cos()))
+                            * (wall_thickness * wall_thickness)
+                            / (lb * lb)));
+            let e3 = e_for_honeycomb
+                * (1.0
+                    - (lb * (hb + lb * angle.sin()))
+                        / (l_cell_side_size * (h_cell_side_size + l_cell_side_size * angle.sin())));
+            let nu12 = ((angle.cos() * angle.cos())
+                / ((h_cell_side_size / l_cell_side_size + angle.sin()) * angle.sin()))
+                * ((1.0
+                    + (1.4 + 1.5 * nu_for_honeycomb) * (wall_thickness * wall_thickness)
+                        / (lb * lb))
+                    / (1.0
+                        + (2.4 + 1.5 * nu_for_honeycomb + 1.0 / (angle.tan() * angle.tan()))
+                            * (wall_thickness * wall_thickness)
+                            / (lb * lb)));
+            let nu13 = e1 / e3 * nu_for_honeycomb;
+            let nu23 = e2 / e3 * nu_for_honeycomb;
+            let c = 1.0
+                + 2.0 * hb / lb
+                + (wall_thickness * wall_thickness) / (lb * lb)
+                    * ((2.4 + 1.5 * nu_for_honeycomb)
+                        / (hb / lb * (2.0 + h_cell_side_size / l_cell_side_size + angle.sin()))
+                        + (h_cell_side_size / l_cell_side_size + angle.sin())
+                            / ((wall_thickness * wall_thickness) / (lb * lb))
+                            * ((h_cell_side_size / l_cell_side_size + angle.sin())
+                                * angle.tan()
+                                * angle.tan()
+                                + angle.sin()));
+            let g12 = e_for_honeycomb
+                * (wall_thickness / lb).powf(3.0)
+                * (h_cell_side_size / l_cell_side_size + angle.sin())
+                / ((hb * hb) / (lb * lb) * angle.cos())
+                * 1.0
+                / c;
+            let g13 = g_for_honeycomb
+                * (((wall_thickness) / (l_cell_side_size))
+                    / (((h_cell_side_size) / (l_cell_side_size) + angle.sin()) * angle.cos()))
+                * (angle.cos() * angle.cos() * lb / l_cell_side_size
+                    + 0.75 * wall_thickness / l_cell_side_size * 2.0 * angle.tan()
+                    - angle.cos() / 2.0 * wall_thickness / l_cell_side_size
+                        * (2.0 * angle.sin() - 1.0));
+            let g23 = g_for_honeycomb
+                * (((wall_thickness) / (l_cell_side_size))
+                    / (((h_cell_side_size) / (l_cell_side_size) + angle.sin()) * angle.cos()))
+                * (angle.sin() * angle.sin() * lb / l_cell_side_size
+                    + hb / (2.0 * l_cell_side_size)
+                    + 0.75 * wall_thickness / l_cell_side_size * 2.0 * angle.tan()
+                    - (angle.sin() * angle.sin()) / (2.0 * angle.cos()) * wall_thickness
+                        / l_cell_side_size
+                        * (2.0 * angle.sin() - 1.0));
+            [e1, e2, e3, nu12, nu13, nu23, g12, g13, g23]
+        }
+    }
 }
 
 #[cfg(test)]
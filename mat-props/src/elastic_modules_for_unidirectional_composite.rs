@@ -1,4 +1,10 @@
-use crate::{Error, Result};
+use crate::{
+    computation::{unpack_inputs, Computation},
+    scalar::{Scalar, ScalarOps},
+    units::Dimension,
+    Error, Result,
+};
+use alloc::vec::Vec;
 use enum_primitive_derive::Primitive;
 use num_traits::FromPrimitive;
 
@@ -20,7 +26,7 @@ enum Model {
 ///
 /// * `number_of_model` - the number of model, the discriminant in [`Model`].
 /// * `fibre_content` - the fibre content in the range from `0.0` to `1.0` where
-/// `0.0` is the matrix and `1.0` is the fibre.
+///   `0.0` is the matrix and `1.0` is the fibre.
 /// * `e_for_fiber` - the [Young's modulus] for fibre.
 /// * `nu_for_fiber` - the [Poisson's ratio] for fibre.
 /// * `e_for_matrix` - the [Young's modulus] for matrix.
@@ -54,144 +60,225 @@ pub fn elastic_modules_for_unidirectional_composite(
 ) -> Result<[f64; 9]> {
     let model = Model::from_u8(number_of_model).ok_or(Error::UnknownModel)?;
 
-    let res = std::panic::catch_unwind(|| {
-        let g_for_fiber = e_for_fiber / (2.0 * (1.0 + nu_for_fiber));
-        let g_for_matrix = e_for_matrix / (2.0 * (1.0 + nu_for_matrix));
+    crate::catch_unwind(|| {
+        compute(
+            model,
+            fibre_content,
+            e_for_fiber,
+            nu_for_fiber,
+            e_for_matrix,
+            nu_for_matrix,
+        )
+    })
+}
+
+/// Generic counterpart of [`elastic_modules_for_unidirectional_composite`] that can be evaluated
+/// over any [`Scalar`]. Unlike the former, it doesn't catch panics.
+pub(crate) fn elastic_modules_for_unidirectional_composite_generic<T: Scalar>(
+    number_of_model: u8,
+    fibre_content: T,
+    e_for_fiber: T,
+    nu_for_fiber: T,
+    e_for_matrix: T,
+    nu_for_matrix: T,
+) -> Result<[T; 9]>
+where
+    f64: ScalarOps<T>,
+{
+    let model = Model::from_u8(number_of_model).ok_or(Error::UnknownModel)?;
+
+    Ok(compute(
+        model,
+        fibre_content,
+        e_for_fiber,
+        nu_for_fiber,
+        e_for_matrix,
+        nu_for_matrix,
+    ))
+}
+
+/// The [`Computation`] standing for [`elastic_modules_for_unidirectional_composite`].
+pub struct ElasticModulesForUnidirectionalComposite;
 
-        match model {
-            Model::RuleOfMixtures => {
-                let e1 = fibre_content * e_for_fiber + e_for_matrix * (1.0 - fibre_content);
-                let e2 = 1.0 / (fibre_content / e_for_fiber + (1.0 - fibre_content) / e_for_matrix);
-                let e3 = 1.0 / (fibre_content / e_for_fiber + (1.0 - fibre_content) / e_for_matrix);
-                let nu12 = nu_for_fiber * fibre_content + nu_for_matrix * (1.0 - fibre_content);
-                let nu13 = nu_for_fiber * fibre_content + nu_for_matrix * (1.0 - fibre_content);
-                let nu23 = f64::NAN;
-                let g12 = fibre_content * g_for_fiber + g_for_matrix * (1.0 - fibre_content);
-                let g13 = fibre_content * g_for_fiber + g_for_matrix * (1.0 - fibre_content);
-                let g23 = f64::NAN;
-                [e1, e2, e3, nu12, nu13, nu23, g12, g13, g23]
-            }
-            Model::Vanin => {
-                let chi_for_fiber = 3.0 - 4.0 * nu_for_fiber;
-                let chi_for_matrix = 3.0 - 4.0 * nu_for_matrix;
-                let e1 = fibre_content * e_for_fiber
-                    + (1.0 - fibre_content) * e_for_matrix
-                    + (8.0
-                        * g_for_matrix
-                        * (nu_for_fiber - nu_for_matrix)
-                        * (nu_for_fiber - nu_for_matrix)
-                        * fibre_content
-                        * (1.0 - fibre_content))
-                        / (2.0 - fibre_content
-                            + fibre_content * chi_for_matrix
-                            + (1.0 - fibre_content) * (chi_for_fiber - 1.0) * (g_for_matrix)
-                                / (g_for_fiber));
-                let nu21 = nu_for_matrix
-                    - (chi_for_matrix + 1.0) * (nu_for_matrix - nu_for_fiber) * fibre_content
-                        / (2.0 - fibre_content
-                            + fibre_content * chi_for_matrix
-                            + (1.0 - fibre_content) * (chi_for_fiber - 1.0) * g_for_matrix
-                                / g_for_fiber);
-                let nu31 = nu_for_matrix
-                    - (chi_for_matrix + 1.0) * (nu_for_matrix - nu_for_fiber) * fibre_content
-                        / (2.0 - fibre_content
-                            + fibre_content * chi_for_matrix
-                            + (1.0 - fibre_content) * (chi_for_fiber - 1.0) * g_for_matrix
-                                / g_for_fiber);
-                let e2 = 1.0
-                    / (nu21 / e1
-                        + 1.0 / (8.0 * g_for_matrix)
-                            * ((2.0 * (1.0 - fibre_content) * (chi_for_matrix - 1.0)
-                                + (chi_for_fiber - 1.0)
-                                    * (chi_for_matrix - 1.0 + 2.0 * fibre_content)
-                                    * g_for_matrix
-                                    / g_for_fiber)
-                                / (2.0 - fibre_content
-                                    + chi_for_matrix * fibre_content
-                                    + (1.0 - fibre_content)
-                                        * (chi_for_fiber - 1.0)
-                                        * (g_for_matrix)
+impl Computation for ElasticModulesForUnidirectionalComposite {
+    const INPUTS: &'static [&'static str] = &[
+        "fibre_content",
+        "e_for_fiber",
+        "nu_for_fiber",
+        "e_for_matrix",
+        "nu_for_matrix",
+    ];
+    const OUTPUTS: &'static [&'static str] = &[
+        "e1", "e2", "e3", "nu12", "nu13", "nu23", "g12", "g13", "g23",
+    ];
+    const INPUT_DIMENSIONS: &'static [Dimension] = &[
+        Dimension::Dimensionless,
+        Dimension::Pressure,
+        Dimension::Dimensionless,
+        Dimension::Pressure,
+        Dimension::Dimensionless,
+    ];
+    const OUTPUT_DIMENSIONS: &'static [Dimension] = &[
+        Dimension::Pressure,
+        Dimension::Pressure,
+        Dimension::Pressure,
+        Dimension::Dimensionless,
+        Dimension::Dimensionless,
+        Dimension::Dimensionless,
+        Dimension::Pressure,
+        Dimension::Pressure,
+        Dimension::Pressure,
+    ];
+
+    fn compute<T: Scalar>(number_of_model: u8, inputs: &[T]) -> Result<Vec<T>>
+    where
+        f64: ScalarOps<T>,
+    {
+        let [fibre_content, e_for_fiber, nu_for_fiber, e_for_matrix, nu_for_matrix] =
+            unpack_inputs(inputs)?;
+        elastic_modules_for_unidirectional_composite_generic(
+            number_of_model,
+            fibre_content,
+            e_for_fiber,
+            nu_for_fiber,
+            e_for_matrix,
+            nu_for_matrix,
+        )
+        .map(Vec::from)
+    }
+}
+
+fn compute<T: Scalar>(
+    model: Model,
+    fibre_content: T,
+    e_for_fiber: T,
+    nu_for_fiber: T,
+    e_for_matrix: T,
+    nu_for_matrix: T,
+) -> [T; 9]
+where
+    f64: ScalarOps<T>,
+{
+    let g_for_fiber = e_for_fiber / (2.0 * (1.0 + nu_for_fiber));
+    let g_for_matrix = e_for_matrix / (2.0 * (1.0 + nu_for_matrix));
+
+    match model {
+        Model::RuleOfMixtures => {
+            let e1 = fibre_content * e_for_fiber + e_for_matrix * (1.0 - fibre_content);
+            let e2 = 1.0 / (fibre_content / e_for_fiber + (1.0 - fibre_content) / e_for_matrix);
+            let e3 = 1.0 / (fibre_content / e_for_fiber + (1.0 - fibre_content) / e_for_matrix);
+            let nu12 = nu_for_fiber * fibre_content + nu_for_matrix * (1.0 - fibre_content);
+            let nu13 = nu_for_fiber * fibre_content + nu_for_matrix * (1.0 - fibre_content);
+            let nu23 = T::from(f64::NAN);
+            let g12 = fibre_content * g_for_fiber + g_for_matrix * (1.0 - fibre_content);
+            let g13 = fibre_content * g_for_fiber + g_for_matrix * (1.0 - fibre_content);
+            let g23 = T::from(f64::NAN);
+            [e1, e2, e3, nu12, nu13, nu23, g12, g13, g23]
+        }
+        Model::Vanin => {
+            let chi_for_fiber = 3.0 - 4.0 * nu_for_fiber;
+            let chi_for_matrix = 3.0 - 4.0 * nu_for_matrix;
+            let e1 = fibre_content * e_for_fiber
+                + (1.0 - fibre_content) * e_for_matrix
+                + (8.0
+                    * g_for_matrix
+                    * (nu_for_fiber - nu_for_matrix)
+                    * (nu_for_fiber - nu_for_matrix)
+                    * fibre_content
+                    * (1.0 - fibre_content))
+                    / (2.0 - fibre_content
+                        + fibre_content * chi_for_matrix
+                        + (1.0 - fibre_content) * (chi_for_fiber - 1.0) * (g_for_matrix)
+                            / (g_for_fiber));
+            let nu21 = nu_for_matrix
+                - (chi_for_matrix + 1.0) * (nu_for_matrix - nu_for_fiber) * fibre_content
+                    / (2.0 - fibre_content
+                        + fibre_content * chi_for_matrix
+                        + (1.0 - fibre_content) * (chi_for_fiber - 1.0) * g_for_matrix
+                            / g_for_fiber);
+            let nu31 = nu_for_matrix
+                - (chi_for_matrix + 1.0) * (nu_for_matrix - nu_for_fiber) * fibre_content
+                    / (2.0 - fibre_content
+                        + fibre_content * chi_for_matrix
+                        + (1.0 - fibre_content) * (chi_for_fiber - 1.0) * g_for_matrix
+                            / g_for_fiber);
+            let e2 = 1.0
+                / (nu21 / e1
+                    + 1.0 / (8.0 * g_for_matrix)
+                        * ((2.0 * (1.0 - fibre_content) * (chi_for_matrix - 1.0)
+                            + (chi_for_fiber - 1.0)
+                                * (chi_for_matrix - 1.0 + 2.0 * fibre_content)
+                                * g_for_matrix
+                                / g_for_fiber)
+                            / (2.0 - fibre_content
+                                + chi_for_matrix * fibre_content
+                                + (1.0 - fibre_content) * (chi_for_fiber - 1.0) * (g_for_matrix)
+                                    / (g_for_fiber))
+                            + 2.0
+                                * (chi_for_matrix * (1.0 - fibre_content)
+                                    + (1.0 + fibre_content * chi_for_matrix) * (g_for_matrix)
                                         / (g_for_fiber))
-                                + 2.0
-                                    * (chi_for_matrix * (1.0 - fibre_content)
-                                        + (1.0 + fibre_content * chi_for_matrix)
-                                            * (g_for_matrix)
-                                            / (g_for_fiber))
-                                    / (chi_for_matrix
-                                        + fibre_content
-                                        + (1.0 - fibre_content) * (g_for_matrix) / (g_for_fiber))));
-                let e3 = 1.0
-                    / (nu31 / e1
-                        + 1.0 / (8.0 * g_for_matrix)
-                            * ((2.0 * (1.0 - fibre_content) * (chi_for_matrix - 1.0)
-                                + (chi_for_fiber - 1.0)
-                                    * (chi_for_matrix - 1.0 + 2.0 * fibre_content)
-                                    * g_for_matrix
-                                    / g_for_fiber)
-                                / (2.0 - fibre_content
-                                    + chi_for_matrix * fibre_content
-                                    + (1.0 - fibre_content)
-                                        * (chi_for_fiber - 1.0)
-                                        * (g_for_matrix)
+                                / (chi_for_matrix
+                                    + fibre_content
+                                    + (1.0 - fibre_content) * (g_for_matrix) / (g_for_fiber))));
+            let e3 = 1.0
+                / (nu31 / e1
+                    + 1.0 / (8.0 * g_for_matrix)
+                        * ((2.0 * (1.0 - fibre_content) * (chi_for_matrix - 1.0)
+                            + (chi_for_fiber - 1.0)
+                                * (chi_for_matrix - 1.0 + 2.0 * fibre_content)
+                                * g_for_matrix
+                                / g_for_fiber)
+                            / (2.0 - fibre_content
+                                + chi_for_matrix * fibre_content
+                                + (1.0 - fibre_content) * (chi_for_fiber - 1.0) * (g_for_matrix)
+                                    / (g_for_fiber))
+                            + 2.0
+                                * (chi_for_matrix * (1.0 - fibre_content)
+                                    + (1.0 + fibre_content * chi_for_matrix) * (g_for_matrix)
                                         / (g_for_fiber))
-                                + 2.0
-                                    * (chi_for_matrix * (1.0 - fibre_content)
-                                        + (1.0 + fibre_content * chi_for_matrix)
-                                            * (g_for_matrix)
-                                            / (g_for_fiber))
-                                    / (chi_for_matrix
-                                        + fibre_content
-                                        + (1.0 - fibre_content) * (g_for_matrix) / (g_for_fiber))));
-                let nu23 = e2
-                    * (-nu21 / e1
-                        + 1.0 / (8.0 * g_for_matrix)
-                            * (-(2.0 * (1.0 - fibre_content) * (chi_for_matrix - 1.0)
-                                + (chi_for_fiber - 1.0)
-                                    * (chi_for_matrix - 1.0 + 2.0 * fibre_content)
-                                    * g_for_matrix
-                                    / g_for_fiber)
-                                / (2.0 - fibre_content
-                                    + chi_for_matrix * fibre_content
-                                    + (1.0 - fibre_content)
-                                        * (chi_for_fiber - 1.0)
-                                        * (g_for_matrix)
+                                / (chi_for_matrix
+                                    + fibre_content
+                                    + (1.0 - fibre_content) * (g_for_matrix) / (g_for_fiber))));
+            let nu23 = e2
+                * (-nu21 / e1
+                    + 1.0 / (8.0 * g_for_matrix)
+                        * (-(2.0 * (1.0 - fibre_content) * (chi_for_matrix - 1.0)
+                            + (chi_for_fiber - 1.0)
+                                * (chi_for_matrix - 1.0 + 2.0 * fibre_content)
+                                * g_for_matrix
+                                / g_for_fiber)
+                            / (2.0 - fibre_content
+                                + chi_for_matrix * fibre_content
+                                + (1.0 - fibre_content) * (chi_for_fiber - 1.0) * (g_for_matrix)
+                                    / (g_for_fiber))
+                            + 2.0
+                                * (chi_for_matrix * (1.0 - fibre_content)
+                                    + (1.0 + fibre_content * chi_for_matrix) * (g_for_matrix)
                                         / (g_for_fiber))
-                                + 2.0
-                                    * (chi_for_matrix * (1.0 - fibre_content)
-                                        + (1.0 + fibre_content * chi_for_matrix)
-                                            * (g_for_matrix)
-                                            / (g_for_fiber))
-                                    / (chi_for_matrix
-                                        + fibre_content
-                                        + (1.0 - fibre_content) * (g_for_matrix) / (g_for_fiber))));
-                let nu12 = nu21 * e2 / e1;
-                let nu13 = nu31 * e3 / e1;
-                let g12 = 1.0
-                    / ((1.0 / g_for_matrix)
-                        * (1.0 - fibre_content
-                            + (1.0 + fibre_content) * g_for_matrix / g_for_fiber)
-                        / (1.0
-                            + fibre_content
-                            + (1.0 - fibre_content) * g_for_matrix / g_for_fiber));
-                let g13 = 1.0
-                    / ((1.0 / g_for_matrix)
-                        * (1.0 - fibre_content
-                            + (1.0 + fibre_content) * g_for_matrix / g_for_fiber)
-                        / (1.0
-                            + fibre_content
-                            + (1.0 - fibre_content) * g_for_matrix / g_for_fiber));
-                let g23 = 1.0
-                    / ((1.0 / g_for_matrix)
-                        * ((1.0 - fibre_content) * chi_for_matrix
-                            + (1.0 + chi_for_matrix * fibre_content) * g_for_matrix / g_for_fiber)
-                        / (chi_for_matrix
-                            + fibre_content
-                            + (1.0 - fibre_content) * g_for_matrix / g_for_fiber));
-                [e1, e2, e3, nu12, nu13, nu23, g12, g13, g23]
-            }
+                                / (chi_for_matrix
+                                    + fibre_content
+                                    + (1.0 - fibre_content) * (g_for_matrix) / (g_for_fiber))));
+            let nu12 = nu21 * e2 / e1;
+            let nu13 = nu31 * e3 / e1;
+            let g12 = 1.0
+                / ((1.0 / g_for_matrix)
+                    * (1.0 - fibre_content + (1.0 + fibre_content) * g_for_matrix / g_for_fiber)
+                    / (1.0 + fibre_content + (1.0 - fibre_content) * g_for_matrix / g_for_fiber));
+            let g13 = 1.0
+                / ((1.0 / g_for_matrix)
+                    * (1.0 - fibre_content + (1.0 + fibre_content) * g_for_matrix / g_for_fiber)
+                    / (1.0 + fibre_content + (1.0 - fibre_content) * g_for_matrix / g_for_fiber));
+            let g23 = 1.0
+                / ((1.0 / g_for_matrix)
+                    * ((1.0 - fibre_content) * chi_for_matrix
+                        + (1.0 + chi_for_matrix * fibre_content) * g_for_matrix / g_for_fiber)
+                    / (chi_for_matrix
+                        + fibre_content
+                        + (1.0 - fibre_content) * g_for_matrix / g_for_fiber));
+            [e1, e2, e3, nu12, nu13, nu23, g12, g13, g23]
         }
-    });
-    res.map_err(Error::NumericalError)
+    }
 }
 
 #[cfg(test)]
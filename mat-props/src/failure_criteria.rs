@@ -0,0 +1,261 @@
+use crate::{Error, Result};
+use alloc::vec::Vec;
+use core::f64::consts::PI;
+use enum_primitive_derive::Primitive;
+#[cfg(not(any(feature = "std", test)))]
+use num_traits::Float;
+use num_traits::FromPrimitive;
+
+#[derive(Primitive, Clone, Copy)]
+enum Criterion {
+    /// The [maximum stress criterion](https://en.wikipedia.org/wiki/Material_failure_theory),
+    /// each stress component over its strength.
+    MaxStress = 1,
+    TsaiHill = 2,
+    TsaiWu = 3,
+    /// Hashin's 1980 criterion with the transverse shear strength taken as `Yc / 2`.
+    Hashin = 4,
+    /// Puck's 2D criterion with the inclination parameters recommended for CFRP.
+    Puck = 5,
+}
+
+// the inclination parameters of Puck's criterion recommended for CFRP
+const P_PERP_PAR_PLUS: f64 = 0.3;
+const P_PERP_PAR_MINUS: f64 = 0.25;
+// the envelope is taken as open beyond this multiple of the largest strength
+const MAX_ENVELOPE_SCALE: f64 = 1e6;
+
+/// Evaluates the [failure criterion] for the plane stress state of a unidirectional ply.
+///
+/// ## Arguments
+///
+/// * `number_of_criterion` - the number of the selected criterion, represented by the discriminant in [`Criterion`].
+/// * `sigma1` - the normal stress in the direction of the fibre.
+/// * `sigma2` - the normal stress in the direction "2" perpendicular to the fibre.
+/// * `tau12` - the in-plane shear stress.
+/// * `strengths` - `[Xt, Xc, Yt, Yc, S12]` as returned by
+///   [`strength_for_unidirectional_composite`](crate::strength_for_unidirectional_composite).
+///
+/// ## Returns
+///
+/// Returns the failure index. The ply fails when it is greater than or equal to `1.0`.
+///
+/// [failure criterion]: https://en.wikipedia.org/wiki/Material_failure_theory
+pub fn failure_index(
+    number_of_criterion: u8,
+    sigma1: f64,
+    sigma2: f64,
+    tau12: f64,
+    strengths: [f64; 5],
+) -> Result<f64> {
+    let criterion = Criterion::from_u8(number_of_criterion).ok_or(Error::UnknownModel)?;
+
+    crate::catch_unwind(|| failure_index_for_criterion(criterion, sigma1, sigma2, tau12, strengths))
+}
+
+/// Computes the points of the [failure envelope] in the `sigma1`-`sigma2` plane for the given
+/// in-plane shear stress.
+///
+/// The points are found by scaling the stress along `number_of_points` evenly spaced
+/// directions until the failure index reaches `1.0`.
+///
+/// ## Arguments
+///
+/// * `number_of_criterion` - the number of the selected criterion, represented by the discriminant in [`Criterion`].
+/// * `strengths` - `[Xt, Xc, Yt, Yc, S12]` as returned by
+///   [`strength_for_unidirectional_composite`](crate::strength_for_unidirectional_composite).
+/// * `tau12` - the in-plane shear stress.
+/// * `number_of_points` - the number of points of the envelope.
+///
+/// ## Returns
+///
+/// Returns the `[sigma1, sigma2]` points of the envelope ordered counterclockwise.
+/// The envelope is empty if the shear stress alone fails the ply.
+///
+/// Returns [`Error::InvalidArgument`] if the envelope is open, i.e. the ply doesn't fail along
+/// some direction even at a million times the largest strength. The quadratic criteria have
+/// open envelopes for some strengths, e.g. Tsai-Hill with `Yt > 2 Xt`.
+///
+/// [failure envelope]: https://en.wikipedia.org/wiki/Material_failure_theory
+pub fn failure_envelope(
+    number_of_criterion: u8,
+    strengths: [f64; 5],
+    tau12: f64,
+    number_of_points: usize,
+) -> Result<Vec<[f64; 2]>> {
+    let criterion = Criterion::from_u8(number_of_criterion).ok_or(Error::UnknownModel)?;
+
+    let nested_res = crate::catch_unwind(|| {
+        let index = |sigma1: f64, sigma2: f64| {
+            failure_index_for_criterion(criterion, sigma1, sigma2, tau12, strengths)
+        };
+        if index(0.0, 0.0) >= 1.0 {
+            return Ok(Vec::new());
+        }
+        // the largest strength is an upper bound for the scale only for the linear criteria
+        let mut scale_bound = strengths.iter().copied().fold(0.0, f64::max);
+        let max_scale = MAX_ENVELOPE_SCALE * scale_bound;
+        (0..number_of_points)
+            .map(|i| {
+                let theta = 2.0 * PI * i as f64 / number_of_points as f64;
+                let (dir1, dir2) = (theta.cos(), theta.sin());
+                while index(scale_bound * dir1, scale_bound * dir2) < 1.0 {
+                    if scale_bound >= max_scale {
+                        return Err(Error::InvalidArgument {
+                            name: "strengths",
+                            reason: "the failure envelope of the criterion is open",
+                        });
+                    }
+                    scale_bound *= 2.0;
+                }
+                let (mut lo, mut hi) = (0.0, scale_bound);
+                for _ in 0..100 {
+                    let mid = 0.5 * (lo + hi);
+                    if index(mid * dir1, mid * dir2) < 1.0 {
+                        lo = mid;
+                    } else {
+                        hi = mid;
+                    }
+                }
+                Ok([hi * dir1, hi * dir2])
+            })
+            .collect()
+    });
+    match nested_res {
+        Ok(res) => res,
+        Err(err) => Err(err),
+    }
+}
+
+fn failure_index_for_criterion(
+    criterion: Criterion,
+    sigma1: f64,
+    sigma2: f64,
+    tau12: f64,
+    strengths: [f64; 5],
+) -> f64 {
+    let [xt, xc, yt, yc, s12] = strengths;
+    let x = if sigma1 >= 0.0 { xt } else { xc };
+    let y = if sigma2 >= 0.0 { yt } else { yc };
+    match criterion {
+        Criterion::MaxStress => (sigma1 / x)
+            .abs()
+            .max((sigma2 / y).abs())
+            .max((tau12 / s12).abs()),
+        Criterion::TsaiHill => {
+            (sigma1 / x) * (sigma1 / x) - sigma1 * sigma2 / (x * x)
+                + (sigma2 / y) * (sigma2 / y)
+                + (tau12 / s12) * (tau12 / s12)
+        }
+        Criterion::TsaiWu => {
+            let f1 = 1.0 / xt - 1.0 / xc;
+            let f2 = 1.0 / yt - 1.0 / yc;
+            let f11 = 1.0 / (xt * xc);
+            let f22 = 1.0 / (yt * yc);
+            let f66 = 1.0 / (s12 * s12);
+            let f12 = -0.5 * (f11 * f22).sqrt();
+            f1 * sigma1
+                + f2 * sigma2
+                + f11 * sigma1 * sigma1
+                + f22 * sigma2 * sigma2
+                + f66 * tau12 * tau12
+                + 2.0 * f12 * sigma1 * sigma2
+        }
+        Criterion::Hashin => {
+            let shear = (tau12 / s12) * (tau12 / s12);
+            let fibre = if sigma1 >= 0.0 {
+                (sigma1 / xt) * (sigma1 / xt) + shear
+            } else {
+                (sigma1 / xc) * (sigma1 / xc)
+            };
+            let matrix = if sigma2 >= 0.0 {
+                (sigma2 / yt) * (sigma2 / yt) + shear
+            } else {
+                let s23 = yc / 2.0;
+                (sigma2 / (2.0 * s23)) * (sigma2 / (2.0 * s23))
+                    + ((yc / (2.0 * s23)) * (yc / (2.0 * s23)) - 1.0) * sigma2 / yc
+                    + shear
+            };
+            fibre.max(matrix)
+        }
+        Criterion::Puck => {
+            let fibre = if sigma1 >= 0.0 {
+                sigma1 / xt
+            } else {
+                -sigma1 / xc
+            };
+            let r_perp_perp_a = s12 / (2.0 * P_PERP_PAR_MINUS)
+                * ((1.0 + 2.0 * P_PERP_PAR_MINUS * yc / s12).sqrt() - 1.0);
+            let p_perp_perp_minus = P_PERP_PAR_MINUS * r_perp_perp_a / s12;
+            let tau21_c = s12 * (1.0 + 2.0 * p_perp_perp_minus).sqrt();
+            let inter_fibre = if sigma2 >= 0.0 {
+                // mode A
+                ((tau12 / s12) * (tau12 / s12)
+                    + (1.0 - P_PERP_PAR_PLUS * yt / s12)
+                        * (1.0 - P_PERP_PAR_PLUS * yt / s12)
+                        * (sigma2 / yt)
+                        * (sigma2 / yt))
+                    .sqrt()
+                    + P_PERP_PAR_PLUS * sigma2 / s12
+            } else if (sigma2 / tau12).abs() <= r_perp_perp_a / tau21_c {
+                // mode B
+                ((tau12 * tau12 + (P_PERP_PAR_MINUS * sigma2) * (P_PERP_PAR_MINUS * sigma2)).sqrt()
+                    + P_PERP_PAR_MINUS * sigma2)
+                    / s12
+            } else {
+                // mode C
+                ((tau12 / (2.0 * (1.0 + p_perp_perp_minus) * s12))
+                    * (tau12 / (2.0 * (1.0 + p_perp_perp_minus) * s12))
+                    + (sigma2 / yc) * (sigma2 / yc))
+                    * yc
+                    / (-sigma2)
+            };
+            fibre.max(inter_fibre)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const STRENGTHS: [f64; 5] = [1500.0, 1200.0, 50.0, 200.0, 70.0];
+
+    #[test]
+    fn test() {
+        let indices = [1, 2, 3, 4, 5]
+            .map(|criterion| failure_index(criterion, 500.0, 20.0, 30.0, STRENGTHS).unwrap());
+        assert_eq!(
+            indices,
+            [
+                0.42857142857142855,
+                0.4503401360544218,
+                0.5046934256933177,
+                0.3436734693877551,
+                0.6171735782210935
+            ]
+        );
+    }
+
+    #[test]
+    fn uniaxial_strengths_lie_on_envelopes() {
+        for criterion in [1, 2, 3, 4, 5] {
+            let envelope = failure_envelope(criterion, STRENGTHS, 0.0, 4).unwrap();
+            let [xt, _xc, yt, _yc, _s12] = STRENGTHS;
+            assert!((envelope[0][0] - xt).abs() < 1e-9);
+            assert!((envelope[1][1] - yt).abs() < 1e-9);
+        }
+    }
+
+    #[test]
+    fn open_envelope_is_an_error() {
+        // Tsai-Hill doesn't fail under the biaxial tension along sigma2 = 2 sigma1 if Yt > 2 Xt
+        assert!(matches!(
+            failure_envelope(2, [100.0, 100.0, 300.0, 300.0, 50.0], 0.0, 64),
+            Err(Error::InvalidArgument {
+                name: "strengths",
+                ..
+            })
+        ));
+    }
+}
@@ -0,0 +1,390 @@
+use crate::{computation::Computation, sensitivities::jacobian, Error, Result};
+use alloc::{borrow::Cow, vec, vec::Vec};
+#[cfg(not(any(feature = "std", test)))]
+use num_traits::Float;
+
+const MAX_ITERATIONS: usize = 200;
+const TOLERANCE: f64 = 1e-12;
+
+/// The input of the computation to be identified.
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Unknown {
+    /// The name of the input, see [`Computation::INPUTS`].
+    pub input: Cow<'static, str>,
+    /// The lower bound of the input.
+    pub min: f64,
+    /// The upper bound of the input, not less than `min`. The bounds may be infinite.
+    pub max: f64,
+}
+
+/// The measured output of the computation.
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Measurement {
+    /// The name of the output, see [`Computation::OUTPUTS`].
+    pub output: Cow<'static, str>,
+    /// The measured value of the output.
+    pub value: f64,
+}
+
+/// The result of [`identify_inputs`].
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Identification {
+    /// The identified values of the unknowns in the same order.
+    pub values: Vec<f64>,
+    /// The [standard errors] of the identified values in the same order. They are NaN if there are
+    /// no more measurements than unknowns or the unknowns can't be told apart by the measurements.
+    ///
+    /// [standard errors]: https://en.wikipedia.org/wiki/Standard_error
+    pub std_errors: Vec<f64>,
+    /// The differences between the computed and the measured outputs in the order of the measurements.
+    pub residuals: Vec<f64>,
+    /// The number of iterations made.
+    pub iterations: usize,
+    /// Whether the iterations converged before reaching the limit. It is `false` as well if
+    /// the iterations stalled, i.e. no step decreased the residuals before the convergence.
+    pub converged: bool,
+}
+
+/// Identifies the unknown inputs of the computation from the measured outputs by the
+/// [Levenberg–Marquardt algorithm].
+///
+/// The sum of squares of the relative residuals `(computed - measured) / measured` is minimized,
+/// so the measurements of different magnitudes have equal weights and must not be zero. The unknowns are kept within their
+/// bounds by projecting each step onto the bounds.
+///
+/// ## Arguments
+///
+/// * `number_of_model` - the number of the selected model of the computation.
+/// * `inputs` - the inputs in the order of [`Computation::INPUTS`]. The values of the unknown inputs are the initial guesses.
+/// * `unknowns` - the inputs to be identified.
+/// * `measurements` - the measured outputs.
+///
+/// ## Returns
+///
+/// Returns the [`Identification`] of the unknowns.
+///
+/// [Levenberg–Marquardt algorithm]: https://en.wikipedia.org/wiki/Levenberg%E2%80%93Marquardt_algorithm
+pub fn identify_inputs<C: Computation>(
+    number_of_model: u8,
+    inputs: &[f64],
+    unknowns: &[Unknown],
+    measurements: &[Measurement],
+) -> Result<Identification> {
+    if inputs.len() != C::INPUTS.len() {
+        return Err(Error::WrongNumberOfInputs {
+            expected: C::INPUTS.len(),
+            actual: inputs.len(),
+        });
+    }
+    let unknown_indices = unknowns
+        .iter()
+        .map(|unknown| {
+            C::input_index(&unknown.input)
+                .ok_or_else(|| Error::UnknownInput(unknown.input.clone().into_owned()))
+        })
+        .collect::<Result<Vec<_>>>()?;
+    let output_indices = measurements
+        .iter()
+        .map(|measurement| {
+            C::output_index(&measurement.output)
+                .ok_or_else(|| Error::UnknownOutput(measurement.output.clone().into_owned()))
+        })
+        .collect::<Result<Vec<_>>>()?;
+    if measurements
+        .iter()
+        .any(|measurement| measurement.value == 0.0 || !measurement.value.is_finite())
+    {
+        return Err(Error::InvalidArgument {
+            name: "measurements",
+            reason: "the measured values must be finite and non-zero",
+        });
+    }
+    if unknowns
+        .iter()
+        .any(|unknown| unknown.min.is_nan() || unknown.max.is_nan() || unknown.min > unknown.max)
+    {
+        return Err(Error::InvalidArgument {
+            name: "unknowns",
+            reason: "the bounds must not be NaN and min must not be greater than max",
+        });
+    }
+
+    let mut inputs = inputs.to_vec();
+    for (&i, unknown) in unknown_indices.iter().zip(unknowns) {
+        inputs[i] = inputs[i].clamp(unknown.min, unknown.max);
+    }
+    let relative_residuals = |inputs: &[f64]| -> Result<Vec<f64>> {
+        let outputs = C::evaluate(number_of_model, inputs)?;
+        Ok(output_indices
+            .iter()
+            .zip(measurements)
+            .map(|(&k, measurement)| (outputs[k] - measurement.value) / measurement.value)
+            .collect())
+    };
+    let relative_jacobian = |inputs: &[f64]| -> Result<Vec<Vec<f64>>> {
+        let jacobian = jacobian::<C>(number_of_model, inputs)?;
+        Ok(output_indices
+            .iter()
+            .zip(measurements)
+            .map(|(&k, measurement)| {
+                unknown_indices
+                    .iter()
+                    .map(|&i| jacobian[k][i] / measurement.value)
+                    .collect()
+            })
+            .collect())
+    };
+    let sum_of_squares = |residuals: &[f64]| residuals.iter().map(|r| r * r).sum::<f64>();
+
+    let p = unknowns.len();
+    let mut residuals = relative_residuals(&inputs)?;
+    let mut lambda = 1e-3;
+    let mut iterations = 0;
+    let mut converged = false;
+    'iterations: while iterations < MAX_ITERATIONS && !converged {
+        iterations += 1;
+        let jacobian = relative_jacobian(&inputs)?;
+        let (normal_matrix, gradient) = normal_equations(&jacobian, &residuals);
+        loop {
+            let mut damped = normal_matrix.clone();
+            for (i, row) in damped.iter_mut().enumerate() {
+                row[i] += lambda * normal_matrix[i][i].max(TOLERANCE);
+            }
+            if let Some(step) = solve(damped, gradient.iter().map(|g| -g).collect()) {
+                let mut trial = inputs.clone();
+                for ((&i, unknown), delta) in unknown_indices.iter().zip(unknowns).zip(&step) {
+                    trial[i] = (trial[i] + delta).clamp(unknown.min, unknown.max);
+                }
+                let trial_residuals = relative_residuals(&trial)?;
+                let decrease = sum_of_squares(&residuals) - sum_of_squares(&trial_residuals);
+                if decrease >= 0.0 {
+                    let change = unknown_indices
+                        .iter()
+                        .map(|&i| (trial[i] - inputs[i]).abs() / inputs[i].abs().max(TOLERANCE))
+                        .fold(0.0, f64::max);
+                    converged = change <= TOLERANCE || decrease <= TOLERANCE * TOLERANCE;
+                    inputs = trial;
+                    residuals = trial_residuals;
+                    lambda = (lambda / 10.0).max(1e-12);
+                    break;
+                }
+            }
+            lambda *= 10.0;
+            if lambda > 1e16 {
+                // No step decreases the residuals, so the iterations stalled.
+                break 'iterations;
+            }
+        }
+    }
+
+    let jacobian = relative_jacobian(&inputs)?;
+    let (normal_matrix, _) = normal_equations(&jacobian, &residuals);
+    let variance = if measurements.len() > p {
+        sum_of_squares(&residuals) / (measurements.len() - p) as f64
+    } else {
+        f64::NAN
+    };
+    let std_errors = (0..p)
+        .map(|i| {
+            let mut unit = vec![0.0; p];
+            unit[i] = 1.0;
+            solve(normal_matrix.clone(), unit)
+                .map_or(f64::NAN, |column| (variance * column[i]).sqrt())
+        })
+        .collect();
+    Ok(Identification {
+        values: unknown_indices.iter().map(|&i| inputs[i]).collect(),
+        std_errors,
+        residuals: residuals
+            .iter()
+            .zip(measurements)
+            .map(|(r, measurement)| r * measurement.value)
+            .collect(),
+        iterations,
+        converged,
+    })
+}
+
+// Returns `JᵀJ` and `Jᵀr`.
+fn normal_equations(jacobian: &[Vec<f64>], residuals: &[f64]) -> (Vec<Vec<f64>>, Vec<f64>) {
+    let p = jacobian.first().map_or(0, Vec::len);
+    let mut normal_matrix = vec![vec![0.0; p]; p];
+    let mut gradient = vec![0.0; p];
+    for (row, r) in jacobian.iter().zip(residuals) {
+        for i in 0..p {
+            gradient[i] += row[i] * r;
+            for j in 0..p {
+                normal_matrix[i][j] += row[i] * row[j];
+            }
+        }
+    }
+    (normal_matrix, gradient)
+}
+
+// Solves the linear system by the Gaussian elimination with partial pivoting.
+fn solve(mut matrix: Vec<Vec<f64>>, mut rhs: Vec<f64>) -> Option<Vec<f64>> {
+    let n = rhs.len();
+    let scale = matrix
+        .iter()
+        .flatten()
+        .fold(0.0, |max: f64, x| max.max(x.abs()));
+    for k in 0..n {
+        let pivot = (k..n).max_by(|&i, &j| matrix[i][k].abs().total_cmp(&matrix[j][k].abs()))?;
+        if matrix[pivot][k].abs() <= TOLERANCE * scale || matrix[pivot][k].is_nan() {
+            return None;
+        }
+        matrix.swap(k, pivot);
+        rhs.swap(k, pivot);
+        for i in k + 1..n {
+            let factor = matrix[i][k] / matrix[k][k];
+            let (upper, lower) = matrix.split_at_mut(i);
+            for (a, b) in lower[0][k..].iter_mut().zip(&upper[k][k..]) {
+                *a -= factor * b;
+            }
+            rhs[i] -= factor * rhs[k];
+        }
+    }
+    for k in (0..n).rev() {
+        let sum: f64 = (k + 1..n).map(|j| matrix[k][j] * rhs[j]).sum();
+        rhs[k] = (rhs[k] - sum) / matrix[k][k];
+    }
+    Some(rhs)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        elastic_modules_for_unidirectional_composite, ElasticModulesForUnidirectionalComposite,
+        ThermalConductivityForUnidirectionalComposite,
+    };
+
+    #[test]
+    fn test() {
+        let [e1, e2, _, _, _, _, g12, _, _] =
+            elastic_modules_for_unidirectional_composite(2, 0.6, 230.0, 0.2, 3.5, 0.35).unwrap();
+        let identification = identify_inputs::<ElasticModulesForUnidirectionalComposite>(
+            2,
+            &[0.6, 200.0, 0.2, 3.5, 0.3],
+            &[
+                Unknown {
+                    input: "e_for_fiber".into(),
+                    min: 100.0,
+                    max: 500.0,
+                },
+                Unknown {
+                    input: "nu_for_matrix".into(),
+                    min: 0.2,
+                    max: 0.45,
+                },
+            ],
+            &[
+                Measurement {
+                    output: "e1".into(),
+                    value: e1,
+                },
+                Measurement {
+                    output: "e2".into(),
+                    value: e2,
+                },
+                Measurement {
+                    output: "g12".into(),
+                    value: g12,
+                },
+            ],
+        )
+        .unwrap();
+        assert!(identification.converged);
+        assert!((identification.values[0] - 230.0).abs() < 1e-6);
+        assert!((identification.values[1] - 0.35).abs() < 1e-6);
+        assert!(identification.residuals.iter().all(|r| r.abs() < 1e-9));
+
+        let identification = identify_inputs::<ThermalConductivityForUnidirectionalComposite>(
+            2,
+            &[0.2, 10.0, 1.0],
+            &[Unknown {
+                input: "k_for_fiber".into(),
+                min: 1.0,
+                max: 50.0,
+            }],
+            &[Measurement {
+                output: "k1".into(),
+                value: 20.8,
+            }],
+        )
+        .unwrap();
+        // The optimum is beyond the upper bound
+        assert_eq!(identification.values[0], 50.0);
+        assert!(identification.std_errors[0].is_nan());
+
+        let err = identify_inputs::<ThermalConductivityForUnidirectionalComposite>(
+            2,
+            &[0.2, 10.0, 1.0],
+            &[Unknown {
+                input: "k_for_fiber".into(),
+                min: 1.0,
+                max: 50.0,
+            }],
+            &[Measurement {
+                output: "k1".into(),
+                value: 0.0,
+            }],
+        )
+        .unwrap_err();
+        assert!(matches!(
+            err,
+            Error::InvalidArgument {
+                name: "measurements",
+                ..
+            }
+        ));
+
+        for (min, max) in [(50.0, 1.0), (f64::NAN, 50.0)] {
+            let err = identify_inputs::<ThermalConductivityForUnidirectionalComposite>(
+                2,
+                &[0.2, 10.0, 1.0],
+                &[Unknown {
+                    input: "k_for_fiber".into(),
+                    min,
+                    max,
+                }],
+                &[Measurement {
+                    output: "k1".into(),
+                    value: 20.8,
+                }],
+            )
+            .unwrap_err();
+            assert!(matches!(
+                err,
+                Error::InvalidArgument {
+                    name: "unknowns",
+                    ..
+                }
+            ));
+        }
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn inputs_round_trip() {
+        let unknown = Unknown {
+            input: "e_for_fiber".into(),
+            min: 100.0,
+            max: 500.0,
+        };
+        let json = serde_json::to_string(&unknown).unwrap();
+        assert_eq!(serde_json::from_str::<Unknown>(&json).unwrap(), unknown);
+        let measurement = Measurement {
+            output: "e1".into(),
+            value: 140.0,
+        };
+        let json = serde_json::to_string(&measurement).unwrap();
+        assert_eq!(
+            serde_json::from_str::<Measurement>(&json).unwrap(),
+            measurement
+        );
+    }
+}
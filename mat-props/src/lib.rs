@@ -1,28 +1,158 @@
+#![cfg_attr(not(feature = "std"), no_std)]
+
+extern crate alloc;
+// the tests use `std` anyway
+#[cfg(all(test, not(feature = "std")))]
+#[macro_use]
+extern crate std;
+
+use alloc::string::String;
 use thiserror::Error;
 
 #[derive(Error, Debug)]
 pub enum Error {
     // the error caught by std::panic::catch_unwind
+    #[cfg(feature = "std")]
     #[error("Numerical error: {0:?}")]
     NumericalError(Box<dyn core::any::Any + Send + 'static>),
     #[error("The argument `{0:?}` was expected to be provided but it was not")]
     ExpectedArgumentMissing(&'static str),
     #[error("Unknown model")]
     UnknownModel,
+    #[error("Expected {expected} inputs but got {actual}")]
+    WrongNumberOfInputs { expected: usize, actual: usize },
+    #[error("Correlation matrix is not square or not positive definite")]
+    InvalidCorrelationMatrix,
+    #[error("Unknown input `{0}`")]
+    UnknownInput(String),
+    #[error("Unknown output `{0}`")]
+    UnknownOutput(String),
+    #[error("No solution gives `{output}` = {target} within the bracket")]
+    NoSolution { output: String, target: f64 },
+    #[error("No design satisfies the constraints within the bounds")]
+    NoFeasibleDesign,
+    #[error("Batch of {inputs} inputs doesn't match {outputs} outputs")]
+    BatchSizeMismatch { inputs: usize, outputs: usize },
+    #[error("Unknown unit system")]
+    UnknownUnitSystem,
+    #[error("Unknown material `{0}`")]
+    UnknownMaterial(String),
+    #[error("Material `{material}` doesn't provide `{property}`")]
+    MissingMaterialProperty {
+        material: String,
+        property: &'static str,
+    },
+    #[error("Invalid material library: {0}")]
+    InvalidMaterialLibrary(String),
+    #[error("Invalid case file: {0}")]
+    InvalidCaseFile(String),
+    #[error("Unknown function `{0}`")]
+    UnknownFunction(String),
+    #[error("Invalid argument `{name}`: {reason}")]
+    InvalidArgument {
+        name: &'static str,
+        reason: &'static str,
+    },
 }
 
 pub type Result<T> = core::result::Result<T, Error>;
 
+/// Runs `f` catching its panics as [`Error::NumericalError`].
+#[cfg(feature = "std")]
+pub(crate) fn catch_unwind<R>(f: impl FnOnce() -> R + core::panic::UnwindSafe) -> Result<R> {
+    std::panic::catch_unwind(f).map_err(Error::NumericalError)
+}
+
+/// Runs `f`, without `std` the panics can't be caught.
+#[cfg(not(feature = "std"))]
+pub(crate) fn catch_unwind<R>(f: impl FnOnce() -> R + core::panic::UnwindSafe) -> Result<R> {
+    Ok(f())
+}
+
+mod batch;
+#[cfg(feature = "serde")]
+mod case;
+mod computation;
+mod density_and_specific_heat_for_honeycomb;
+mod density_and_specific_heat_for_unidirectional_composite;
+mod design;
+mod dual;
+#[cfg(feature = "std")]
 mod effective_properties;
 mod elastic_modules_for_honeycomb;
 mod elastic_modules_for_unidirectional_composite;
+mod failure_criteria;
+mod identification;
+#[cfg(feature = "std")]
+mod materials;
+mod prony_series;
+mod quantities;
+mod scalar;
+mod sensitivities;
+mod specific_elastic_modules_for_honeycomb;
+mod specific_elastic_modules_for_unidirectional_composite;
+mod strength_for_unidirectional_composite;
+mod sweep;
 mod thermal_conductivity_for_unidirectional_composite;
+mod thermal_diffusivity_for_unidirectional_composite;
 mod thermal_expansion_for_honeycomb;
 mod thermal_expansion_for_unidirectional_composite;
+#[cfg(feature = "std")]
+mod uncertainty;
+mod units;
+mod viscoelastic_modules_for_honeycomb;
+mod viscoelastic_modules_for_unidirectional_composite;
 
+pub use batch::{evaluate_batch, evaluate_batch_columns};
+#[cfg(feature = "serde")]
+pub use case::{Case, CaseFormat, CaseResult};
+pub use computation::Computation;
+pub use density_and_specific_heat_for_honeycomb::density_and_specific_heat_for_honeycomb;
+pub use density_and_specific_heat_for_unidirectional_composite::density_and_specific_heat_for_unidirectional_composite;
+pub use design::{goal_seek, minimize_density_for_honeycomb, Constraint, HoneycombDesign};
+pub use dual::Dual;
+#[cfg(feature = "std")]
 pub use effective_properties::effective_properties;
-pub use elastic_modules_for_honeycomb::elastic_modules_for_honeycomb;
-pub use elastic_modules_for_unidirectional_composite::elastic_modules_for_unidirectional_composite;
-pub use thermal_conductivity_for_unidirectional_composite::thermal_conductivity_for_unidirectional_composite;
-pub use thermal_expansion_for_honeycomb::thermal_expansion_for_honeycomb;
-pub use thermal_expansion_for_unidirectional_composite::thermal_expansion_for_unidirectional_composite;
+pub use elastic_modules_for_honeycomb::{
+    elastic_modules_for_honeycomb, ElasticModulesForHoneycomb,
+};
+pub use elastic_modules_for_unidirectional_composite::{
+    elastic_modules_for_unidirectional_composite, ElasticModulesForUnidirectionalComposite,
+};
+pub use failure_criteria::{failure_envelope, failure_index};
+pub use identification::{identify_inputs, Identification, Measurement, Unknown};
+#[cfg(feature = "std")]
+pub use materials::{Material, MaterialKind, MaterialLibrary};
+pub use quantities::{
+    elastic_modules_for_honeycomb_with_units,
+    elastic_modules_for_unidirectional_composite_with_units,
+    thermal_conductivity_for_unidirectional_composite_with_units,
+    thermal_expansion_for_honeycomb_with_units,
+    thermal_expansion_for_unidirectional_composite_with_units, ElasticModules,
+};
+pub use scalar::{Scalar, ScalarOps};
+pub use sensitivities::jacobian;
+pub use specific_elastic_modules_for_honeycomb::specific_elastic_modules_for_honeycomb;
+pub use specific_elastic_modules_for_unidirectional_composite::specific_elastic_modules_for_unidirectional_composite;
+pub use strength_for_unidirectional_composite::strength_for_unidirectional_composite;
+pub use sweep::{sweep, Axis, Grid, SweepTable};
+pub use thermal_conductivity_for_unidirectional_composite::{
+    thermal_conductivity_for_unidirectional_composite,
+    ThermalConductivityForUnidirectionalComposite,
+};
+pub use thermal_diffusivity_for_unidirectional_composite::thermal_diffusivity_for_unidirectional_composite;
+pub use thermal_expansion_for_honeycomb::{
+    thermal_expansion_for_honeycomb, ThermalExpansionForHoneycomb,
+};
+pub use thermal_expansion_for_unidirectional_composite::{
+    thermal_expansion_for_unidirectional_composite, ThermalExpansionForUnidirectionalComposite,
+};
+#[cfg(feature = "std")]
+pub use uncertainty::{propagate_uncertainty, Distribution, Method, OutputStatistics};
+pub use units::{evaluate_in_units, Dimension, UnitSystem};
+pub use viscoelastic_modules_for_honeycomb::{
+    complex_modules_for_honeycomb, relaxation_modules_for_honeycomb,
+};
+pub use viscoelastic_modules_for_unidirectional_composite::{
+    complex_modules_for_unidirectional_composite, relaxation_modules_for_unidirectional_composite,
+};
@@ -0,0 +1,350 @@
+use crate::{computation::Computation, Error, Result};
+
+/// The role of a constituent material in a composite.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(rename_all = "snake_case")
+)]
+pub enum MaterialKind {
+    Fibre,
+    Matrix,
+    /// The material of the walls of a honeycomb core.
+    Core,
+}
+
+/// A constituent material with its properties in SI units.
+///
+/// The properties are named after the prefixes of the inputs of the computations,
+/// e.g. `e` is used for `e_for_fiber`. For fibres, the properties are the longitudinal ones.
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Material {
+    pub name: String,
+    pub kind: MaterialKind,
+    /// [Young's modulus](https://en.wikipedia.org/wiki/Young%27s_modulus), Pa.
+    pub e: Option<f64>,
+    /// [Poisson's ratio](https://en.wikipedia.org/wiki/Poisson%27s_ratio).
+    pub nu: Option<f64>,
+    /// The coefficient of [thermal expansion](https://en.wikipedia.org/wiki/Thermal_expansion), 1/K.
+    pub alpha: Option<f64>,
+    /// [Thermal conductivity](https://en.wikipedia.org/wiki/Thermal_conductivity), W/(m·K).
+    pub k: Option<f64>,
+    /// [Density](https://en.wikipedia.org/wiki/Density), kg/m³.
+    pub rho: Option<f64>,
+    /// The reference the properties are taken from.
+    pub source: String,
+}
+
+impl Material {
+    /// Returns the property with the given name, e.g. `e` or `alpha`.
+    pub fn property(&self, name: &str) -> Option<f64> {
+        match name {
+            "e" => self.e,
+            "nu" => self.nu,
+            "alpha" => self.alpha,
+            "k" => self.k,
+            "rho" => self.rho,
+            _ => None,
+        }
+    }
+}
+
+/// A collection of named constituent materials.
+#[derive(Clone, Debug, Default, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct MaterialLibrary {
+    pub materials: Vec<Material>,
+}
+
+struct BuiltinMaterial {
+    name: &'static str,
+    kind: MaterialKind,
+    e: f64,
+    nu: f64,
+    alpha: Option<f64>,
+    k: f64,
+    rho: f64,
+    source: &'static str,
+}
+
+const BUILTIN_MATERIALS: &[BuiltinMaterial] = &[
+    BuiltinMaterial {
+        name: "T300",
+        kind: MaterialKind::Fibre,
+        e: 230e9,
+        nu: 0.2,
+        alpha: Some(-0.41e-6),
+        k: 10.5,
+        rho: 1760.0,
+        source: "Toray, T300 Technical Data Sheet No. CFA-001",
+    },
+    BuiltinMaterial {
+        name: "T700S",
+        kind: MaterialKind::Fibre,
+        e: 230e9,
+        nu: 0.2,
+        alpha: Some(-0.38e-6),
+        k: 9.4,
+        rho: 1800.0,
+        source: "Toray, T700S Technical Data Sheet No. CFA-005",
+    },
+    BuiltinMaterial {
+        name: "IM7",
+        kind: MaterialKind::Fibre,
+        e: 276e9,
+        nu: 0.2,
+        alpha: Some(-0.64e-6),
+        k: 5.4,
+        rho: 1780.0,
+        source: "Hexcel, HexTow IM7 Product Data Sheet",
+    },
+    BuiltinMaterial {
+        name: "E-glass",
+        kind: MaterialKind::Fibre,
+        e: 72.4e9,
+        nu: 0.22,
+        alpha: Some(5.0e-6),
+        k: 1.3,
+        rho: 2540.0,
+        source: "I. M. Daniel, O. Ishai, Engineering Mechanics of Composite Materials, 2nd ed., Table 2.1",
+    },
+    BuiltinMaterial {
+        name: "S-glass",
+        kind: MaterialKind::Fibre,
+        e: 85.5e9,
+        nu: 0.22,
+        alpha: Some(2.9e-6),
+        k: 1.45,
+        rho: 2490.0,
+        source: "I. M. Daniel, O. Ishai, Engineering Mechanics of Composite Materials, 2nd ed., Table 2.1",
+    },
+    BuiltinMaterial {
+        name: "Kevlar 49",
+        kind: MaterialKind::Fibre,
+        e: 131e9,
+        nu: 0.35,
+        alpha: Some(-2.0e-6),
+        k: 0.04,
+        rho: 1440.0,
+        source: "DuPont, Kevlar Aramid Fiber Technical Guide",
+    },
+    BuiltinMaterial {
+        name: "3501-6",
+        kind: MaterialKind::Matrix,
+        e: 4.3e9,
+        nu: 0.35,
+        alpha: Some(41.4e-6),
+        k: 0.18,
+        rho: 1270.0,
+        source: "I. M. Daniel, O. Ishai, Engineering Mechanics of Composite Materials, 2nd ed., Table 2.2",
+    },
+    BuiltinMaterial {
+        name: "PEEK",
+        kind: MaterialKind::Matrix,
+        e: 3.6e9,
+        nu: 0.4,
+        alpha: Some(47e-6),
+        k: 0.25,
+        rho: 1300.0,
+        source: "Victrex, PEEK 450G Product Data Sheet",
+    },
+    BuiltinMaterial {
+        name: "Al 5052",
+        kind: MaterialKind::Core,
+        e: 70.3e9,
+        nu: 0.33,
+        alpha: Some(23.8e-6),
+        k: 138.0,
+        rho: 2680.0,
+        source: "ASM Handbook, Vol. 2, Properties of Wrought Aluminum Alloys, 5052",
+    },
+    BuiltinMaterial {
+        name: "Nomex",
+        kind: MaterialKind::Core,
+        e: 3.4e9,
+        nu: 0.3,
+        // not given by the manufacturer
+        alpha: None,
+        k: 0.12,
+        rho: 1400.0,
+        source: "DuPont, Nomex Type 410 Technical Data Sheet",
+    },
+];
+
+impl MaterialLibrary {
+    /// Returns the library of the built-in materials: T300, T700S, IM7, E-glass, S-glass and Kevlar 49 fibres,
+    /// 3501-6 epoxy and PEEK matrices, Al 5052 foil and Nomex paper for honeycomb cores.
+    pub fn builtin() -> Self {
+        let materials = BUILTIN_MATERIALS
+            .iter()
+            .map(|material| Material {
+                name: material.name.to_owned(),
+                kind: material.kind,
+                e: Some(material.e),
+                nu: Some(material.nu),
+                alpha: material.alpha,
+                k: Some(material.k),
+                rho: Some(material.rho),
+                source: material.source.to_owned(),
+            })
+            .collect();
+        Self { materials }
+    }
+
+    /// Returns the material with the given name. The names are case-insensitive.
+    pub fn get(&self, name: &str) -> Option<&Material> {
+        self.materials
+            .iter()
+            .find(|material| material.name.eq_ignore_ascii_case(name))
+    }
+
+    /// Adds the materials of the other library, replacing the materials with the same names.
+    pub fn extend(&mut self, other: MaterialLibrary) {
+        for material in other.materials {
+            match self
+                .materials
+                .iter_mut()
+                .find(|m| m.name.eq_ignore_ascii_case(&material.name))
+            {
+                Some(m) => *m = material,
+                None => self.materials.push(material),
+            }
+        }
+    }
+
+    /// Assembles the inputs of the computation from the given values and the properties of the materials.
+    ///
+    /// ## Arguments
+    ///
+    /// * `values` - the inputs given by name, e.g. `("fibre_content", 0.6)`.
+    /// * `materials` - the names of the materials by role, e.g. `("fiber", "T300")`. The input `<property>_for_<role>`,
+    ///   e.g. `e_for_fiber`, is taken from the property of the material unless it is given in `values`.
+    ///
+    /// ## Returns
+    ///
+    /// Returns the inputs in the order of [`Computation::INPUTS`]. The properties of the materials are in SI units,
+    /// so the values should be too, see [`UnitSystem::Si`](crate::UnitSystem::Si).
+    pub fn inputs_for<C: Computation>(
+        &self,
+        values: &[(&str, f64)],
+        materials: &[(&str, &str)],
+    ) -> Result<Vec<f64>> {
+        C::INPUTS
+            .iter()
+            .map(|&input| {
+                if let Some(&(_, value)) = values.iter().find(|(name, _)| *name == input) {
+                    return Ok(value);
+                }
+                let Some((property, role)) = input.split_once("_for_") else {
+                    return Err(Error::ExpectedArgumentMissing(input));
+                };
+                let Some(&(_, name)) = materials.iter().find(|(r, _)| *r == role) else {
+                    return Err(Error::ExpectedArgumentMissing(input));
+                };
+                let material = self
+                    .get(name)
+                    .ok_or_else(|| Error::UnknownMaterial(name.to_owned()))?;
+                material
+                    .property(property)
+                    .ok_or_else(|| Error::MissingMaterialProperty {
+                        material: material.name.clone(),
+                        property: input,
+                    })
+            })
+            .collect()
+    }
+
+    /// Parses the library from the TOML with the `[[materials]]` array of tables.
+    #[cfg(feature = "serde")]
+    pub fn from_toml_str(s: &str) -> Result<Self> {
+        toml::from_str(s).map_err(|err| Error::InvalidMaterialLibrary(err.to_string()))
+    }
+
+    /// Parses the library from the JSON object with the `materials` array.
+    #[cfg(feature = "serde")]
+    pub fn from_json_str(s: &str) -> Result<Self> {
+        serde_json::from_str(s).map_err(|err| Error::InvalidMaterialLibrary(err.to_string()))
+    }
+
+    /// Loads the library from the `.toml` or `.json` file.
+    #[cfg(feature = "serde")]
+    pub fn load(path: impl AsRef<std::path::Path>) -> Result<Self> {
+        let path = path.as_ref();
+        let s = std::fs::read_to_string(path)
+            .map_err(|err| Error::InvalidMaterialLibrary(err.to_string()))?;
+        match path.extension().and_then(|ext| ext.to_str()) {
+            Some("toml") => Self::from_toml_str(&s),
+            Some("json") => Self::from_json_str(&s),
+            _ => Err(Error::InvalidMaterialLibrary(format!(
+                "unsupported file extension of {}",
+                path.display()
+            ))),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        elastic_modules_for_unidirectional_composite, ElasticModulesForUnidirectionalComposite,
+        ThermalExpansionForHoneycomb,
+    };
+
+    #[test]
+    fn test() {
+        let library = MaterialLibrary::builtin();
+        let inputs = library
+            .inputs_for::<ElasticModulesForUnidirectionalComposite>(
+                &[("fibre_content", 0.6)],
+                &[("fiber", "t300"), ("matrix", "3501-6")],
+            )
+            .unwrap();
+        assert_eq!(inputs, [0.6, 230e9, 0.2, 4.3e9, 0.35]);
+        assert!(elastic_modules_for_unidirectional_composite(
+            2, inputs[0], inputs[1], inputs[2], inputs[3], inputs[4]
+        )
+        .is_ok());
+
+        assert!(matches!(
+            library.inputs_for::<ThermalExpansionForHoneycomb>(
+                &[
+                    ("l_cell_side_size", 9.24e-3),
+                    ("h_cell_side_size", 8.4619e-3),
+                    ("wall_thickness", 0.4e-3),
+                    ("angle", 0.5),
+                ],
+                &[("honeycomb", "Nomex")],
+            ),
+            Err(Error::MissingMaterialProperty { .. })
+        ));
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn user_defined_library() {
+        let mut library = MaterialLibrary::builtin();
+        library.extend(
+            MaterialLibrary::from_toml_str(
+                r#"
+                [[materials]]
+                name = "Nomex"
+                kind = "core"
+                e = 3.4e9
+                nu = 0.3
+                alpha = 17e-6
+                source = "measured"
+                "#,
+            )
+            .unwrap(),
+        );
+        assert_eq!(library.get("nomex").unwrap().alpha, Some(17e-6));
+        assert_eq!(
+            library.materials.len(),
+            MaterialLibrary::builtin().materials.len()
+        );
+        assert!(MaterialLibrary::from_json_str(r#"{"materials": [{"name": "X"}]}"#).is_err());
+    }
+}
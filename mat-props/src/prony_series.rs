@@ -0,0 +1,20 @@
+use crate::scalar::{Scalar, ScalarOps};
+
+/// Computes the [Carson transform] `s * E(s)` of the relaxation modulus given by the [Prony series]
+///
+/// `E(t) = e_inf + sum(e_i * exp(-t / tau_i))`.
+///
+/// For `s = i * omega` it is the complex modulus at the angular frequency `omega`.
+///
+/// [Carson transform]: https://en.wikipedia.org/wiki/Viscoelasticity#Correspondence_principle
+/// [Prony series]: https://en.wikipedia.org/wiki/Prony%27s_method
+pub(crate) fn carson_transform<T: Scalar>(e_inf: f64, prony_series: &[[f64; 2]], s: T) -> T
+where
+    f64: ScalarOps<T>,
+{
+    prony_series
+        .iter()
+        .fold(T::from(e_inf), |acc, &[e_i, tau_i]| {
+            acc + e_i * (s * tau_i) / (1.0 + s * tau_i)
+        })
+}
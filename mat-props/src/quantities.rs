@@ -0,0 +1,182 @@
+//! The models with the inputs and the outputs as [`uom`] quantities, so that the units can't be mixed up.
+
+use crate::{
+    elastic_modules_for_honeycomb, elastic_modules_for_unidirectional_composite,
+    thermal_conductivity_for_unidirectional_composite, thermal_expansion_for_honeycomb,
+    thermal_expansion_for_unidirectional_composite, Result,
+};
+use uom::si::{
+    angle::radian,
+    f64::{Angle, Length, Pressure, Ratio, TemperatureCoefficient, ThermalConductivity},
+    length::meter,
+    pressure::pascal,
+    ratio::ratio,
+    temperature_coefficient::per_kelvin,
+    thermal_conductivity::watt_per_meter_kelvin,
+};
+
+/// [Elastic modules](https://en.wikipedia.org/wiki/Elastic_modulus) of an orthotropic material.
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ElasticModules {
+    pub e1: Pressure,
+    pub e2: Pressure,
+    pub e3: Pressure,
+    pub nu12: Ratio,
+    pub nu13: Ratio,
+    pub nu23: Ratio,
+    pub g12: Pressure,
+    pub g13: Pressure,
+    pub g23: Pressure,
+}
+
+impl From<[f64; 9]> for ElasticModules {
+    // the modules are in Pa
+    fn from([e1, e2, e3, nu12, nu13, nu23, g12, g13, g23]: [f64; 9]) -> Self {
+        Self {
+            e1: Pressure::new::<pascal>(e1),
+            e2: Pressure::new::<pascal>(e2),
+            e3: Pressure::new::<pascal>(e3),
+            nu12: Ratio::new::<ratio>(nu12),
+            nu13: Ratio::new::<ratio>(nu13),
+            nu23: Ratio::new::<ratio>(nu23),
+            g12: Pressure::new::<pascal>(g12),
+            g13: Pressure::new::<pascal>(g13),
+            g23: Pressure::new::<pascal>(g23),
+        }
+    }
+}
+
+/// The same as [`elastic_modules_for_unidirectional_composite`] but with quantities.
+pub fn elastic_modules_for_unidirectional_composite_with_units(
+    number_of_model: u8,
+    fibre_content: Ratio,
+    e_for_fiber: Pressure,
+    nu_for_fiber: Ratio,
+    e_for_matrix: Pressure,
+    nu_for_matrix: Ratio,
+) -> Result<ElasticModules> {
+    elastic_modules_for_unidirectional_composite(
+        number_of_model,
+        fibre_content.get::<ratio>(),
+        e_for_fiber.get::<pascal>(),
+        nu_for_fiber.get::<ratio>(),
+        e_for_matrix.get::<pascal>(),
+        nu_for_matrix.get::<ratio>(),
+    )
+    .map(ElasticModules::from)
+}
+
+/// The same as [`elastic_modules_for_honeycomb`] but with quantities.
+pub fn elastic_modules_for_honeycomb_with_units(
+    number_of_model: u8,
+    l_cell_side_size: Length,
+    h_cell_side_size: Length,
+    wall_thickness: Length,
+    angle: Angle,
+    e_for_honeycomb: Pressure,
+    nu_for_honeycomb: Ratio,
+) -> Result<ElasticModules> {
+    elastic_modules_for_honeycomb(
+        number_of_model,
+        l_cell_side_size.get::<meter>(),
+        h_cell_side_size.get::<meter>(),
+        wall_thickness.get::<meter>(),
+        angle.get::<radian>(),
+        e_for_honeycomb.get::<pascal>(),
+        nu_for_honeycomb.get::<ratio>(),
+    )
+    .map(ElasticModules::from)
+}
+
+/// The same as [`thermal_conductivity_for_unidirectional_composite`] but with quantities.
+pub fn thermal_conductivity_for_unidirectional_composite_with_units(
+    number_of_model: u8,
+    fibre_content: Ratio,
+    k_for_fiber: ThermalConductivity,
+    k_for_matrix: ThermalConductivity,
+) -> Result<[ThermalConductivity; 3]> {
+    thermal_conductivity_for_unidirectional_composite(
+        number_of_model,
+        fibre_content.get::<ratio>(),
+        k_for_fiber.get::<watt_per_meter_kelvin>(),
+        k_for_matrix.get::<watt_per_meter_kelvin>(),
+    )
+    .map(|k| k.map(ThermalConductivity::new::<watt_per_meter_kelvin>))
+}
+
+/// The same as [`thermal_expansion_for_unidirectional_composite`] but with quantities.
+#[allow(clippy::too_many_arguments)]
+pub fn thermal_expansion_for_unidirectional_composite_with_units(
+    number_of_model: u8,
+    fibre_content: Ratio,
+    e_for_fiber: Pressure,
+    nu_for_fiber: Ratio,
+    alpha_for_fiber: TemperatureCoefficient,
+    e_for_matrix: Pressure,
+    nu_for_matrix: Ratio,
+    alpha_for_matrix: TemperatureCoefficient,
+) -> Result<[TemperatureCoefficient; 3]> {
+    thermal_expansion_for_unidirectional_composite(
+        number_of_model,
+        fibre_content.get::<ratio>(),
+        e_for_fiber.get::<pascal>(),
+        nu_for_fiber.get::<ratio>(),
+        alpha_for_fiber.get::<per_kelvin>(),
+        e_for_matrix.get::<pascal>(),
+        nu_for_matrix.get::<ratio>(),
+        alpha_for_matrix.get::<per_kelvin>(),
+    )
+    .map(|alpha| alpha.map(TemperatureCoefficient::new::<per_kelvin>))
+}
+
+/// The same as [`thermal_expansion_for_honeycomb`] but with quantities.
+pub fn thermal_expansion_for_honeycomb_with_units(
+    number_of_model: u8,
+    l_cell_side_size: Length,
+    h_cell_side_size: Length,
+    wall_thickness: Length,
+    angle: Angle,
+    alpha_for_honeycomb: TemperatureCoefficient,
+) -> Result<[TemperatureCoefficient; 3]> {
+    thermal_expansion_for_honeycomb(
+        number_of_model,
+        l_cell_side_size.get::<meter>(),
+        h_cell_side_size.get::<meter>(),
+        wall_thickness.get::<meter>(),
+        angle.get::<radian>(),
+        alpha_for_honeycomb.get::<per_kelvin>(),
+    )
+    .map(|alpha| alpha.map(TemperatureCoefficient::new::<per_kelvin>))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use uom::si::{angle::degree, length::millimeter, pressure::gigapascal};
+
+    #[test]
+    fn test() {
+        let modules = elastic_modules_for_honeycomb_with_units(
+            1,
+            Length::new::<millimeter>(9.24),
+            Length::new::<millimeter>(8.4619),
+            Length::new::<millimeter>(0.4),
+            Angle::new::<degree>(30.0),
+            Pressure::new::<gigapascal>(70.7),
+            Ratio::new::<ratio>(0.2),
+        )
+        .unwrap();
+        let [e1, ..] = elastic_modules_for_honeycomb(
+            1,
+            9.24,
+            8.4619,
+            0.4,
+            core::f64::consts::PI / 6.0,
+            70.7,
+            0.2,
+        )
+        .unwrap();
+        assert!((modules.e1.get::<gigapascal>() - e1).abs() < 1e-9 * e1);
+    }
+}
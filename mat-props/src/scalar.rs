@@ -0,0 +1,77 @@
+use core::ops::{Add, Div, Mul, Neg, Sub};
+use num_complex::Complex64;
+use num_traits::Float;
+
+/// The number type over which the models can be evaluated.
+///
+/// Besides [`f64`], the models are evaluated over [`Complex64`] in the frequency domain
+/// (see the elastic–viscoelastic correspondence principle) and over [`Dual`](crate::Dual)
+/// numbers for the sensitivities.
+pub trait Scalar:
+    Copy
+    + From<f64>
+    + Add<Output = Self>
+    + Sub<Output = Self>
+    + Mul<Output = Self>
+    + Div<Output = Self>
+    + Neg<Output = Self>
+    + Add<f64, Output = Self>
+    + Sub<f64, Output = Self>
+    + Mul<f64, Output = Self>
+    + Div<f64, Output = Self>
+{
+    fn sin(self) -> Self;
+    fn cos(self) -> Self;
+    fn tan(self) -> Self;
+    fn powf(self, n: f64) -> Self;
+}
+
+/// The arithmetic with [`f64`] on the left-hand side, e.g. `2.0 * x`.
+///
+/// Bounds on `f64` are not implied by the bounds on `T`, so the generic
+/// functions carry `where f64: ScalarOps<T>`.
+pub trait ScalarOps<T>:
+    Add<T, Output = T> + Sub<T, Output = T> + Mul<T, Output = T> + Div<T, Output = T>
+{
+}
+
+impl<T> ScalarOps<T> for f64 where
+    f64: Add<T, Output = T> + Sub<T, Output = T> + Mul<T, Output = T> + Div<T, Output = T>
+{
+}
+
+impl Scalar for f64 {
+    fn sin(self) -> Self {
+        Float::sin(self)
+    }
+
+    fn cos(self) -> Self {
+        Float::cos(self)
+    }
+
+    fn tan(self) -> Self {
+        Float::tan(self)
+    }
+
+    fn powf(self, n: f64) -> Self {
+        Float::powf(self, n)
+    }
+}
+
+impl Scalar for Complex64 {
+    fn sin(self) -> Self {
+        Complex64::sin(self)
+    }
+
+    fn cos(self) -> Self {
+        Complex64::cos(self)
+    }
+
+    fn tan(self) -> Self {
+        Complex64::tan(self)
+    }
+
+    fn powf(self, n: f64) -> Self {
+        Complex64::powf(self, n)
+    }
+}
@@ -0,0 +1,114 @@
+use crate::{computation::Computation, dual::Dual, Result};
+use alloc::{vec, vec::Vec};
+
+/// Computes the [Jacobian] of the outputs of the computation with respect to its inputs.
+///
+/// The derivatives are exact up to the floating point error because the computation is
+/// evaluated over [`Dual`] numbers once per input.
+///
+/// ## Arguments
+///
+/// * `number_of_model` - the number of the selected model of the computation.
+/// * `inputs` - the inputs in the order of [`Computation::INPUTS`].
+///
+/// ## Returns
+///
+/// Returns the matrix `J` where `J[i][j]` is the derivative of the `i`-th output in the order of
+/// [`Computation::OUTPUTS`] with respect to the `j`-th input.
+///
+/// [Jacobian]: https://en.wikipedia.org/wiki/Jacobian_matrix_and_determinant
+pub fn jacobian<C: Computation>(number_of_model: u8, inputs: &[f64]) -> Result<Vec<Vec<f64>>> {
+    let nested_res = crate::catch_unwind(|| {
+        let mut jacobian = vec![vec![0.0; inputs.len()]; C::OUTPUTS.len()];
+        for j in 0..inputs.len() {
+            let dual_inputs: Vec<Dual> = inputs
+                .iter()
+                .enumerate()
+                .map(|(k, &input)| Dual::new(input, if k == j { 1.0 } else { 0.0 }))
+                .collect();
+            let outputs = C::compute(number_of_model, &dual_inputs)?;
+            for (row, output) in jacobian.iter_mut().zip(outputs) {
+                row[j] = output.eps;
+            }
+        }
+        Ok(jacobian)
+    });
+    match nested_res {
+        Ok(res) => res,
+        Err(err) => Err(err),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        ElasticModulesForHoneycomb, ElasticModulesForUnidirectionalComposite,
+        ThermalConductivityForUnidirectionalComposite, ThermalExpansionForHoneycomb,
+        ThermalExpansionForUnidirectionalComposite,
+    };
+    use std::f64::consts::PI;
+
+    fn assert_matches_finite_differences<C: Computation>(number_of_model: u8, inputs: &[f64]) {
+        let jacobian = jacobian::<C>(number_of_model, inputs).unwrap();
+        for j in 0..inputs.len() {
+            let h = 1e-6 * inputs[j].abs().max(1e-3);
+            let mut forward = inputs.to_vec();
+            forward[j] += h;
+            let mut backward = inputs.to_vec();
+            backward[j] -= h;
+            let forward = C::evaluate(number_of_model, &forward).unwrap();
+            let backward = C::evaluate(number_of_model, &backward).unwrap();
+            for i in 0..C::OUTPUTS.len() {
+                if jacobian[i][j].is_nan() {
+                    continue;
+                }
+                let finite_difference = (forward[i] - backward[i]) / (2.0 * h);
+                assert!(
+                    (jacobian[i][j] - finite_difference).abs()
+                        <= 1e-5 * (1.0 + finite_difference.abs()),
+                    "d{}/d{}: {} != {}",
+                    C::OUTPUTS[i],
+                    C::INPUTS[j],
+                    jacobian[i][j],
+                    finite_difference,
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn test() {
+        let jacobian =
+            jacobian::<ElasticModulesForUnidirectionalComposite>(1, &[0.2, 100.0, 0.3, 5.0, 0.2])
+                .unwrap();
+        // dE1/d(fibre_content) = e_for_fiber - e_for_matrix for the rule of mixtures
+        assert_eq!(jacobian[0][0], 95.0);
+        // dE1/d(e_for_fiber) = fibre_content
+        assert_eq!(jacobian[0][1], 0.2);
+    }
+
+    #[test]
+    fn jacobians_match_finite_differences() {
+        assert_matches_finite_differences::<ElasticModulesForUnidirectionalComposite>(
+            2,
+            &[0.2, 100.0, 0.3, 5.0, 0.2],
+        );
+        assert_matches_finite_differences::<ElasticModulesForHoneycomb>(
+            1,
+            &[9.24, 8.4619, 0.4, PI / 6.0, 7.07, 0.2],
+        );
+        assert_matches_finite_differences::<ThermalConductivityForUnidirectionalComposite>(
+            2,
+            &[0.2, 100.0, 1.0],
+        );
+        assert_matches_finite_differences::<ThermalExpansionForUnidirectionalComposite>(
+            1,
+            &[0.2, 100.0, 0.3, 1e-6, 5.0, 0.2, 20e-5],
+        );
+        assert_matches_finite_differences::<ThermalExpansionForHoneycomb>(
+            1,
+            &[9.24, 8.4619, 0.4, PI / 6.0, 20e-5],
+        );
+    }
+}
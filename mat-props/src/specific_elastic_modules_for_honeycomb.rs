@@ -0,0 +1,90 @@
+use crate::{density_and_specific_heat_for_honeycomb, elastic_modules_for_honeycomb, Result};
+
+/// Computes [specific modules] for honeycomb structures, i.e. the elastic modules
+/// divided by the density of the honeycomb.
+///
+/// ## Arguments
+///
+/// * `number_of_model` - the number of the elastic model, see
+///   [`elastic_modules_for_honeycomb`](crate::elastic_modules_for_honeycomb).
+/// * `l_cell_side_size` - the side length of the hexagonal cell.
+/// * `h_cell_side_size` - the height of the hexagonal cell.
+/// * `wall_thickness` - the thickness of the cell walls.
+/// * `angle` - the angle of inclination of the hexagonal cell.
+/// * `e_for_honeycomb` - Young's modulus for the honeycomb material.
+/// * `nu_for_honeycomb` - Poisson's ratio for the honeycomb material.
+/// * `rho_for_honeycomb` - the density of the honeycomb material.
+///
+/// ## Returns
+///
+/// Returns the array of specific modules in the following order:
+///
+/// * `E1/rho`, `E2/rho`, `E3/rho` - the specific Young's modules.
+/// * `G12/rho`, `G13/rho`, `G23/rho` - the specific shear modules.
+///
+/// [specific modules]: https://en.wikipedia.org/wiki/Specific_modulus
+#[allow(clippy::too_many_arguments)]
+pub fn specific_elastic_modules_for_honeycomb(
+    number_of_model: u8,
+    l_cell_side_size: f64,
+    h_cell_side_size: f64,
+    wall_thickness: f64,
+    angle: f64,
+    e_for_honeycomb: f64,
+    nu_for_honeycomb: f64,
+    rho_for_honeycomb: f64,
+) -> Result<[f64; 6]> {
+    let [e1, e2, e3, _nu12, _nu13, _nu23, g12, g13, g23] = elastic_modules_for_honeycomb(
+        number_of_model,
+        l_cell_side_size,
+        h_cell_side_size,
+        wall_thickness,
+        angle,
+        e_for_honeycomb,
+        nu_for_honeycomb,
+    )?;
+    let [_rho_relative, rho, _c] = density_and_specific_heat_for_honeycomb(
+        l_cell_side_size,
+        h_cell_side_size,
+        wall_thickness,
+        angle,
+        rho_for_honeycomb,
+        None,
+    )?;
+    crate::catch_unwind(|| {
+        [
+            e1 / rho,
+            e2 / rho,
+            e3 / rho,
+            g12 / rho,
+            g13 / rho,
+            g23 / rho,
+        ]
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test() {
+        let [e1, e2, e3, g12, g13, g23] = specific_elastic_modules_for_honeycomb(
+            1,
+            9.24,
+            8.4619,
+            0.4,
+            std::f64::consts::PI / 6.0,
+            7.07,
+            0.2,
+            2.68,
+        )
+        .unwrap();
+        assert_eq!(e1, 0.010995135573675296);
+        assert_eq!(e2, 0.009799655624239453);
+        assert_eq!(e3, 2.638059701492537);
+        assert_eq!(g12, 0.002116508599775234);
+        assert_eq!(g13, 0.587150903805536);
+        assert_eq!(g23, 0.554992032348427);
+    }
+}
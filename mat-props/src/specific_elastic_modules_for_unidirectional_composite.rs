@@ -0,0 +1,85 @@
+use crate::{
+    density_and_specific_heat_for_unidirectional_composite,
+    elastic_modules_for_unidirectional_composite, Result,
+};
+
+/// Computes [specific modules] for unidirectional composite, i.e. the elastic modules
+/// divided by the density of the composite.
+///
+/// ## Arguments
+///
+/// * `number_of_model` - the number of the elastic model, see
+///   [`elastic_modules_for_unidirectional_composite`](crate::elastic_modules_for_unidirectional_composite).
+/// * `fibre_content` - the fibre content in the range from `0.0` to `1.0` where `0.0` is the matrix and `1.0` is the fibre.
+/// * `e_for_fiber` - the Young's modulus for fibre.
+/// * `nu_for_fiber` - the Poisson's ratio for fibre.
+/// * `e_for_matrix` - the Young's modulus for matrix.
+/// * `nu_for_matrix` - the Poisson's ratio for matrix.
+/// * `rho_for_fiber` - the density of the fibre.
+/// * `rho_for_matrix` - the density of the matrix.
+///
+/// ## Returns
+///
+/// Returns the array of specific modules in the following order:
+///
+/// * `E1/rho`, `E2/rho`, `E3/rho` - the specific Young's modules.
+/// * `G12/rho`, `G13/rho`, `G23/rho` - the specific shear modules.
+///
+/// [specific modules]: https://en.wikipedia.org/wiki/Specific_modulus
+#[allow(clippy::too_many_arguments)]
+pub fn specific_elastic_modules_for_unidirectional_composite(
+    number_of_model: u8,
+    fibre_content: f64,
+    e_for_fiber: f64,
+    nu_for_fiber: f64,
+    e_for_matrix: f64,
+    nu_for_matrix: f64,
+    rho_for_fiber: f64,
+    rho_for_matrix: f64,
+) -> Result<[f64; 6]> {
+    let [e1, e2, e3, _nu12, _nu13, _nu23, g12, g13, g23] =
+        elastic_modules_for_unidirectional_composite(
+            number_of_model,
+            fibre_content,
+            e_for_fiber,
+            nu_for_fiber,
+            e_for_matrix,
+            nu_for_matrix,
+        )?;
+    let [rho, _c] = density_and_specific_heat_for_unidirectional_composite(
+        fibre_content,
+        rho_for_fiber,
+        rho_for_matrix,
+        None,
+        None,
+    )?;
+    crate::catch_unwind(|| {
+        [
+            e1 / rho,
+            e2 / rho,
+            e3 / rho,
+            g12 / rho,
+            g13 / rho,
+            g23 / rho,
+        ]
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test() {
+        let [e1, e2, e3, g12, g13, g23] = specific_elastic_modules_for_unidirectional_composite(
+            2, 0.2, 100.0, 0.3, 5.0, 0.2, 1.76, 1.2,
+        )
+        .unwrap();
+        assert_eq!(e1, 18.301618391330454);
+        assert_eq!(e2, 5.006379654523638);
+        assert_eq!(e3, 5.006379654523638);
+        assert_eq!(g12, 2.2824243777119855);
+        assert_eq!(g13, 2.2824243777119855);
+        assert_eq!(g23, 2.110873172795928);
+    }
+}
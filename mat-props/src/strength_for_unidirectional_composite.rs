@@ -0,0 +1,152 @@
+use crate::{elastic_modules_for_unidirectional_composite, Error, Result};
+use core::f64::consts::PI;
+use enum_primitive_derive::Primitive;
+#[cfg(not(any(feature = "std", test)))]
+use num_traits::Float;
+use num_traits::FromPrimitive;
+
+#[derive(Primitive)]
+enum Model {
+    /// Longitudinal compression by Rosen's shear microbuckling model.
+    Rosen = 1,
+    /// Longitudinal compression by the Argon-Budiansky kink band model.
+    KinkBand = 2,
+}
+
+/// Computes micromechanical [strength] estimates for unidirectional composite.
+///
+/// The elastic modules of the ply are computed with Vanin's model of
+/// [`elastic_modules_for_unidirectional_composite`](crate::elastic_modules_for_unidirectional_composite).
+/// The transverse and shear strengths account for the strain magnification in the matrix
+/// between the fibres in the square packing.
+///
+/// ## Arguments
+///
+/// * `number_of_model` - the number of the selected model of longitudinal compression,
+///   represented by the discriminant in [`Model`].
+/// * `fibre_content` - the fibre content in the range from `0.0` to `1.0` where `0.0` is the matrix and `1.0` is the fibre.
+/// * `e_for_fiber` - the Young's modulus for fibre.
+/// * `nu_for_fiber` - the Poisson's ratio for fibre.
+/// * `sigma_t_for_fiber` - the tensile strength of the fibre.
+/// * `e_for_matrix` - the Young's modulus for matrix.
+/// * `nu_for_matrix` - the Poisson's ratio for matrix.
+/// * `sigma_t_for_matrix` - the tensile strength of the matrix.
+/// * `sigma_c_for_matrix` - the compressive strength of the matrix (positive).
+/// * `tau_for_matrix` - the shear strength of the matrix.
+/// * `misalignment_angle` - the initial fibre misalignment angle in radians.
+///   Required by the kink band model.
+///
+/// ## Returns
+///
+/// Returns the array of strengths (all positive) in the following order:
+///
+/// * `Xt` - the longitudinal tensile strength.
+/// * `Xc` - the longitudinal compressive strength.
+/// * `Yt` - the transverse tensile strength.
+/// * `Yc` - the transverse compressive strength.
+/// * `S12` - the in-plane shear strength.
+///
+/// [strength]: https://en.wikipedia.org/wiki/Strength_of_materials
+#[allow(clippy::too_many_arguments)]
+pub fn strength_for_unidirectional_composite(
+    number_of_model: u8,
+    fibre_content: f64,
+    e_for_fiber: f64,
+    nu_for_fiber: f64,
+    sigma_t_for_fiber: f64,
+    e_for_matrix: f64,
+    nu_for_matrix: f64,
+    sigma_t_for_matrix: f64,
+    sigma_c_for_matrix: f64,
+    tau_for_matrix: f64,
+    misalignment_angle: Option<f64>,
+) -> Result<[f64; 5]> {
+    let model = Model::from_u8(number_of_model).ok_or(Error::UnknownModel)?;
+
+    let [_e1, e2, _e3, _nu12, _nu13, _nu23, g12, _g13, _g23] =
+        elastic_modules_for_unidirectional_composite(
+            2,
+            fibre_content,
+            e_for_fiber,
+            nu_for_fiber,
+            e_for_matrix,
+            nu_for_matrix,
+        )?;
+
+    let nested_res = crate::catch_unwind(|| {
+        let g_for_fiber = e_for_fiber / (2.0 * (1.0 + nu_for_fiber));
+        let g_for_matrix = e_for_matrix / (2.0 * (1.0 + nu_for_matrix));
+        // the ratio of the fibre diameter to the fibre spacing for the square packing
+        let d_to_s = (4.0 * fibre_content / PI).sqrt();
+        let strain_magnification = 1.0 / (d_to_s * (e_for_matrix / e_for_fiber - 1.0) + 1.0);
+        let shear_strain_magnification = 1.0 / (d_to_s * (g_for_matrix / g_for_fiber - 1.0) + 1.0);
+
+        let xt = sigma_t_for_fiber
+            * (fibre_content + (1.0 - fibre_content) * e_for_matrix / e_for_fiber);
+        let xc = match model {
+            Model::Rosen => g_for_matrix / (1.0 - fibre_content),
+            Model::KinkBand => {
+                let Some(misalignment_angle) = misalignment_angle else {
+                    return Err(Error::ExpectedArgumentMissing(stringify!(
+                        misalignment_angle
+                    )));
+                };
+                let gamma_y = tau_for_matrix / g12;
+                g12 / (1.0 + misalignment_angle / gamma_y)
+            }
+        };
+        let yt = e2 * sigma_t_for_matrix / (e_for_matrix * strain_magnification);
+        let yc = e2 * sigma_c_for_matrix / (e_for_matrix * strain_magnification);
+        let s12 = g12 * tau_for_matrix / (g_for_matrix * shear_strain_magnification);
+        Ok([xt, xc, yt, yc, s12])
+    });
+    match nested_res {
+        Ok(res) => res,
+        Err(err) => Err(err),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test() {
+        let [xt, xc, yt, yc, s12] = strength_for_unidirectional_composite(
+            1, 0.6, 230.0, 0.2, 3.5, 3.5, 0.35, 0.08, 0.12, 0.07, None,
+        )
+        .unwrap();
+        assert_eq!(xt, 2.121304347826087);
+        assert_eq!(xc, 3.2407407407407405);
+        assert_eq!(yt, 0.039378039108727725);
+        assert_eq!(yc, 0.05906705866309158);
+        assert_eq!(s12, 0.03672303579437301);
+
+        let [_xt, xc, _yt, _yc, _s12] = strength_for_unidirectional_composite(
+            2,
+            0.6,
+            230.0,
+            0.2,
+            3.5,
+            3.5,
+            0.35,
+            0.08,
+            0.12,
+            0.07,
+            Some(2.0 * PI / 180.0),
+        )
+        .unwrap();
+        assert_eq!(xc, 1.4259796447088824);
+    }
+
+    #[test]
+    fn kink_band_requires_misalignment_angle() {
+        let res = strength_for_unidirectional_composite(
+            2, 0.6, 230.0, 0.2, 3.5, 3.5, 0.35, 0.08, 0.12, 0.07, None,
+        );
+        assert!(matches!(
+            res,
+            Err(Error::ExpectedArgumentMissing("misalignment_angle"))
+        ));
+    }
+}
@@ -0,0 +1,241 @@
+use crate::{computation::Computation, Error, Result};
+use alloc::{borrow::Cow, vec, vec::Vec};
+#[cfg(not(any(feature = "std", test)))]
+use num_traits::Float;
+
+/// The values of a swept input.
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(rename_all = "snake_case")
+)]
+pub enum Grid {
+    /// The evenly spaced values from `start` to `end` inclusive.
+    Linear {
+        start: f64,
+        end: f64,
+        number_of_points: usize,
+    },
+    /// The values from `start` to `end` inclusive evenly spaced on the logarithmic scale.
+    /// Both `start` and `end` must be positive.
+    Log {
+        start: f64,
+        end: f64,
+        number_of_points: usize,
+    },
+    /// The given values.
+    Explicit(Vec<f64>),
+}
+
+impl Grid {
+    /// Returns the number of the values of the grid without computing them.
+    pub fn number_of_points(&self) -> usize {
+        match *self {
+            Self::Linear {
+                number_of_points, ..
+            }
+            | Self::Log {
+                number_of_points, ..
+            } => number_of_points,
+            Self::Explicit(ref points) => points.len(),
+        }
+    }
+
+    /// Returns the values of the grid.
+    pub fn points(&self) -> Vec<f64> {
+        let linspace = |start: f64, end: f64, number_of_points: usize| -> Vec<f64> {
+            match number_of_points {
+                0 => Vec::new(),
+                1 => vec![start],
+                _ => (0..number_of_points)
+                    .map(|i| start + (end - start) * i as f64 / (number_of_points - 1) as f64)
+                    .collect(),
+            }
+        };
+        match *self {
+            Self::Linear {
+                start,
+                end,
+                number_of_points,
+            } => linspace(start, end, number_of_points),
+            Self::Log {
+                start,
+                end,
+                number_of_points,
+            } => linspace(start.ln(), end.ln(), number_of_points)
+                .into_iter()
+                .map(f64::exp)
+                .collect(),
+            Self::Explicit(ref points) => points.clone(),
+        }
+    }
+}
+
+/// The swept input.
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Axis {
+    /// The name of the input, see [`Computation::INPUTS`].
+    pub input: Cow<'static, str>,
+    /// The values of the input.
+    pub grid: Grid,
+}
+
+/// The result of [`sweep`].
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct SweepTable {
+    /// The values of the swept inputs for each row in the order of the axes.
+    pub inputs: Vec<Vec<f64>>,
+    /// The outputs for each row in the order of [`Computation::OUTPUTS`].
+    pub outputs: Vec<Vec<f64>>,
+}
+
+/// Evaluates the computation over the grid of one or more swept inputs.
+///
+/// ## Arguments
+///
+/// * `number_of_model` - the number of the selected model of the computation.
+/// * `inputs` - the inputs in the order of [`Computation::INPUTS`]. The values of the swept inputs are ignored.
+/// * `axes` - the swept inputs.
+///
+/// ## Returns
+///
+/// Returns the [`SweepTable`] with a row for each point of the Cartesian product of the grids.
+/// The rows are in the row-major order, i.e. the last axis varies the fastest.
+///
+/// ## Errors
+///
+/// Returns [`Error::InvalidArgument`] if the number of rows overflows `usize`. The callers that take
+/// the grids from untrusted input should also limit [`Grid::number_of_points`].
+pub fn sweep<C: Computation>(
+    number_of_model: u8,
+    inputs: &[f64],
+    axes: &[Axis],
+) -> Result<SweepTable> {
+    if inputs.len() != C::INPUTS.len() {
+        return Err(Error::WrongNumberOfInputs {
+            expected: C::INPUTS.len(),
+            actual: inputs.len(),
+        });
+    }
+    let input_indices = axes
+        .iter()
+        .map(|axis| {
+            C::input_index(&axis.input)
+                .ok_or_else(|| Error::UnknownInput(axis.input.clone().into_owned()))
+        })
+        .collect::<Result<Vec<_>>>()?;
+    let number_of_rows = axes
+        .iter()
+        .try_fold(1usize, |rows, axis| {
+            rows.checked_mul(axis.grid.number_of_points())
+        })
+        .ok_or(Error::InvalidArgument {
+            name: "axes",
+            reason: "the number of rows overflows usize",
+        })?;
+    let grids: Vec<Vec<f64>> = axes.iter().map(|axis| axis.grid.points()).collect();
+
+    let mut table = SweepTable {
+        inputs: Vec::with_capacity(number_of_rows),
+        outputs: Vec::with_capacity(number_of_rows),
+    };
+    let mut inputs = inputs.to_vec();
+    for row in 0..number_of_rows {
+        let mut rest = row;
+        let mut swept = vec![0.0; axes.len()];
+        for (axis, grid) in grids.iter().enumerate().rev() {
+            swept[axis] = grid[rest % grid.len()];
+            rest /= grid.len();
+        }
+        for (&i, &value) in input_indices.iter().zip(&swept) {
+            inputs[i] = value;
+        }
+        table.outputs.push(C::evaluate(number_of_model, &inputs)?);
+        table.inputs.push(swept);
+    }
+    Ok(table)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        elastic_modules_for_unidirectional_composite, ElasticModulesForUnidirectionalComposite,
+        ThermalExpansionForHoneycomb,
+    };
+
+    #[test]
+    fn test() {
+        let table = sweep::<ElasticModulesForUnidirectionalComposite>(
+            2,
+            &[0.0, 100.0, 0.3, 5.0, 0.2],
+            &[
+                Axis {
+                    input: "fibre_content".into(),
+                    grid: Grid::Linear {
+                        start: 0.0,
+                        end: 0.8,
+                        number_of_points: 5,
+                    },
+                },
+                Axis {
+                    input: "e_for_fiber".into(),
+                    grid: Grid::Explicit(vec![100.0, 230.0]),
+                },
+            ],
+        )
+        .unwrap();
+        assert_eq!(table.inputs.len(), 10);
+        assert_eq!(table.inputs[3], [0.2, 230.0]);
+        assert_eq!(
+            table.outputs[3],
+            elastic_modules_for_unidirectional_composite(2, 0.2, 230.0, 0.3, 5.0, 0.2).unwrap()
+        );
+
+        let table = sweep::<ThermalExpansionForHoneycomb>(
+            1,
+            &[9.24, 8.4619, 0.4, 0.0, 20e-5],
+            &[Axis {
+                input: "angle".into(),
+                grid: Grid::Log {
+                    start: 0.1,
+                    end: 1.0,
+                    number_of_points: 3,
+                },
+            }],
+        )
+        .unwrap();
+        assert!((table.inputs[1][0] - 10f64.powf(-0.5)).abs() < 1e-15);
+
+        let huge = Axis {
+            input: "angle".into(),
+            grid: Grid::Linear {
+                start: 0.1,
+                end: 1.0,
+                number_of_points: usize::MAX,
+            },
+        };
+        assert!(matches!(
+            sweep::<ThermalExpansionForHoneycomb>(
+                1,
+                &[9.24, 8.4619, 0.4, 0.0, 20e-5],
+                &[huge.clone(), huge]
+            ),
+            Err(Error::InvalidArgument { name: "axes", .. })
+        ));
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn axis_round_trips() {
+        let axis = Axis {
+            input: "fibre_content".into(),
+            grid: Grid::Explicit(vec![0.4, 0.6]),
+        };
+        let json = serde_json::to_string(&axis).unwrap();
+        assert_eq!(serde_json::from_str::<Axis>(&json).unwrap(), axis);
+    }
+}
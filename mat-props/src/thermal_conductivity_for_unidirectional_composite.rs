@@ -1,4 +1,10 @@
-use crate::{Error, Result};
+use crate::{
+    computation::{unpack_inputs, Computation},
+    scalar::{Scalar, ScalarOps},
+    units::Dimension,
+    Error, Result,
+};
+use alloc::vec::Vec;
 use core::f64::consts::PI;
 use enum_primitive_derive::Primitive;
 use num_traits::FromPrimitive;
@@ -37,7 +43,58 @@ pub fn thermal_conductivity_for_unidirectional_composite(
 ) -> Result<[f64; 3]> {
     let model = Model::from_u8(number_of_model).ok_or(Error::UnknownModel)?;
 
-    std::panic::catch_unwind(|| match model {
+    crate::catch_unwind(|| compute(model, fibre_content, k_for_fiber, k_for_matrix))
+}
+
+/// Generic counterpart of [`thermal_conductivity_for_unidirectional_composite`] that can be evaluated
+/// over any [`Scalar`]. Unlike the former, it doesn't catch panics.
+pub(crate) fn thermal_conductivity_for_unidirectional_composite_generic<T: Scalar>(
+    number_of_model: u8,
+    fibre_content: T,
+    k_for_fiber: T,
+    k_for_matrix: T,
+) -> Result<[T; 3]>
+where
+    f64: ScalarOps<T>,
+{
+    let model = Model::from_u8(number_of_model).ok_or(Error::UnknownModel)?;
+
+    Ok(compute(model, fibre_content, k_for_fiber, k_for_matrix))
+}
+
+/// The [`Computation`] standing for [`thermal_conductivity_for_unidirectional_composite`].
+pub struct ThermalConductivityForUnidirectionalComposite;
+
+impl Computation for ThermalConductivityForUnidirectionalComposite {
+    const INPUTS: &'static [&'static str] = &["fibre_content", "k_for_fiber", "k_for_matrix"];
+    const OUTPUTS: &'static [&'static str] = &["k1", "k2", "k3"];
+    const INPUT_DIMENSIONS: &'static [Dimension] = &[
+        Dimension::Dimensionless,
+        Dimension::ThermalConductivity,
+        Dimension::ThermalConductivity,
+    ];
+    const OUTPUT_DIMENSIONS: &'static [Dimension] = &[Dimension::ThermalConductivity; 3];
+
+    fn compute<T: Scalar>(number_of_model: u8, inputs: &[T]) -> Result<Vec<T>>
+    where
+        f64: ScalarOps<T>,
+    {
+        let [fibre_content, k_for_fiber, k_for_matrix] = unpack_inputs(inputs)?;
+        thermal_conductivity_for_unidirectional_composite_generic(
+            number_of_model,
+            fibre_content,
+            k_for_fiber,
+            k_for_matrix,
+        )
+        .map(Vec::from)
+    }
+}
+
+fn compute<T: Scalar>(model: Model, fibre_content: T, k_for_fiber: T, k_for_matrix: T) -> [T; 3]
+where
+    f64: ScalarOps<T>,
+{
+    match model {
         Model::RuleOfMixtures => {
             let k1 = fibre_content * k_for_fiber + (1.0 - fibre_content) * k_for_matrix;
             let k2 = 1.0 / (fibre_content / k_for_fiber + (1.0 - fibre_content) / k_for_matrix);
@@ -49,45 +106,56 @@ pub fn thermal_conductivity_for_unidirectional_composite(
             let k_2_zero = k_for_matrix
                 * ((1.0 + fibre_content + (1.0 - fibre_content) * k_for_fiber / k_for_matrix)
                     / (1.0 - fibre_content + (1.0 - fibre_content) * k_for_fiber / k_for_matrix));
-            let n = 6.0;
+            let [n_n_n_minus_1, sin_squared, pi_2_powf_n, two_n] = vanin_coefficients();
             let k2 = k_2_zero
                 * (1.0
-                    + n * n * (n - 1.0) * k_2_zero / k_for_matrix
+                    + n_n_n_minus_1 * k_2_zero / k_for_matrix
                         * ((1.0 - k_for_fiber / k_for_matrix)
                             / (1.0 - fibre_content
                                 + (1.0 + fibre_content) * k_for_fiber / k_for_matrix))
                         * ((1.0 - k_for_fiber / k_for_matrix)
                             / (1.0 - fibre_content
                                 + (1.0 + fibre_content) * k_for_fiber / k_for_matrix))
-                        * ((PI / 2.0).sin() * (PI / 2.0).sin())
-                        / (PI / 2.0).powf(n)
+                        * sin_squared
+                        / pi_2_powf_n
                         * (fibre_content * fibre_content
-                            - fibre_content.powf(2.0 * n)
+                            - fibre_content.powf(two_n)
                                 * ((1.0 - k_for_fiber / k_for_matrix)
                                     / (1.0 + k_for_fiber / k_for_matrix))
                                 * ((1.0 - k_for_fiber / k_for_matrix)
                                     / (1.0 + k_for_fiber / k_for_matrix))));
             let k3 = k_2_zero
                 * (1.0
-                    + n * n * (n - 1.0) * k_2_zero / k_for_matrix
+                    + n_n_n_minus_1 * k_2_zero / k_for_matrix
                         * ((1.0 - k_for_fiber / k_for_matrix)
                             / (1.0 - fibre_content
                                 + (1.0 + fibre_content) * k_for_fiber / k_for_matrix))
                         * ((1.0 - k_for_fiber / k_for_matrix)
                             / (1.0 - fibre_content
                                 + (1.0 + fibre_content) * k_for_fiber / k_for_matrix))
-                        * ((PI / 2.0).sin() * (PI / 2.0).sin())
-                        / (PI / 2.0).powf(n)
+                        * sin_squared
+                        / pi_2_powf_n
                         * (fibre_content * fibre_content
-                            - fibre_content.powf(2.0 * n)
+                            - fibre_content.powf(two_n)
                                 * ((1.0 - k_for_fiber / k_for_matrix)
                                     / (1.0 + k_for_fiber / k_for_matrix))
                                 * ((1.0 - k_for_fiber / k_for_matrix)
                                     / (1.0 + k_for_fiber / k_for_matrix))));
             [k1, k2, k3]
         }
-    })
-    .map_err(Error::NumericalError)
+    }
+}
+
+// The factors of Vanin's model that don't depend on the arguments. They are computed outside of
+// `compute` where `f64` arithmetic would be resolved against the `f64: ScalarOps<T>` bound.
+fn vanin_coefficients() -> [f64; 4] {
+    let n = 6.0;
+    [
+        n * n * (n - 1.0),
+        (PI / 2.0).sin() * (PI / 2.0).sin(),
+        (PI / 2.0).powf(n),
+        2.0 * n,
+    ]
 }
 
 #[cfg(test)]
@@ -0,0 +1,77 @@
+use crate::{
+    density_and_specific_heat_for_unidirectional_composite,
+    thermal_conductivity_for_unidirectional_composite, Result,
+};
+
+/// Computes [thermal diffusivity] for unidirectional composite.
+///
+/// ## Arguments
+///
+/// * `number_of_model` - the number of the thermal conductivity model, see
+///   [`thermal_conductivity_for_unidirectional_composite`](crate::thermal_conductivity_for_unidirectional_composite).
+/// * `fibre_content` - the fibre content in the range from `0.0` to `1.0` where `0.0` is the matrix and `1.0` is the fibre.
+/// * `k_for_fiber` - the thermal conductivity for the fibre.
+/// * `k_for_matrix` - the thermal conductivity for the matrix.
+/// * `rho_for_fiber` - the density of the fibre.
+/// * `rho_for_matrix` - the density of the matrix.
+/// * `c_for_fiber` - the specific heat capacity of the fibre.
+/// * `c_for_matrix` - the specific heat capacity of the matrix.
+///
+/// ## Returns
+///
+/// Returns the array of thermal diffusivities in the following order:
+///
+/// * `a1` - [thermal diffusivity] in the primary direction.
+/// * `a2` - [thermal diffusivity] in the secondary direction.
+/// * `a3` - [thermal diffusivity] in the tertiary direction.
+///
+/// [thermal diffusivity]: https://en.wikipedia.org/wiki/Thermal_diffusivity
+#[allow(clippy::too_many_arguments)]
+pub fn thermal_diffusivity_for_unidirectional_composite(
+    number_of_model: u8,
+    fibre_content: f64,
+    k_for_fiber: f64,
+    k_for_matrix: f64,
+    rho_for_fiber: f64,
+    rho_for_matrix: f64,
+    c_for_fiber: f64,
+    c_for_matrix: f64,
+) -> Result<[f64; 3]> {
+    let [k1, k2, k3] = thermal_conductivity_for_unidirectional_composite(
+        number_of_model,
+        fibre_content,
+        k_for_fiber,
+        k_for_matrix,
+    )?;
+    let [rho, c] = density_and_specific_heat_for_unidirectional_composite(
+        fibre_content,
+        rho_for_fiber,
+        rho_for_matrix,
+        Some(c_for_fiber),
+        Some(c_for_matrix),
+    )?;
+    crate::catch_unwind(|| {
+        let volumetric_heat_capacity = rho * c;
+        [
+            k1 / volumetric_heat_capacity,
+            k2 / volumetric_heat_capacity,
+            k3 / volumetric_heat_capacity,
+        ]
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test() {
+        let [a1, a2, a3] = thermal_diffusivity_for_unidirectional_composite(
+            2, 0.2, 100.0, 1.0, 1760.0, 1200.0, 750.0, 1100.0,
+        )
+        .unwrap();
+        assert_eq!(a1, 1.575757575757576e-5);
+        assert_eq!(a2, 1.0076265330251839e-6);
+        assert_eq!(a3, 1.0076265330251839e-6);
+    }
+}
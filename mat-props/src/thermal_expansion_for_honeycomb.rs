@@ -1,4 +1,10 @@
-use crate::{Error, Result};
+use crate::{
+    computation::{unpack_inputs, Computation},
+    scalar::{Scalar, ScalarOps},
+    units::Dimension,
+    Error, Result,
+};
+use alloc::vec::Vec;
 use enum_primitive_derive::Primitive;
 use num_traits::FromPrimitive;
 
@@ -16,7 +22,7 @@ enum Model {
 /// * `l_cell_side_size` - side length of the hexagonal cells in the honeycomb.
 /// * `h_cell_side_size` - height of the honeycomb cells.
 /// * `wall_thickness` - wall thickness.
-/// * `angle` - angle of the honeycomb structure.
+/// * `angle` - angle of the honeycomb structure in radians.
 /// * `alpha_for_honeycomb` - coefficient of thermal expansion for the honeycomb material.
 ///
 /// ## Returns
@@ -38,7 +44,94 @@ pub fn thermal_expansion_for_honeycomb(
 ) -> Result<[f64; 3]> {
     let model = Model::from_u8(number_of_model).ok_or(Error::UnknownModel)?;
 
-    std::panic::catch_unwind(|| match model {
+    crate::catch_unwind(|| {
+        compute(
+            model,
+            l_cell_side_size,
+            h_cell_side_size,
+            _wall_thickness,
+            angle,
+            alpha_for_honeycomb,
+        )
+    })
+}
+
+/// Generic counterpart of [`thermal_expansion_for_honeycomb`] that can be evaluated
+/// over any [`Scalar`]. Unlike the former, it doesn't catch panics.
+pub(crate) fn thermal_expansion_for_honeycomb_generic<T: Scalar>(
+    number_of_model: u8,
+    l_cell_side_size: T,
+    h_cell_side_size: T,
+    _wall_thickness: T,
+    angle: T,
+    alpha_for_honeycomb: T,
+) -> Result<[T; 3]>
+where
+    f64: ScalarOps<T>,
+{
+    let model = Model::from_u8(number_of_model).ok_or(Error::UnknownModel)?;
+
+    Ok(compute(
+        model,
+        l_cell_side_size,
+        h_cell_side_size,
+        _wall_thickness,
+        angle,
+        alpha_for_honeycomb,
+    ))
+}
+
+/// The [`Computation`] standing for [`thermal_expansion_for_honeycomb`].
+pub struct ThermalExpansionForHoneycomb;
+
+impl Computation for ThermalExpansionForHoneycomb {
+    const INPUTS: &'static [&'static str] = &[
+        "l_cell_side_size",
+        "h_cell_side_size",
+        "wall_thickness",
+        "angle",
+        "alpha_for_honeycomb",
+    ];
+    const OUTPUTS: &'static [&'static str] = &["alpha1", "alpha2", "alpha3"];
+    const INPUT_DIMENSIONS: &'static [Dimension] = &[
+        Dimension::Length,
+        Dimension::Length,
+        Dimension::Length,
+        Dimension::Angle,
+        Dimension::ThermalExpansion,
+    ];
+    const OUTPUT_DIMENSIONS: &'static [Dimension] = &[Dimension::ThermalExpansion; 3];
+
+    fn compute<T: Scalar>(number_of_model: u8, inputs: &[T]) -> Result<Vec<T>>
+    where
+        f64: ScalarOps<T>,
+    {
+        let [l_cell_side_size, h_cell_side_size, _wall_thickness, angle, alpha_for_honeycomb] =
+            unpack_inputs(inputs)?;
+        thermal_expansion_for_honeycomb_generic(
+            number_of_model,
+            l_cell_side_size,
+            h_cell_side_size,
+            _wall_thickness,
+            angle,
+            alpha_for_honeycomb,
+        )
+        .map(Vec::from)
+    }
+}
+
+fn compute<T: Scalar>(
+    model: Model,
+    l_cell_side_size: T,
+    h_cell_side_size: T,
+    _wall_thickness: T,
+    angle: T,
+    alpha_for_honeycomb: T,
+) -> [T; 3]
+where
+    f64: ScalarOps<T>,
+{
+    match model {
         Model::Vanin => {
             let alpha1 = alpha_for_honeycomb;
             let alpha2 = ((h_cell_side_size) / (l_cell_side_size) * alpha_for_honeycomb
@@ -47,8 +140,7 @@ pub fn thermal_expansion_for_honeycomb(
             let alpha3 = alpha_for_honeycomb;
             [alpha1, alpha2, alpha3]
         }
-    })
-    .map_err(Error::NumericalError)
+    }
 }
 
 #[cfg(test)]
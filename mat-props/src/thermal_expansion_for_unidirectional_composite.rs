@@ -1,4 +1,11 @@
-use crate::{elastic_modules_for_unidirectional_composite, Error, Result};
+use crate::{
+    computation::{unpack_inputs, Computation},
+    elastic_modules_for_unidirectional_composite::elastic_modules_for_unidirectional_composite_generic,
+    scalar::{Scalar, ScalarOps},
+    units::Dimension,
+    Error, Result,
+};
+use alloc::vec::Vec;
 use enum_primitive_derive::Primitive;
 use num_traits::FromPrimitive;
 
@@ -33,6 +40,7 @@ enum Model {
 /// [Young's modulus]: https://en.wikipedia.org/wiki/Young%27s_modulus
 /// [Poisson's ratio]: https://en.wikipedia.org/wiki/Poisson%27s_ratio
 /// [coefficient of thermal expansion]: https://matmatch.com/learn/property/what-is-coefficient-of-thermal-expansion
+#[allow(clippy::too_many_arguments)]
 pub fn thermal_expansion_for_unidirectional_composite(
     number_of_model: u8,
     fibre_content: f64,
@@ -45,16 +53,118 @@ pub fn thermal_expansion_for_unidirectional_composite(
 ) -> Result<[f64; 3]> {
     let model = Model::from_u8(number_of_model).ok_or(Error::UnknownModel)?;
 
-    let (g_for_fiber, g_for_matrix, chi_for_fiber, chi_for_matrix) =
-        std::panic::catch_unwind(|| {
-            let g_for_fiber = e_for_fiber / (2.0 * (1.0 + nu_for_fiber));
-            let g_for_matrix = e_for_matrix / (2.0 * (1.0 + nu_for_matrix));
-            let chi_for_fiber = 3.0 - 4.0 * nu_for_fiber;
-            let chi_for_matrix = 3.0 - 4.0 * nu_for_matrix;
-            (g_for_fiber, g_for_matrix, chi_for_fiber, chi_for_matrix)
-        })
-        .map_err(Error::NumericalError)?;
-    let a = elastic_modules_for_unidirectional_composite(
+    let nested_res = crate::catch_unwind(|| {
+        compute(
+            model,
+            fibre_content,
+            e_for_fiber,
+            nu_for_fiber,
+            alpha_for_fiber,
+            e_for_matrix,
+            nu_for_matrix,
+            alpha_for_matrix,
+        )
+    });
+    match nested_res {
+        Ok(res) => res,
+        Err(err) => Err(err),
+    }
+}
+
+/// Generic counterpart of [`thermal_expansion_for_unidirectional_composite`] that can be evaluated
+/// over any [`Scalar`]. Unlike the former, it doesn't catch panics.
+#[allow(clippy::too_many_arguments)]
+pub(crate) fn thermal_expansion_for_unidirectional_composite_generic<T: Scalar>(
+    number_of_model: u8,
+    fibre_content: T,
+    e_for_fiber: T,
+    nu_for_fiber: T,
+    alpha_for_fiber: T,
+    e_for_matrix: T,
+    nu_for_matrix: T,
+    alpha_for_matrix: T,
+) -> Result<[T; 3]>
+where
+    f64: ScalarOps<T>,
+{
+    let model = Model::from_u8(number_of_model).ok_or(Error::UnknownModel)?;
+
+    compute(
+        model,
+        fibre_content,
+        e_for_fiber,
+        nu_for_fiber,
+        alpha_for_fiber,
+        e_for_matrix,
+        nu_for_matrix,
+        alpha_for_matrix,
+    )
+}
+
+/// The [`Computation`] standing for [`thermal_expansion_for_unidirectional_composite`].
+pub struct ThermalExpansionForUnidirectionalComposite;
+
+impl Computation for ThermalExpansionForUnidirectionalComposite {
+    const INPUTS: &'static [&'static str] = &[
+        "fibre_content",
+        "e_for_fiber",
+        "nu_for_fiber",
+        "alpha_for_fiber",
+        "e_for_matrix",
+        "nu_for_matrix",
+        "alpha_for_matrix",
+    ];
+    const OUTPUTS: &'static [&'static str] = &["alpha1", "alpha2", "alpha3"];
+    const INPUT_DIMENSIONS: &'static [Dimension] = &[
+        Dimension::Dimensionless,
+        Dimension::Pressure,
+        Dimension::Dimensionless,
+        Dimension::ThermalExpansion,
+        Dimension::Pressure,
+        Dimension::Dimensionless,
+        Dimension::ThermalExpansion,
+    ];
+    const OUTPUT_DIMENSIONS: &'static [Dimension] = &[Dimension::ThermalExpansion; 3];
+
+    fn compute<T: Scalar>(number_of_model: u8, inputs: &[T]) -> Result<Vec<T>>
+    where
+        f64: ScalarOps<T>,
+    {
+        let [fibre_content, e_for_fiber, nu_for_fiber, alpha_for_fiber, e_for_matrix, nu_for_matrix, alpha_for_matrix] =
+            unpack_inputs(inputs)?;
+        thermal_expansion_for_unidirectional_composite_generic(
+            number_of_model,
+            fibre_content,
+            e_for_fiber,
+            nu_for_fiber,
+            alpha_for_fiber,
+            e_for_matrix,
+            nu_for_matrix,
+            alpha_for_matrix,
+        )
+        .map(Vec::from)
+    }
+}
+
+#[allow(clippy::too_many_arguments)]
+fn compute<T: Scalar>(
+    model: Model,
+    fibre_content: T,
+    e_for_fiber: T,
+    nu_for_fiber: T,
+    alpha_for_fiber: T,
+    e_for_matrix: T,
+    nu_for_matrix: T,
+    alpha_for_matrix: T,
+) -> Result<[T; 3]>
+where
+    f64: ScalarOps<T>,
+{
+    let g_for_fiber = e_for_fiber / (2.0 * (1.0 + nu_for_fiber));
+    let g_for_matrix = e_for_matrix / (2.0 * (1.0 + nu_for_matrix));
+    let chi_for_fiber = 3.0 - 4.0 * nu_for_fiber;
+    let chi_for_matrix = 3.0 - 4.0 * nu_for_matrix;
+    let a = elastic_modules_for_unidirectional_composite_generic(
         2,
         fibre_content,
         e_for_fiber,
@@ -62,40 +172,35 @@ pub fn thermal_expansion_for_unidirectional_composite(
         e_for_matrix,
         nu_for_matrix,
     )?;
-    std::panic::catch_unwind(|| {
-        let nu21 = a[3] * a[0] / a[1];
-        let nu31 = a[4] * a[0] / a[2];
-        match model {
-            Model::Vanin => {
-                let alpha1 = alpha_for_matrix
-                    - (alpha_for_matrix - alpha_for_fiber) * fibre_content / a[0]
-                        * (e_for_fiber
-                            + (8.0
-                                * g_for_matrix
-                                * (nu_for_fiber - nu_for_matrix)
-                                * (1.0 - fibre_content)
-                                * (1.0 + nu_for_fiber))
-                                / (2.0 - fibre_content
-                                    + fibre_content * chi_for_matrix
-                                    + (1.0 - fibre_content)
-                                        * (chi_for_fiber + 1.0)
-                                        * (g_for_matrix)
-                                        / (g_for_fiber)));
-                let alpha2 = alpha_for_matrix + (alpha_for_matrix - alpha1) * nu21
-                    - (alpha_for_matrix - alpha_for_fiber)
-                        * (1.0 + nu_for_fiber)
-                        * (nu_for_matrix - nu21)
-                        / (nu_for_matrix - nu_for_fiber);
-                let alpha3 = alpha_for_matrix + (alpha_for_matrix - alpha1) * nu31
-                    - (alpha_for_matrix - alpha_for_fiber)
-                        * (1.0 + nu_for_fiber)
-                        * (nu_for_matrix - nu31)
-                        / (nu_for_matrix - nu_for_fiber);
-                [alpha1, alpha2, alpha3]
-            }
+    let nu21 = a[3] * a[0] / a[1];
+    let nu31 = a[4] * a[0] / a[2];
+    match model {
+        Model::Vanin => {
+            let alpha1 = alpha_for_matrix
+                - (alpha_for_matrix - alpha_for_fiber) * fibre_content / a[0]
+                    * (e_for_fiber
+                        + (8.0
+                            * g_for_matrix
+                            * (nu_for_fiber - nu_for_matrix)
+                            * (1.0 - fibre_content)
+                            * (1.0 + nu_for_fiber))
+                            / (2.0 - fibre_content
+                                + fibre_content * chi_for_matrix
+                                + (1.0 - fibre_content) * (chi_for_fiber + 1.0) * (g_for_matrix)
+                                    / (g_for_fiber)));
+            let alpha2 = alpha_for_matrix + (alpha_for_matrix - alpha1) * nu21
+                - (alpha_for_matrix - alpha_for_fiber)
+                    * (1.0 + nu_for_fiber)
+                    * (nu_for_matrix - nu21)
+                    / (nu_for_matrix - nu_for_fiber);
+            let alpha3 = alpha_for_matrix + (alpha_for_matrix - alpha1) * nu31
+                - (alpha_for_matrix - alpha_for_fiber)
+                    * (1.0 + nu_for_fiber)
+                    * (nu_for_matrix - nu31)
+                    / (nu_for_matrix - nu_for_fiber);
+            Ok([alpha1, alpha2, alpha3])
         }
-    })
-    .map_err(Error::NumericalError)
+    }
 }
 
 #[cfg(test)]
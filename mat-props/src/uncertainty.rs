@@ -0,0 +1,547 @@
+use crate::{computation::Computation, sensitivities::jacobian, Error, Result};
+use rand::{rngs::StdRng, Rng, SeedableRng};
+use rand_distr::StandardNormal;
+
+/// The [probability distribution] of an input.
+///
+/// [probability distribution]: https://en.wikipedia.org/wiki/Probability_distribution
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(rename_all = "snake_case")
+)]
+pub enum Distribution {
+    /// The input without scatter.
+    Constant(f64),
+    /// The [normal distribution].
+    ///
+    /// [normal distribution]: https://en.wikipedia.org/wiki/Normal_distribution
+    Normal { mean: f64, std_dev: f64 },
+    /// The [log-normal distribution] given by the mean and the standard deviation of the input itself
+    /// (not of its logarithm).
+    ///
+    /// [log-normal distribution]: https://en.wikipedia.org/wiki/Log-normal_distribution
+    LogNormal { mean: f64, std_dev: f64 },
+    /// The [continuous uniform distribution].
+    ///
+    /// [continuous uniform distribution]: https://en.wikipedia.org/wiki/Continuous_uniform_distribution
+    Uniform { min: f64, max: f64 },
+    /// The [truncated normal distribution] given by the parameters of the parent normal distribution.
+    /// The standard deviation must be positive and `min` less than `max`, the bounds may be infinite.
+    ///
+    /// [truncated normal distribution]: https://en.wikipedia.org/wiki/Truncated_normal_distribution
+    TruncatedNormal {
+        mean: f64,
+        std_dev: f64,
+        min: f64,
+        max: f64,
+    },
+}
+
+impl Distribution {
+    /// Returns the mean of the distribution.
+    pub fn mean(&self) -> f64 {
+        match *self {
+            Self::Constant(value) => value,
+            Self::Normal { mean, .. } | Self::LogNormal { mean, .. } => mean,
+            Self::Uniform { min, max } => (min + max) / 2.0,
+            Self::TruncatedNormal {
+                mean,
+                std_dev,
+                min,
+                max,
+            } => {
+                let alpha = (min - mean) / std_dev;
+                let beta = (max - mean) / std_dev;
+                let z = normal_cdf(beta) - normal_cdf(alpha);
+                mean + (normal_pdf(alpha) - normal_pdf(beta)) / z * std_dev
+            }
+        }
+    }
+
+    /// Returns the standard deviation of the distribution.
+    pub fn std_dev(&self) -> f64 {
+        match *self {
+            Self::Constant(_) => 0.0,
+            Self::Normal { std_dev, .. } | Self::LogNormal { std_dev, .. } => std_dev,
+            Self::Uniform { min, max } => (max - min) / 12f64.sqrt(),
+            Self::TruncatedNormal {
+                mean,
+                std_dev,
+                min,
+                max,
+            } => {
+                let alpha = (min - mean) / std_dev;
+                let beta = (max - mean) / std_dev;
+                let z = normal_cdf(beta) - normal_cdf(alpha);
+                // φ(±∞) * ±∞ vanishes
+                let alpha_pdf = if alpha.is_finite() {
+                    alpha * normal_pdf(alpha)
+                } else {
+                    0.0
+                };
+                let beta_pdf = if beta.is_finite() {
+                    beta * normal_pdf(beta)
+                } else {
+                    0.0
+                };
+                let delta = (normal_pdf(alpha) - normal_pdf(beta)) / z;
+                std_dev * (1.0 + (alpha_pdf - beta_pdf) / z - delta * delta).sqrt()
+            }
+        }
+    }
+
+    // Maps the standard normal variable to the distribution, i.e. `F⁻¹(Φ(z))`.
+    fn transform_standard_normal(&self, z: f64) -> f64 {
+        match *self {
+            Self::Constant(value) => value,
+            Self::Normal { mean, std_dev } => mean + std_dev * z,
+            Self::LogNormal { mean, std_dev } => {
+                let sigma_squared = (1.0 + (std_dev / mean) * (std_dev / mean)).ln();
+                let mu = mean.ln() - sigma_squared / 2.0;
+                (mu + sigma_squared.sqrt() * z).exp()
+            }
+            Self::Uniform { min, max } => min + (max - min) * normal_cdf(z),
+            Self::TruncatedNormal {
+                mean,
+                std_dev,
+                min,
+                max,
+            } => {
+                let cdf_min = normal_cdf((min - mean) / std_dev);
+                let cdf_max = normal_cdf((max - mean) / std_dev);
+                let p = cdf_min + normal_cdf(z) * (cdf_max - cdf_min);
+                (mean + std_dev * inverse_normal_cdf(p)).clamp(min, max)
+            }
+        }
+    }
+}
+
+/// The method of [propagation of uncertainty].
+///
+/// [propagation of uncertainty]: https://en.wikipedia.org/wiki/Propagation_of_uncertainty
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(rename_all = "snake_case")
+)]
+pub enum Method {
+    /// The [Monte Carlo method] with the given number of samples and the seed of the random number generator.
+    ///
+    /// The results are reproducible for the same seed.
+    ///
+    /// [Monte Carlo method]: https://en.wikipedia.org/wiki/Monte_Carlo_method
+    MonteCarlo { number_of_samples: usize, seed: u64 },
+    /// The first-order second-moment method, i.e. the linearization of the model at the means
+    /// of the inputs with the [Jacobian](crate::jacobian). The outputs are assumed to be normal.
+    FirstOrderSecondMoment,
+}
+
+/// The statistics of an output.
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct OutputStatistics {
+    /// The mean of the output.
+    pub mean: f64,
+    /// The standard deviation of the output.
+    pub std_dev: f64,
+    /// The values of the output at the requested probabilities in the same order.
+    pub percentiles: Vec<f64>,
+    /// The [A-basis] allowable: 99 % of the population exceeds it with the 95 % confidence.
+    /// For [`Method::FirstOrderSecondMoment`], it is just the 1st percentile of the normal output,
+    /// without the confidence.
+    ///
+    /// [A-basis]: https://en.wikipedia.org/wiki/A-basis
+    pub a_basis: f64,
+    /// The [B-basis] allowable: 90 % of the population exceeds it with the 95 % confidence.
+    /// For [`Method::FirstOrderSecondMoment`], it is just the 10th percentile of the normal output,
+    /// without the confidence.
+    ///
+    /// [B-basis]: https://en.wikipedia.org/wiki/A-basis
+    pub b_basis: f64,
+}
+
+/// Propagates the scatter of the inputs of the computation to its outputs.
+///
+/// Correlated inputs are sampled with the [Gaussian copula], i.e. the correlation is imposed on
+/// the underlying standard normal variables. The same correlation is used by the first-order
+/// second-moment method as the correlation of the inputs.
+///
+/// ## Arguments
+///
+/// * `number_of_model` - the number of the selected model of the computation.
+/// * `distributions` - the distributions of the inputs in the order of [`Computation::INPUTS`].
+/// * `correlations` - the optional correlation matrix of the inputs, the inputs are independent if it is `None`.
+/// * `method` - the method of the propagation. [`Method::MonteCarlo`] needs at least 2 samples.
+/// * `probabilities` - the probabilities in the range from `0.0` to `1.0` at which the percentiles are computed.
+///
+/// ## Returns
+///
+/// Returns the [`OutputStatistics`] for each output in the order of [`Computation::OUTPUTS`].
+/// For [`Method::MonteCarlo`], the percentiles are interpolated between the sorted samples, and the allowables are
+/// the lower confidence bounds of the 1st and 10th percentiles by the order statistics; they are NaN
+/// if there are too few samples.
+///
+/// [Gaussian copula]: https://en.wikipedia.org/wiki/Copula_(probability_theory)#Gaussian_copula
+pub fn propagate_uncertainty<C: Computation>(
+    number_of_model: u8,
+    distributions: &[Distribution],
+    correlations: Option<&[Vec<f64>]>,
+    method: Method,
+    probabilities: &[f64],
+) -> Result<Vec<OutputStatistics>> {
+    if distributions.len() != C::INPUTS.len() {
+        return Err(Error::WrongNumberOfInputs {
+            expected: C::INPUTS.len(),
+            actual: distributions.len(),
+        });
+    }
+    if probabilities.iter().any(|p| !(0.0..=1.0).contains(p)) {
+        return Err(Error::InvalidArgument {
+            name: "probabilities",
+            reason: "the probabilities must be in the range from 0 to 1",
+        });
+    }
+    if distributions
+        .iter()
+        .any(|distribution| match *distribution {
+            Distribution::TruncatedNormal {
+                mean,
+                std_dev,
+                min,
+                max,
+            } => !(mean.is_finite() && std_dev.is_finite() && std_dev > 0.0 && min < max),
+            _ => false,
+        })
+    {
+        return Err(Error::InvalidArgument {
+            name: "distributions",
+            reason: "the truncated normal distributions need a finite mean, a positive standard deviation and min < max",
+        });
+    }
+    let n = distributions.len();
+    let identity: Vec<Vec<f64>> = (0..n)
+        .map(|i| (0..n).map(|j| if i == j { 1.0 } else { 0.0 }).collect())
+        .collect();
+    let correlations = correlations.unwrap_or(&identity);
+
+    match method {
+        Method::MonteCarlo {
+            number_of_samples,
+            seed,
+        } => {
+            if number_of_samples < 2 {
+                return Err(Error::InvalidArgument {
+                    name: "number_of_samples",
+                    reason: "at least 2 samples are needed for the standard deviation",
+                });
+            }
+            let cholesky = cholesky(correlations)?;
+            let mut rng = StdRng::seed_from_u64(seed);
+            let mut samples = vec![Vec::with_capacity(number_of_samples); C::OUTPUTS.len()];
+            for _ in 0..number_of_samples {
+                let independent: Vec<f64> = (0..n).map(|_| rng.sample(StandardNormal)).collect();
+                let inputs: Vec<f64> = distributions
+                    .iter()
+                    .enumerate()
+                    .map(|(i, distribution)| {
+                        let z = (0..=i).map(|j| cholesky[i][j] * independent[j]).sum();
+                        distribution.transform_standard_normal(z)
+                    })
+                    .collect();
+                let outputs = C::evaluate(number_of_model, &inputs)?;
+                for (output_samples, output) in samples.iter_mut().zip(outputs) {
+                    output_samples.push(output);
+                }
+            }
+            Ok(samples
+                .into_iter()
+                .map(|output_samples| sample_statistics(output_samples, probabilities))
+                .collect())
+        }
+        Method::FirstOrderSecondMoment => {
+            if correlations.len() != n || correlations.iter().any(|row| row.len() != n) {
+                return Err(Error::InvalidCorrelationMatrix);
+            }
+            let means: Vec<f64> = distributions.iter().map(Distribution::mean).collect();
+            let std_devs: Vec<f64> = distributions.iter().map(Distribution::std_dev).collect();
+            let output_means = C::evaluate(number_of_model, &means)?;
+            let jacobian = jacobian::<C>(number_of_model, &means)?;
+            Ok(output_means
+                .into_iter()
+                .zip(jacobian)
+                .map(|(mean, gradient)| {
+                    let mut variance = 0.0;
+                    for i in 0..n {
+                        for j in 0..n {
+                            variance += gradient[i]
+                                * gradient[j]
+                                * correlations[i][j]
+                                * std_devs[i]
+                                * std_devs[j];
+                        }
+                    }
+                    let std_dev = variance.sqrt();
+                    OutputStatistics {
+                        mean,
+                        std_dev,
+                        percentiles: probabilities
+                            .iter()
+                            .map(|&p| mean + std_dev * inverse_normal_cdf(p))
+                            .collect(),
+                        a_basis: mean + std_dev * inverse_normal_cdf(0.01),
+                        b_basis: mean + std_dev * inverse_normal_cdf(0.1),
+                    }
+                })
+                .collect())
+        }
+    }
+}
+
+fn sample_statistics(mut samples: Vec<f64>, probabilities: &[f64]) -> OutputStatistics {
+    let n = samples.len() as f64;
+    let mean = samples.iter().sum::<f64>() / n;
+    let std_dev = (samples.iter().map(|x| (x - mean) * (x - mean)).sum::<f64>() / (n - 1.0)).sqrt();
+    samples.sort_by(f64::total_cmp);
+    let percentile = |p: f64| {
+        let position = p * (n - 1.0);
+        let lower = position.floor() as usize;
+        let upper = position.ceil() as usize;
+        samples[lower] + (position - lower as f64) * (samples[upper] - samples[lower])
+    };
+    // The lower 95 % confidence bound of the percentile is the order statistic of the rank
+    // given by the normal approximation of the binomial distribution.
+    let basis = |p: f64| {
+        let rank = n * p - inverse_normal_cdf(0.95) * (n * p * (1.0 - p)).sqrt();
+        if rank < 1.0 {
+            f64::NAN
+        } else {
+            samples[rank.floor() as usize - 1]
+        }
+    };
+    OutputStatistics {
+        mean,
+        std_dev,
+        percentiles: probabilities.iter().map(|&p| percentile(p)).collect(),
+        a_basis: basis(0.01),
+        b_basis: basis(0.1),
+    }
+}
+
+// The lower triangular matrix `L` of the Cholesky decomposition `R = L * Lᵀ`.
+fn cholesky(matrix: &[Vec<f64>]) -> Result<Vec<Vec<f64>>> {
+    let n = matrix.len();
+    if matrix.iter().any(|row| row.len() != n) {
+        return Err(Error::InvalidCorrelationMatrix);
+    }
+    let mut l = vec![vec![0.0; n]; n];
+    for i in 0..n {
+        for j in 0..=i {
+            let sum: f64 = (0..j).map(|k| l[i][k] * l[j][k]).sum();
+            if i == j {
+                let diagonal = matrix[i][i] - sum;
+                if diagonal <= 0.0 {
+                    return Err(Error::InvalidCorrelationMatrix);
+                }
+                l[i][j] = diagonal.sqrt();
+            } else {
+                l[i][j] = (matrix[i][j] - sum) / l[j][j];
+            }
+        }
+    }
+    Ok(l)
+}
+
+fn normal_pdf(x: f64) -> f64 {
+    (-x * x / 2.0).exp() / (2.0 * core::f64::consts::PI).sqrt()
+}
+
+fn normal_cdf(x: f64) -> f64 {
+    0.5 * libm::erfc(-x / core::f64::consts::SQRT_2)
+}
+
+// Acklam's rational approximation refined by one step of Halley's method.
+fn inverse_normal_cdf(p: f64) -> f64 {
+    const A: [f64; 6] = [
+        -3.969683028665376e1,
+        2.209460984245205e2,
+        -2.759285104469687e2,
+        1.38357751867269e2,
+        -3.066479806614716e1,
+        2.506628277459239,
+    ];
+    const B: [f64; 5] = [
+        -5.447609879822406e1,
+        1.615858368580409e2,
+        -1.556989798598866e2,
+        6.680131188771972e1,
+        -1.328068155288572e1,
+    ];
+    const C: [f64; 6] = [
+        -7.784894002430293e-3,
+        -3.223964580411365e-1,
+        -2.400758277161838,
+        -2.549732539343734,
+        4.374664141464968,
+        2.938163982698783,
+    ];
+    const D: [f64; 4] = [
+        7.784695709041462e-3,
+        3.224671290700398e-1,
+        2.445134137142996,
+        3.754408661907416,
+    ];
+    const P_LOW: f64 = 0.02425;
+
+    if p <= 0.0 {
+        return f64::NEG_INFINITY;
+    }
+    if p >= 1.0 {
+        return f64::INFINITY;
+    }
+    let x = if p < P_LOW {
+        let q = (-2.0 * p.ln()).sqrt();
+        (((((C[0] * q + C[1]) * q + C[2]) * q + C[3]) * q + C[4]) * q + C[5])
+            / ((((D[0] * q + D[1]) * q + D[2]) * q + D[3]) * q + 1.0)
+    } else if p <= 1.0 - P_LOW {
+        let q = p - 0.5;
+        let r = q * q;
+        (((((A[0] * r + A[1]) * r + A[2]) * r + A[3]) * r + A[4]) * r + A[5]) * q
+            / (((((B[0] * r + B[1]) * r + B[2]) * r + B[3]) * r + B[4]) * r + 1.0)
+    } else {
+        let q = (-2.0 * (1.0 - p).ln()).sqrt();
+        -(((((C[0] * q + C[1]) * q + C[2]) * q + C[3]) * q + C[4]) * q + C[5])
+            / ((((D[0] * q + D[1]) * q + D[2]) * q + D[3]) * q + 1.0)
+    };
+    let e = normal_cdf(x) - p;
+    let u = e * (2.0 * core::f64::consts::PI).sqrt() * (x * x / 2.0).exp();
+    x - u / (1.0 + x * u / 2.0)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ElasticModulesForUnidirectionalComposite;
+
+    #[test]
+    fn test() {
+        let distributions = [
+            Distribution::TruncatedNormal {
+                mean: 0.6,
+                std_dev: 0.03,
+                min: 0.0,
+                max: 1.0,
+            },
+            Distribution::Constant(230.0),
+            Distribution::Constant(0.2),
+            Distribution::LogNormal {
+                mean: 3.5,
+                std_dev: 0.175,
+            },
+            Distribution::Uniform {
+                min: 0.33,
+                max: 0.37,
+            },
+        ];
+        let correlations = [
+            vec![1.0, 0.0, 0.0, -0.5, 0.0],
+            vec![0.0, 1.0, 0.0, 0.0, 0.0],
+            vec![0.0, 0.0, 1.0, 0.0, 0.0],
+            vec![-0.5, 0.0, 0.0, 1.0, 0.0],
+            vec![0.0, 0.0, 0.0, 0.0, 1.0],
+        ];
+        let monte_carlo = propagate_uncertainty::<ElasticModulesForUnidirectionalComposite>(
+            1,
+            &distributions,
+            Some(&correlations),
+            Method::MonteCarlo {
+                number_of_samples: 20000,
+                seed: 42,
+            },
+            &[0.1, 0.5, 0.9],
+        )
+        .unwrap();
+        let first_order = propagate_uncertainty::<ElasticModulesForUnidirectionalComposite>(
+            1,
+            &distributions,
+            Some(&correlations),
+            Method::FirstOrderSecondMoment,
+            &[0.1, 0.5, 0.9],
+        )
+        .unwrap();
+
+        // E1 is linear in the rule of mixtures
+        let [monte_carlo, first_order] = [&monte_carlo[0], &first_order[0]];
+        assert!((monte_carlo.mean - first_order.mean).abs() < 0.01 * first_order.mean);
+        assert!((monte_carlo.std_dev - first_order.std_dev).abs() < 0.05 * first_order.std_dev);
+        assert!(monte_carlo.a_basis < monte_carlo.b_basis);
+        assert!(monte_carlo.b_basis < monte_carlo.percentiles[0]);
+        assert!((first_order.percentiles[1] - first_order.mean).abs() < 1e-9);
+    }
+
+    #[test]
+    fn invalid_arguments() {
+        let distributions = [Distribution::Constant(1.0); 5];
+        let propagate = |method, probabilities: &[f64]| {
+            propagate_uncertainty::<ElasticModulesForUnidirectionalComposite>(
+                1,
+                &distributions,
+                None,
+                method,
+                probabilities,
+            )
+        };
+        let monte_carlo = |number_of_samples| Method::MonteCarlo {
+            number_of_samples,
+            seed: 42,
+        };
+        assert!(matches!(
+            propagate(monte_carlo(0), &[0.5]),
+            Err(Error::InvalidArgument {
+                name: "number_of_samples",
+                ..
+            })
+        ));
+        for p in [-0.1, 1.1, f64::NAN] {
+            assert!(matches!(
+                propagate(Method::FirstOrderSecondMoment, &[p]),
+                Err(Error::InvalidArgument {
+                    name: "probabilities",
+                    ..
+                })
+            ));
+        }
+        assert!(propagate(monte_carlo(2), &[0.0, 1.0]).is_ok());
+
+        for (std_dev, min, max) in [(1.0, 2.0, 0.5), (1.0, f64::NAN, 2.0), (0.0, 0.5, 2.0)] {
+            let mut distributions = distributions;
+            distributions[0] = Distribution::TruncatedNormal {
+                mean: 1.0,
+                std_dev,
+                min,
+                max,
+            };
+            assert!(matches!(
+                propagate_uncertainty::<ElasticModulesForUnidirectionalComposite>(
+                    1,
+                    &distributions,
+                    None,
+                    monte_carlo(2),
+                    &[],
+                ),
+                Err(Error::InvalidArgument {
+                    name: "distributions",
+                    ..
+                })
+            ));
+        }
+    }
+
+    #[test]
+    fn inverse_normal_cdf_test() {
+        assert!((inverse_normal_cdf(0.975) - 1.959963984540054).abs() < 1e-12);
+        assert!((normal_cdf(inverse_normal_cdf(0.01)) - 0.01).abs() < 1e-15);
+    }
+}
@@ -0,0 +1,155 @@
+use crate::{computation::Computation, Error, Result};
+use alloc::vec::Vec;
+use enum_primitive_derive::Primitive;
+use num_traits::FromPrimitive;
+
+/// The physical dimension of an input or an output.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(rename_all = "snake_case")
+)]
+pub enum Dimension {
+    /// Fractions, e.g. the fibre content, and Poisson's ratios.
+    Dimensionless,
+    /// Sizes of the honeycomb cell.
+    Length,
+    /// Angles of the honeycomb cell.
+    Angle,
+    /// Young's and shear modules.
+    Pressure,
+    /// [Thermal conductivity](https://en.wikipedia.org/wiki/Thermal_conductivity).
+    ThermalConductivity,
+    /// The coefficient of [thermal expansion](https://en.wikipedia.org/wiki/Thermal_expansion).
+    ThermalExpansion,
+}
+
+/// The system of units of the inputs and the outputs.
+///
+/// The models don't depend on the units of the inputs as long as they are consistent,
+/// e.g. all modules are in the same unit, except for the angles which must be in radians.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Primitive)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(rename_all = "snake_case")
+)]
+pub enum UnitSystem {
+    /// The values are used as they are, the angles are in radians.
+    #[default]
+    Consistent = 0,
+    /// Pa, m, rad, W/(m·K), 1/K.
+    Si = 1,
+    /// GPa, mm, °, W/(m·K), 1/K.
+    Engineering = 2,
+}
+
+impl UnitSystem {
+    // The value of the unit in SI
+    fn unit(self, dimension: Dimension) -> f64 {
+        match (self, dimension) {
+            (Self::Engineering, Dimension::Pressure) => 1e9,
+            (Self::Engineering, Dimension::Length) => 1e-3,
+            (Self::Engineering, Dimension::Angle) => core::f64::consts::PI / 180.0,
+            _ => 1.0,
+        }
+    }
+
+    /// Returns the symbol of the unit of the dimension or an empty string if the unit is arbitrary.
+    pub fn symbol(self, dimension: Dimension) -> &'static str {
+        match (self, dimension) {
+            (_, Dimension::Dimensionless) => "",
+            (Self::Consistent, Dimension::Angle) | (Self::Si, Dimension::Angle) => "rad",
+            (Self::Consistent, _) => "",
+            (Self::Si, Dimension::Length) => "m",
+            (Self::Si, Dimension::Pressure) => "Pa",
+            (Self::Engineering, Dimension::Length) => "mm",
+            (Self::Engineering, Dimension::Angle) => "°",
+            (Self::Engineering, Dimension::Pressure) => "GPa",
+            (_, Dimension::ThermalConductivity) => "W/(m·K)",
+            (_, Dimension::ThermalExpansion) => "1/K",
+        }
+    }
+
+    /// Converts the value of the dimension from this unit system to the other one.
+    ///
+    /// [`UnitSystem::Consistent`] is converted as [`UnitSystem::Si`].
+    pub fn convert(self, value: f64, dimension: Dimension, to: UnitSystem) -> f64 {
+        if self == to {
+            value
+        } else {
+            value * self.unit(dimension) / to.unit(dimension)
+        }
+    }
+}
+
+/// Evaluates the computation with the inputs and the outputs in the given unit systems.
+///
+/// ## Arguments
+///
+/// * `number_of_model` - the number of the selected model of the computation.
+/// * `inputs` - the inputs in the order of [`Computation::INPUTS`] in the units of `input_unit_system`.
+/// * `input_unit_system` - the number of the unit system of the inputs, represented by the discriminant in [`UnitSystem`].
+/// * `output_unit_system` - the number of the unit system of the outputs, represented by the discriminant in [`UnitSystem`].
+///
+/// ## Returns
+///
+/// Returns the outputs in the order of [`Computation::OUTPUTS`] in the units of `output_unit_system`.
+pub fn evaluate_in_units<C: Computation>(
+    number_of_model: u8,
+    inputs: &[f64],
+    input_unit_system: u8,
+    output_unit_system: u8,
+) -> Result<Vec<f64>> {
+    let input_unit_system =
+        UnitSystem::from_u8(input_unit_system).ok_or(Error::UnknownUnitSystem)?;
+    let output_unit_system =
+        UnitSystem::from_u8(output_unit_system).ok_or(Error::UnknownUnitSystem)?;
+    if inputs.len() != C::INPUTS.len() {
+        return Err(Error::WrongNumberOfInputs {
+            expected: C::INPUTS.len(),
+            actual: inputs.len(),
+        });
+    }
+    let inputs: Vec<f64> = inputs
+        .iter()
+        .zip(C::INPUT_DIMENSIONS)
+        .map(|(&input, &dimension)| input_unit_system.convert(input, dimension, UnitSystem::Si))
+        .collect();
+    let outputs = C::evaluate(number_of_model, &inputs)?;
+    Ok(outputs
+        .into_iter()
+        .zip(C::OUTPUT_DIMENSIONS)
+        .map(|(output, &dimension)| UnitSystem::Si.convert(output, dimension, output_unit_system))
+        .collect())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{elastic_modules_for_honeycomb, ElasticModulesForHoneycomb};
+
+    #[test]
+    fn test() {
+        let outputs = evaluate_in_units::<ElasticModulesForHoneycomb>(
+            1,
+            &[9.24, 8.4619, 0.4, 30.0, 70.7, 0.2],
+            UnitSystem::Engineering as u8,
+            UnitSystem::Si as u8,
+        )
+        .unwrap();
+        let [e1, ..] = elastic_modules_for_honeycomb(
+            1,
+            9.24e-3,
+            8.4619e-3,
+            0.4e-3,
+            core::f64::consts::PI / 6.0,
+            70.7e9,
+            0.2,
+        )
+        .unwrap();
+        assert!((outputs[0] - e1).abs() < 1e-9 * e1);
+        assert_eq!(UnitSystem::Si.symbol(Dimension::Pressure), "Pa");
+    }
+}
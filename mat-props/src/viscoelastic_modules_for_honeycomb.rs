@@ -0,0 +1,175 @@
+use crate::{
+    elastic_modules_for_honeycomb::elastic_modules_for_honeycomb_generic,
+    prony_series::carson_transform, Result,
+};
+use alloc::vec::Vec;
+use num_complex::Complex64;
+
+/// Computes time-dependent [relaxation] modules for honeycomb structures made of a viscoelastic material
+/// using the elastic–viscoelastic [correspondence principle].
+///
+/// The elastic model is evaluated in the Laplace domain with the Carson transform of the
+/// relaxation modulus of the honeycomb material, and the result is inverted with Schapery's quasi-elastic
+/// approximation `E(t) ≈ s * E(s)` at `s = 1 / (2 * t)`. The Poisson's ratio of the honeycomb material
+/// is assumed to be time-independent.
+///
+/// ## Arguments
+///
+/// * `number_of_model` - the number of the elastic model, see
+///   [`elastic_modules_for_honeycomb`](crate::elastic_modules_for_honeycomb).
+/// * `l_cell_side_size` - the side length of the hexagonal cell.
+/// * `h_cell_side_size` - the height of the hexagonal cell.
+/// * `wall_thickness` - the thickness of the cell walls.
+/// * `angle` - the angle of inclination of the hexagonal cell.
+/// * `e_inf_for_honeycomb` - the long-term (equilibrium) Young's modulus for the honeycomb material.
+/// * `prony_series_for_honeycomb` - the `[e_i, tau_i]` terms of the [Prony series] of the relaxation modulus
+///   of the honeycomb material `E(t) = e_inf + sum(e_i * exp(-t / tau_i))`.
+/// * `nu_for_honeycomb` - Poisson's ratio for the honeycomb material.
+/// * `times` - the times at which the relaxation modules are computed.
+///
+/// ## Returns
+///
+/// Returns the nine relaxation modules in the order of
+/// [`elastic_modules_for_honeycomb`](crate::elastic_modules_for_honeycomb)
+/// for each of the `times`.
+///
+/// [relaxation]: https://en.wikipedia.org/wiki/Stress_relaxation
+/// [correspondence principle]: https://en.wikipedia.org/wiki/Viscoelasticity
+/// [Prony series]: https://en.wikipedia.org/wiki/Prony%27s_method
+#[allow(clippy::too_many_arguments)]
+pub fn relaxation_modules_for_honeycomb(
+    number_of_model: u8,
+    l_cell_side_size: f64,
+    h_cell_side_size: f64,
+    wall_thickness: f64,
+    angle: f64,
+    e_inf_for_honeycomb: f64,
+    prony_series_for_honeycomb: &[[f64; 2]],
+    nu_for_honeycomb: f64,
+    times: &[f64],
+) -> Result<Vec<[f64; 9]>> {
+    let nested_res = crate::catch_unwind(|| {
+        times
+            .iter()
+            .map(|&t| {
+                let s = 1.0 / (2.0 * t);
+                elastic_modules_for_honeycomb_generic(
+                    number_of_model,
+                    l_cell_side_size,
+                    h_cell_side_size,
+                    wall_thickness,
+                    angle,
+                    carson_transform(e_inf_for_honeycomb, prony_series_for_honeycomb, s),
+                    nu_for_honeycomb,
+                )
+            })
+            .collect::<Result<Vec<_>>>()
+    });
+    match nested_res {
+        Ok(res) => res,
+        Err(err) => Err(err),
+    }
+}
+
+/// Computes [complex modules] for honeycomb structures made of a viscoelastic material
+/// using the elastic–viscoelastic [correspondence principle].
+///
+/// The elastic model is evaluated in the frequency domain with the complex modulus of the
+/// honeycomb material. The Poisson's ratio of the honeycomb material is assumed to be
+/// frequency-independent.
+///
+/// ## Arguments
+///
+/// * `number_of_model` - the number of the elastic model, see
+///   [`elastic_modules_for_honeycomb`](crate::elastic_modules_for_honeycomb).
+/// * `l_cell_side_size` - the side length of the hexagonal cell.
+/// * `h_cell_side_size` - the height of the hexagonal cell.
+/// * `wall_thickness` - the thickness of the cell walls.
+/// * `angle` - the angle of inclination of the hexagonal cell.
+/// * `e_inf_for_honeycomb` - the long-term (equilibrium) Young's modulus for the honeycomb material.
+/// * `prony_series_for_honeycomb` - the `[e_i, tau_i]` terms of the [Prony series] of the relaxation modulus
+///   of the honeycomb material `E(t) = e_inf + sum(e_i * exp(-t / tau_i))`.
+/// * `nu_for_honeycomb` - Poisson's ratio for the honeycomb material.
+/// * `angular_frequencies` - the angular frequencies at which the complex modules are computed.
+///
+/// ## Returns
+///
+/// Returns the nine complex modules in the order of
+/// [`elastic_modules_for_honeycomb`](crate::elastic_modules_for_honeycomb)
+/// for each of the `angular_frequencies`. Each modulus is `[storage, loss]`.
+///
+/// [complex modules]: https://en.wikipedia.org/wiki/Dynamic_modulus
+/// [correspondence principle]: https://en.wikipedia.org/wiki/Viscoelasticity
+/// [Prony series]: https://en.wikipedia.org/wiki/Prony%27s_method
+#[allow(clippy::too_many_arguments)]
+pub fn complex_modules_for_honeycomb(
+    number_of_model: u8,
+    l_cell_side_size: f64,
+    h_cell_side_size: f64,
+    wall_thickness: f64,
+    angle: f64,
+    e_inf_for_honeycomb: f64,
+    prony_series_for_honeycomb: &[[f64; 2]],
+    nu_for_honeycomb: f64,
+    angular_frequencies: &[f64],
+) -> Result<Vec<[[f64; 2]; 9]>> {
+    let nested_res = crate::catch_unwind(|| {
+        angular_frequencies
+            .iter()
+            .map(|&omega| {
+                let s = Complex64::new(0.0, omega);
+                let res = elastic_modules_for_honeycomb_generic(
+                    number_of_model,
+                    Complex64::from(l_cell_side_size),
+                    Complex64::from(h_cell_side_size),
+                    Complex64::from(wall_thickness),
+                    Complex64::from(angle),
+                    carson_transform(e_inf_for_honeycomb, prony_series_for_honeycomb, s),
+                    Complex64::from(nu_for_honeycomb),
+                )?;
+                Ok(res.map(|m| [m.re, m.im]))
+            })
+            .collect::<Result<Vec<_>>>()
+    });
+    match nested_res {
+        Ok(res) => res,
+        Err(err) => Err(err),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::f64::consts::PI;
+
+    #[test]
+    fn test() {
+        let res = relaxation_modules_for_honeycomb(
+            1,
+            9.24,
+            8.4619,
+            0.4,
+            PI / 6.0,
+            5.0,
+            &[[2.07, 10.0]],
+            0.2,
+            &[10.0],
+        )
+        .unwrap();
+        assert_eq!(res[0][2], 0.2891191295949775);
+
+        let res = complex_modules_for_honeycomb(
+            1,
+            9.24,
+            8.4619,
+            0.4,
+            PI / 6.0,
+            5.0,
+            &[[2.07, 10.0]],
+            0.2,
+            &[0.1],
+        )
+        .unwrap();
+        assert_eq!(res[0][2], [0.3066491998428277, 0.05259021074355039]);
+    }
+}
@@ -0,0 +1,193 @@
+use crate::{
+    elastic_modules_for_unidirectional_composite::elastic_modules_for_unidirectional_composite_generic,
+    prony_series::carson_transform, Result,
+};
+use alloc::vec::Vec;
+use num_complex::Complex64;
+
+/// Computes time-dependent [relaxation] modules for unidirectional composite with a viscoelastic matrix
+/// using the elastic–viscoelastic [correspondence principle].
+///
+/// The elastic model is evaluated in the Laplace domain with the Carson transform of the
+/// relaxation modulus of the matrix, and the result is inverted with Schapery's quasi-elastic
+/// approximation `E(t) ≈ s * E(s)` at `s = 1 / (2 * t)`. The fibre is elastic and the Poisson's
+/// ratio of the matrix is assumed to be time-independent.
+///
+/// ## Arguments
+///
+/// * `number_of_model` - the number of the elastic model, see
+///   [`elastic_modules_for_unidirectional_composite`](crate::elastic_modules_for_unidirectional_composite).
+/// * `fibre_content` - the fibre content in the range from `0.0` to `1.0` where `0.0` is the matrix and `1.0` is the fibre.
+/// * `e_for_fiber` - the Young's modulus for fibre.
+/// * `nu_for_fiber` - the Poisson's ratio for fibre.
+/// * `e_inf_for_matrix` - the long-term (equilibrium) Young's modulus for matrix.
+/// * `prony_series_for_matrix` - the `[e_i, tau_i]` terms of the [Prony series] of the matrix relaxation modulus
+///   `E(t) = e_inf + sum(e_i * exp(-t / tau_i))`.
+/// * `nu_for_matrix` - the Poisson's ratio for matrix.
+/// * `times` - the times at which the relaxation modules are computed.
+///
+/// ## Returns
+///
+/// Returns the nine relaxation modules in the order of
+/// [`elastic_modules_for_unidirectional_composite`](crate::elastic_modules_for_unidirectional_composite)
+/// for each of the `times`.
+///
+/// [relaxation]: https://en.wikipedia.org/wiki/Stress_relaxation
+/// [correspondence principle]: https://en.wikipedia.org/wiki/Viscoelasticity
+/// [Prony series]: https://en.wikipedia.org/wiki/Prony%27s_method
+#[allow(clippy::too_many_arguments)]
+pub fn relaxation_modules_for_unidirectional_composite(
+    number_of_model: u8,
+    fibre_content: f64,
+    e_for_fiber: f64,
+    nu_for_fiber: f64,
+    e_inf_for_matrix: f64,
+    prony_series_for_matrix: &[[f64; 2]],
+    nu_for_matrix: f64,
+    times: &[f64],
+) -> Result<Vec<[f64; 9]>> {
+    let nested_res = crate::catch_unwind(|| {
+        times
+            .iter()
+            .map(|&t| {
+                let s = 1.0 / (2.0 * t);
+                elastic_modules_for_unidirectional_composite_generic(
+                    number_of_model,
+                    fibre_content,
+                    e_for_fiber,
+                    nu_for_fiber,
+                    carson_transform(e_inf_for_matrix, prony_series_for_matrix, s),
+                    nu_for_matrix,
+                )
+            })
+            .collect::<Result<Vec<_>>>()
+    });
+    match nested_res {
+        Ok(res) => res,
+        Err(err) => Err(err),
+    }
+}
+
+/// Computes [complex modules] for unidirectional composite with a viscoelastic matrix
+/// using the elastic–viscoelastic [correspondence principle].
+///
+/// The elastic model is evaluated in the frequency domain with the complex modulus of the matrix.
+/// The fibre is elastic and the Poisson's ratio of the matrix is assumed to be frequency-independent.
+///
+/// ## Arguments
+///
+/// * `number_of_model` - the number of the elastic model, see
+///   [`elastic_modules_for_unidirectional_composite`](crate::elastic_modules_for_unidirectional_composite).
+/// * `fibre_content` - the fibre content in the range from `0.0` to `1.0` where `0.0` is the matrix and `1.0` is the fibre.
+/// * `e_for_fiber` - the Young's modulus for fibre.
+/// * `nu_for_fiber` - the Poisson's ratio for fibre.
+/// * `e_inf_for_matrix` - the long-term (equilibrium) Young's modulus for matrix.
+/// * `prony_series_for_matrix` - the `[e_i, tau_i]` terms of the [Prony series] of the matrix relaxation modulus
+///   `E(t) = e_inf + sum(e_i * exp(-t / tau_i))`.
+/// * `nu_for_matrix` - the Poisson's ratio for matrix.
+/// * `angular_frequencies` - the angular frequencies at which the complex modules are computed.
+///
+/// ## Returns
+///
+/// Returns the nine complex modules in the order of
+/// [`elastic_modules_for_unidirectional_composite`](crate::elastic_modules_for_unidirectional_composite)
+/// for each of the `angular_frequencies`. Each modulus is `[storage, loss]`.
+///
+/// [complex modules]: https://en.wikipedia.org/wiki/Dynamic_modulus
+/// [correspondence principle]: https://en.wikipedia.org/wiki/Viscoelasticity
+/// [Prony series]: https://en.wikipedia.org/wiki/Prony%27s_method
+#[allow(clippy::too_many_arguments)]
+pub fn complex_modules_for_unidirectional_composite(
+    number_of_model: u8,
+    fibre_content: f64,
+    e_for_fiber: f64,
+    nu_for_fiber: f64,
+    e_inf_for_matrix: f64,
+    prony_series_for_matrix: &[[f64; 2]],
+    nu_for_matrix: f64,
+    angular_frequencies: &[f64],
+) -> Result<Vec<[[f64; 2]; 9]>> {
+    let nested_res = crate::catch_unwind(|| {
+        angular_frequencies
+            .iter()
+            .map(|&omega| {
+                let s = Complex64::new(0.0, omega);
+                let res = elastic_modules_for_unidirectional_composite_generic(
+                    number_of_model,
+                    Complex64::from(fibre_content),
+                    Complex64::from(e_for_fiber),
+                    Complex64::from(nu_for_fiber),
+                    carson_transform(e_inf_for_matrix, prony_series_for_matrix, s),
+                    Complex64::from(nu_for_matrix),
+                )?;
+                Ok(res.map(|m| [m.re, m.im]))
+            })
+            .collect::<Result<Vec<_>>>()
+    });
+    match nested_res {
+        Ok(res) => res,
+        Err(err) => Err(err),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::elastic_modules_for_unidirectional_composite;
+
+    const PRONY_SERIES: [[f64; 2]; 2] = [[2.0, 1.0], [1.0, 100.0]];
+
+    #[test]
+    fn test() {
+        let res = relaxation_modules_for_unidirectional_composite(
+            2,
+            0.2,
+            100.0,
+            0.3,
+            2.0,
+            &PRONY_SERIES,
+            0.2,
+            &[10.0],
+        )
+        .unwrap();
+        assert_eq!(res[0][1], 3.9745165149176174);
+
+        let res = complex_modules_for_unidirectional_composite(
+            2,
+            0.2,
+            100.0,
+            0.3,
+            2.0,
+            &PRONY_SERIES,
+            0.2,
+            &[0.1],
+        )
+        .unwrap();
+        assert_eq!(res[0][1], [4.081045213593691, 0.38221071164730214]);
+    }
+
+    #[test]
+    fn elastic_matrix_gives_elastic_modules() {
+        let elastic =
+            elastic_modules_for_unidirectional_composite(2, 0.2, 100.0, 0.3, 5.0, 0.2).unwrap();
+        let relaxation = relaxation_modules_for_unidirectional_composite(
+            2,
+            0.2,
+            100.0,
+            0.3,
+            5.0,
+            &[],
+            0.2,
+            &[1.0, 1000.0],
+        )
+        .unwrap();
+        assert_eq!(relaxation, vec![elastic, elastic]);
+        let complex =
+            complex_modules_for_unidirectional_composite(2, 0.2, 100.0, 0.3, 5.0, &[], 0.2, &[1.0])
+                .unwrap();
+        for ([storage, loss], elastic) in complex[0].into_iter().zip(elastic) {
+            assert!((storage - elastic).abs() <= 1e-12 * elastic.abs());
+            assert!(loss.abs() <= 1e-12 * elastic.abs());
+        }
+    }
+}
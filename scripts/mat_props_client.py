@@ -0,0 +1,331 @@
+"""Generated by `cargo run -- codegen` in `back` from the message declarations of `mat-props-proto/src/messages.rs`. Do not edit."""
+
+import asyncio
+import urllib.error
+import urllib.request
+from dataclasses import dataclass
+from struct import pack, unpack
+from sys import byteorder
+
+NATIVE_ENDIANNESS = 0 if byteorder == "little" else 1
+# the explicit byte order disables the native alignment, the formats have the padding
+_BYTE_ORDER = ("<", ">")
+
+
+class ProblemError(Exception):
+    """The error response of the backend with its application/problem+json body."""
+
+    def __init__(self, status: int, problem: str):
+        super().__init__(f"{status}: {problem}")
+        self.status = status
+        self.problem = problem
+
+
+def _post(url: str, body: bytes, content_type: str) -> bytes:
+    req = urllib.request.Request(
+        url, data=body, headers={"Content-Type": content_type}, method="POST"
+    )
+    try:
+        with urllib.request.urlopen(req) as resp:
+            return resp.read()
+    except urllib.error.HTTPError as e:
+        raise ProblemError(e.code, e.read().decode()) from e
+
+
+ELASTIC_MODULES_FOR_UNIDIRECTIONAL_COMPOSITE_PATH = "/compute/elastic_modules_for_unidirectional_composite"
+ELASTIC_MODULES_FOR_UNIDIRECTIONAL_COMPOSITE_ARGS_CONTENT_TYPE = "application/x.elastic-modules-for-unidirectional-composite-args-message"
+ELASTIC_MODULES_FOR_UNIDIRECTIONAL_COMPOSITE_ARGS_MESSAGE_ID = 1
+ELASTIC_MODULES_FOR_UNIDIRECTIONAL_COMPOSITE_ARGS_FORMAT = "BBBBxxxxddddd"
+ELASTIC_MODULES_FOR_UNIDIRECTIONAL_COMPOSITE_RESPONSE_CONTENT_TYPE = "application/x.elastic-modules-for-unidirectional-composite-response-message"
+ELASTIC_MODULES_FOR_UNIDIRECTIONAL_COMPOSITE_RESPONSE_MESSAGE_ID = 32769
+ELASTIC_MODULES_FOR_UNIDIRECTIONAL_COMPOSITE_RESPONSE_FORMAT = "ddddddddd"
+
+
+@dataclass
+class ElasticModulesForUnidirectionalCompositeArgs:
+    number_of_model: int
+    fibre_content: float
+    e_for_fiber: float
+    nu_for_fiber: float
+    e_for_matrix: float
+    nu_for_matrix: float
+    unit_system: int = 0
+    output_unit_system: int = 0
+
+
+@dataclass
+class ElasticModulesForUnidirectionalCompositeResponse:
+    e1: float
+    e2: float
+    e3: float
+    nu12: float
+    nu13: float
+    nu23: float
+    g12: float
+    g13: float
+    g23: float
+
+
+def encode_elastic_modules_for_unidirectional_composite_args(args: ElasticModulesForUnidirectionalCompositeArgs, endianness: int = NATIVE_ENDIANNESS) -> bytes:
+    return pack(
+        _BYTE_ORDER[endianness] + ELASTIC_MODULES_FOR_UNIDIRECTIONAL_COMPOSITE_ARGS_FORMAT,
+        endianness,
+        args.number_of_model,
+        args.unit_system,
+        args.output_unit_system,
+        args.fibre_content,
+        args.e_for_fiber,
+        args.nu_for_fiber,
+        args.e_for_matrix,
+        args.nu_for_matrix,
+    )
+
+
+def decode_elastic_modules_for_unidirectional_composite_response(data: bytes, endianness: int = NATIVE_ENDIANNESS) -> ElasticModulesForUnidirectionalCompositeResponse:
+    return ElasticModulesForUnidirectionalCompositeResponse(*unpack(_BYTE_ORDER[endianness] + ELASTIC_MODULES_FOR_UNIDIRECTIONAL_COMPOSITE_RESPONSE_FORMAT, data))
+
+
+async def elastic_modules_for_unidirectional_composite(base_url: str, args: ElasticModulesForUnidirectionalCompositeArgs) -> ElasticModulesForUnidirectionalCompositeResponse:
+    data = await asyncio.to_thread(
+        _post,
+        base_url + ELASTIC_MODULES_FOR_UNIDIRECTIONAL_COMPOSITE_PATH,
+        encode_elastic_modules_for_unidirectional_composite_args(args),
+        ELASTIC_MODULES_FOR_UNIDIRECTIONAL_COMPOSITE_ARGS_CONTENT_TYPE,
+    )
+    return decode_elastic_modules_for_unidirectional_composite_response(data)
+
+
+ELASTIC_MODULES_FOR_HONEYCOMB_PATH = "/compute/elastic_modules_for_honeycomb"
+ELASTIC_MODULES_FOR_HONEYCOMB_ARGS_CONTENT_TYPE = "application/x.elastic-modules-for-honeycomb-args-message"
+ELASTIC_MODULES_FOR_HONEYCOMB_ARGS_MESSAGE_ID = 2
+ELASTIC_MODULES_FOR_HONEYCOMB_ARGS_FORMAT = "BBBBxxxxdddddd"
+ELASTIC_MODULES_FOR_HONEYCOMB_RESPONSE_CONTENT_TYPE = "application/x.elastic-modules-for-honeycomb-response-message"
+ELASTIC_MODULES_FOR_HONEYCOMB_RESPONSE_MESSAGE_ID = 32770
+ELASTIC_MODULES_FOR_HONEYCOMB_RESPONSE_FORMAT = "ddddddddd"
+
+
+@dataclass
+class ElasticModulesForHoneycombArgs:
+    number_of_model: int
+    l_cell_side_size: float
+    h_cell_side_size: float
+    wall_thickness: float
+    angle: float
+    e_for_honeycomb: float
+    nu_for_honeycomb: float
+    unit_system: int = 0
+    output_unit_system: int = 0
+
+
+@dataclass
+class ElasticModulesForHoneycombResponse:
+    e1: float
+    e2: float
+    e3: float
+    nu12: float
+    nu13: float
+    nu23: float
+    g12: float
+    g13: float
+    g23: float
+
+
+def encode_elastic_modules_for_honeycomb_args(args: ElasticModulesForHoneycombArgs, endianness: int = NATIVE_ENDIANNESS) -> bytes:
+    return pack(
+        _BYTE_ORDER[endianness] + ELASTIC_MODULES_FOR_HONEYCOMB_ARGS_FORMAT,
+        endianness,
+        args.number_of_model,
+        args.unit_system,
+        args.output_unit_system,
+        args.l_cell_side_size,
+        args.h_cell_side_size,
+        args.wall_thickness,
+        args.angle,
+        args.e_for_honeycomb,
+        args.nu_for_honeycomb,
+    )
+
+
+def decode_elastic_modules_for_honeycomb_response(data: bytes, endianness: int = NATIVE_ENDIANNESS) -> ElasticModulesForHoneycombResponse:
+    return ElasticModulesForHoneycombResponse(*unpack(_BYTE_ORDER[endianness] + ELASTIC_MODULES_FOR_HONEYCOMB_RESPONSE_FORMAT, data))
+
+
+async def elastic_modules_for_honeycomb(base_url: str, args: ElasticModulesForHoneycombArgs) -> ElasticModulesForHoneycombResponse:
+    data = await asyncio.to_thread(
+        _post,
+        base_url + ELASTIC_MODULES_FOR_HONEYCOMB_PATH,
+        encode_elastic_modules_for_honeycomb_args(args),
+        ELASTIC_MODULES_FOR_HONEYCOMB_ARGS_CONTENT_TYPE,
+    )
+    return decode_elastic_modules_for_honeycomb_response(data)
+
+
+THERMAL_CONDUCTIVITY_FOR_UNIDIRECTIONAL_COMPOSITE_PATH = "/compute/thermal_conductivity_for_unidirectional_composite"
+THERMAL_CONDUCTIVITY_FOR_UNIDIRECTIONAL_COMPOSITE_ARGS_CONTENT_TYPE = "application/x.thermal-conductivity-for-unidirectional-composite-args-message"
+THERMAL_CONDUCTIVITY_FOR_UNIDIRECTIONAL_COMPOSITE_ARGS_MESSAGE_ID = 3
+THERMAL_CONDUCTIVITY_FOR_UNIDIRECTIONAL_COMPOSITE_ARGS_FORMAT = "BBBBxxxxddd"
+THERMAL_CONDUCTIVITY_FOR_UNIDIRECTIONAL_COMPOSITE_RESPONSE_CONTENT_TYPE = "application/x.thermal-conductivity-for-unidirectional-composite-response-message"
+THERMAL_CONDUCTIVITY_FOR_UNIDIRECTIONAL_COMPOSITE_RESPONSE_MESSAGE_ID = 32771
+THERMAL_CONDUCTIVITY_FOR_UNIDIRECTIONAL_COMPOSITE_RESPONSE_FORMAT = "ddd"
+
+
+@dataclass
+class ThermalConductivityForUnidirectionalCompositeArgs:
+    number_of_model: int
+    fibre_content: float
+    k_for_fiber: float
+    k_for_matrix: float
+    unit_system: int = 0
+    output_unit_system: int = 0
+
+
+@dataclass
+class ThermalConductivityForUnidirectionalCompositeResponse:
+    k1: float
+    k2: float
+    k3: float
+
+
+def encode_thermal_conductivity_for_unidirectional_composite_args(args: ThermalConductivityForUnidirectionalCompositeArgs, endianness: int = NATIVE_ENDIANNESS) -> bytes:
+    return pack(
+        _BYTE_ORDER[endianness] + THERMAL_CONDUCTIVITY_FOR_UNIDIRECTIONAL_COMPOSITE_ARGS_FORMAT,
+        endianness,
+        args.number_of_model,
+        args.unit_system,
+        args.output_unit_system,
+        args.fibre_content,
+        args.k_for_fiber,
+        args.k_for_matrix,
+    )
+
+
+def decode_thermal_conductivity_for_unidirectional_composite_response(data: bytes, endianness: int = NATIVE_ENDIANNESS) -> ThermalConductivityForUnidirectionalCompositeResponse:
+    return ThermalConductivityForUnidirectionalCompositeResponse(*unpack(_BYTE_ORDER[endianness] + THERMAL_CONDUCTIVITY_FOR_UNIDIRECTIONAL_COMPOSITE_RESPONSE_FORMAT, data))
+
+
+async def thermal_conductivity_for_unidirectional_composite(base_url: str, args: ThermalConductivityForUnidirectionalCompositeArgs) -> ThermalConductivityForUnidirectionalCompositeResponse:
+    data = await asyncio.to_thread(
+        _post,
+        base_url + THERMAL_CONDUCTIVITY_FOR_UNIDIRECTIONAL_COMPOSITE_PATH,
+        encode_thermal_conductivity_for_unidirectional_composite_args(args),
+        THERMAL_CONDUCTIVITY_FOR_UNIDIRECTIONAL_COMPOSITE_ARGS_CONTENT_TYPE,
+    )
+    return decode_thermal_conductivity_for_unidirectional_composite_response(data)
+
+
+THERMAL_EXPANSION_FOR_UNIDIRECTIONAL_COMPOSITE_PATH = "/compute/thermal_expansion_for_unidirectional_composite"
+THERMAL_EXPANSION_FOR_UNIDIRECTIONAL_COMPOSITE_ARGS_CONTENT_TYPE = "application/x.thermal-expansion-for-unidirectional-composite-args-message"
+THERMAL_EXPANSION_FOR_UNIDIRECTIONAL_COMPOSITE_ARGS_MESSAGE_ID = 4
+THERMAL_EXPANSION_FOR_UNIDIRECTIONAL_COMPOSITE_ARGS_FORMAT = "BBBBxxxxddddddd"
+THERMAL_EXPANSION_FOR_UNIDIRECTIONAL_COMPOSITE_RESPONSE_CONTENT_TYPE = "application/x.thermal-expansion-for-unidirectional-composite-response-message"
+THERMAL_EXPANSION_FOR_UNIDIRECTIONAL_COMPOSITE_RESPONSE_MESSAGE_ID = 32772
+THERMAL_EXPANSION_FOR_UNIDIRECTIONAL_COMPOSITE_RESPONSE_FORMAT = "ddd"
+
+
+@dataclass
+class ThermalExpansionForUnidirectionalCompositeArgs:
+    number_of_model: int
+    fibre_content: float
+    e_for_fiber: float
+    nu_for_fiber: float
+    alpha_for_fiber: float
+    e_for_matrix: float
+    nu_for_matrix: float
+    alpha_for_matrix: float
+    unit_system: int = 0
+    output_unit_system: int = 0
+
+
+@dataclass
+class ThermalExpansionForUnidirectionalCompositeResponse:
+    alpha1: float
+    alpha2: float
+    alpha3: float
+
+
+def encode_thermal_expansion_for_unidirectional_composite_args(args: ThermalExpansionForUnidirectionalCompositeArgs, endianness: int = NATIVE_ENDIANNESS) -> bytes:
+    return pack(
+        _BYTE_ORDER[endianness] + THERMAL_EXPANSION_FOR_UNIDIRECTIONAL_COMPOSITE_ARGS_FORMAT,
+        endianness,
+        args.number_of_model,
+        args.unit_system,
+        args.output_unit_system,
+        args.fibre_content,
+        args.e_for_fiber,
+        args.nu_for_fiber,
+        args.alpha_for_fiber,
+        args.e_for_matrix,
+        args.nu_for_matrix,
+        args.alpha_for_matrix,
+    )
+
+
+def decode_thermal_expansion_for_unidirectional_composite_response(data: bytes, endianness: int = NATIVE_ENDIANNESS) -> ThermalExpansionForUnidirectionalCompositeResponse:
+    return ThermalExpansionForUnidirectionalCompositeResponse(*unpack(_BYTE_ORDER[endianness] + THERMAL_EXPANSION_FOR_UNIDIRECTIONAL_COMPOSITE_RESPONSE_FORMAT, data))
+
+
+async def thermal_expansion_for_unidirectional_composite(base_url: str, args: ThermalExpansionForUnidirectionalCompositeArgs) -> ThermalExpansionForUnidirectionalCompositeResponse:
+    data = await asyncio.to_thread(
+        _post,
+        base_url + THERMAL_EXPANSION_FOR_UNIDIRECTIONAL_COMPOSITE_PATH,
+        encode_thermal_expansion_for_unidirectional_composite_args(args),
+        THERMAL_EXPANSION_FOR_UNIDIRECTIONAL_COMPOSITE_ARGS_CONTENT_TYPE,
+    )
+    return decode_thermal_expansion_for_unidirectional_composite_response(data)
+
+
+THERMAL_EXPANSION_FOR_HONEYCOMB_PATH = "/compute/thermal_expansion_for_honeycomb"
+THERMAL_EXPANSION_FOR_HONEYCOMB_ARGS_CONTENT_TYPE = "application/x.thermal-expansion-for-honeycomb-args-message"
+THERMAL_EXPANSION_FOR_HONEYCOMB_ARGS_MESSAGE_ID = 5
+THERMAL_EXPANSION_FOR_HONEYCOMB_ARGS_FORMAT = "BBBBxxxxddddd"
+THERMAL_EXPANSION_FOR_HONEYCOMB_RESPONSE_CONTENT_TYPE = "application/x.thermal-expansion-for-honeycomb-response-message"
+THERMAL_EXPANSION_FOR_HONEYCOMB_RESPONSE_MESSAGE_ID = 32773
+THERMAL_EXPANSION_FOR_HONEYCOMB_RESPONSE_FORMAT = "ddd"
+
+
+@dataclass
+class ThermalExpansionForHoneycombArgs:
+    number_of_model: int
+    l_cell_side_size: float
+    h_cell_side_size: float
+    _wall_thickness: float
+    angle: float
+    alpha_for_honeycomb: float
+    unit_system: int = 0
+    output_unit_system: int = 0
+
+
+@dataclass
+class ThermalExpansionForHoneycombResponse:
+    alpha1: float
+    alpha2: float
+    alpha3: float
+
+
+def encode_thermal_expansion_for_honeycomb_args(args: ThermalExpansionForHoneycombArgs, endianness: int = NATIVE_ENDIANNESS) -> bytes:
+    return pack(
+        _BYTE_ORDER[endianness] + THERMAL_EXPANSION_FOR_HONEYCOMB_ARGS_FORMAT,
+        endianness,
+        args.number_of_model,
+        args.unit_system,
+        args.output_unit_system,
+        args.l_cell_side_size,
+        args.h_cell_side_size,
+        args._wall_thickness,
+        args.angle,
+        args.alpha_for_honeycomb,
+    )
+
+
+def decode_thermal_expansion_for_honeycomb_response(data: bytes, endianness: int = NATIVE_ENDIANNESS) -> ThermalExpansionForHoneycombResponse:
+    return ThermalExpansionForHoneycombResponse(*unpack(_BYTE_ORDER[endianness] + THERMAL_EXPANSION_FOR_HONEYCOMB_RESPONSE_FORMAT, data))
+
+
+async def thermal_expansion_for_honeycomb(base_url: str, args: ThermalExpansionForHoneycombArgs) -> ThermalExpansionForHoneycombResponse:
+    data = await asyncio.to_thread(
+        _post,
+        base_url + THERMAL_EXPANSION_FOR_HONEYCOMB_PATH,
+        encode_thermal_expansion_for_honeycomb_args(args),
+        THERMAL_EXPANSION_FOR_HONEYCOMB_ARGS_CONTENT_TYPE,
+    )
+    return decode_thermal_expansion_for_honeycomb_response(data)